@@ -110,6 +110,18 @@ mod [<$pkg _benchmarks>]  {
             .map_err(|x| x.to_string()).unwrap()
     }
 
+    pub fn create_less_safe_key(config: &AeadConfig) -> aead::LessSafeKey {
+        aead::LessSafeKey::new(key(&config))
+    }
+
+    pub fn seal_less_safe(key: &aead::LessSafeKey, config: &AeadConfig, in_out: &mut [u8]) -> Tag {
+        let mut nonce = [0u8; aead::NONCE_LEN];
+        nonce.copy_from_slice(&config.nonce[0..aead::NONCE_LEN]);
+        key
+            .seal_in_place_separate_tag(Nonce::assume_unique_for_key(nonce), aad(config), in_out)
+            .map_err(|x| x.to_string()).unwrap()
+    }
+
     pub fn open(opening_key: &mut OpeningKey<impl NonceSequence>, aad: Aad<String>, in_out: &mut [u8]) {
        opening_key
             .open_in_place(aad, in_out)
@@ -137,6 +149,7 @@ fn test_aes_128_gcm(c: &mut Criterion) {
             test_aead_separate(c, &config);
             test_aead_append(c, &config);
             test_aead_open(c, &config);
+            test_aead_key_reuse(c, &config);
             Ok(())
         },
     );
@@ -156,6 +169,7 @@ fn test_aes_256_gcm(c: &mut Criterion) {
             test_aead_separate(c, &config);
             test_aead_append(c, &config);
             test_aead_open(c, &config);
+            test_aead_key_reuse(c, &config);
             Ok(())
         },
     );
@@ -175,6 +189,7 @@ fn test_chacha20(c: &mut Criterion) {
             test_aead_separate(c, &config);
             test_aead_append(c, &config);
             test_aead_open(c, &config);
+            test_aead_key_reuse(c, &config);
             Ok(())
         },
     );
@@ -272,5 +287,47 @@ fn test_aead_open(c: &mut Criterion, config: &AeadConfig) {
     }
 }
 
+// Compares sealing with a key whose context is built once against building a new key (and
+// expanding its key schedule) for every message.
+fn test_aead_key_reuse(c: &mut Criterion, config: &AeadConfig) {
+    let mut in_out = config.in_out.clone();
+
+    let bench_group_name = format!(
+        "AEAD-{:?}-key-reuse-{}-bytes",
+        config.algorithm,
+        in_out.len()
+    );
+    let mut group = c.benchmark_group(bench_group_name);
+
+    let aws_key = aws_lc_rs_benchmarks::create_less_safe_key(config);
+    group.bench_function("AWS-LC", |b| {
+        b.iter(|| {
+            let _tag = aws_lc_rs_benchmarks::seal_less_safe(&aws_key, config, &mut in_out);
+        });
+    });
+    group.bench_function("AWS-LC-new-key", |b| {
+        b.iter(|| {
+            let aws_key = aws_lc_rs_benchmarks::create_less_safe_key(config);
+            let _tag = aws_lc_rs_benchmarks::seal_less_safe(&aws_key, config, &mut in_out);
+        });
+    });
+
+    #[cfg(feature = "ring-benchmarks")]
+    {
+        let ring_key = ring_benchmarks::create_less_safe_key(config);
+        group.bench_function("Ring", |b| {
+            b.iter(|| {
+                let _tag = ring_benchmarks::seal_less_safe(&ring_key, config, &mut in_out);
+            });
+        });
+        group.bench_function("Ring-new-key", |b| {
+            b.iter(|| {
+                let ring_key = ring_benchmarks::create_less_safe_key(config);
+                let _tag = ring_benchmarks::seal_less_safe(&ring_key, config, &mut in_out);
+            });
+        });
+    }
+}
+
 criterion_group!(benches, test_aes_128_gcm, test_aes_256_gcm, test_chacha20,);
 criterion_main!(benches);
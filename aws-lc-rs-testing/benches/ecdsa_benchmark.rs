@@ -123,6 +123,12 @@ macro_rules! benchmark_ecdsa {
             .expect(&format!("Unable to build EcdsaKeyPair: {:?}", config))
     }
 
+    pub fn create_asn1_key_pair(config: &EcdsaConfig) -> EcdsaKeyPair {
+        let signing = signing(config.curve, config.digest, &crate::ASN1);
+        EcdsaKeyPair::from_private_key_and_public_key(signing, &config.d, &config.q)
+            .expect(&format!("Unable to build EcdsaKeyPair: {:?}", config))
+    }
+
     pub fn signing(
         curve: &'static EcdsaCurve,
         digest: &'static EcdsaDigest,
@@ -218,6 +224,16 @@ fn test_ecdsa_sign(c: &mut Criterion, config: &EcdsaConfig) {
             aws_lc_rs_benchmarks::sign(&aws_key_pair, &aws_rng, &config.msg);
         });
     });
+    if config.format == &FIXED {
+        // The same key and digest with the ASN.1 encoding, so that the difference between the
+        // two isolates the cost of serializing the fixed encoding.
+        let aws_asn1_key_pair = aws_lc_rs_benchmarks::create_asn1_key_pair(config);
+        group.bench_function("AWS-LC-asn1", |b| {
+            b.iter(|| {
+                aws_lc_rs_benchmarks::sign(&aws_asn1_key_pair, &aws_rng, &config.msg);
+            });
+        });
+    }
     #[cfg(feature = "ring-benchmarks")]
     {
         let ring_rng = ring_benchmarks::get_rng();
@@ -253,6 +269,16 @@ fn test_ecdsa_verify(c: &mut Criterion, config: &EcdsaConfig) {
             aws_lc_rs_benchmarks::verify(aws_verification_alg, pub_key, &config.msg, sig);
         });
     });
+    if (config.curve, config.digest, config.format) == (&P256, &SHA256, &FIXED) {
+        let pub_key: &[u8; 65] = pub_key.try_into().unwrap();
+        let sig: &[u8; 64] = sig.try_into().unwrap();
+        group.bench_function("AWS-LC-verify_p256_fixed", |b| {
+            b.iter(|| {
+                aws_lc_rs::signature::verify_p256_fixed(pub_key, &config.msg, sig)
+                    .expect("verification failed");
+            });
+        });
+    }
     #[cfg(feature = "ring-benchmarks")]
     {
         let ring_verification_alg =
@@ -6,28 +6,53 @@ use aws_lc_rs::{hkdf, test};
 
 #[test]
 fn test_key_type_header_protection_key() {
-    let key_bytes = test::from_dirty_hex(r"d480429666d48b400633921c5407d1d1");
-    let info = test::from_dirty_hex(r"f0f1f2f3f4f5f6f7f8f9");
-    let info_wrap = [info.as_slice()];
-    let sample = test::from_dirty_hex(r"b0b1b2b3b4b5b6b7b8b9babbbcbdbebf");
-
-    let prk = hkdf::Prk::new_less_safe(hkdf::HKDF_SHA256, &key_bytes);
-    let okm = prk.expand(&info_wrap, &AES_128).unwrap();
-    let hpk = HeaderProtectionKey::from(okm);
-    assert_eq!(&AES_128, hpk.algorithm());
     assert_eq!(16, AES_128.key_len());
     assert_eq!(16, AES_128.sample_len());
     assert_eq!(32, AES_256.key_len());
     assert_eq!(16, AES_256.sample_len());
     assert_eq!(32, CHACHA20.key_len());
     assert_eq!(16, CHACHA20.sample_len());
-    let mask = hpk.new_mask(&sample).unwrap();
 
-    let ring_prk = ring::hkdf::Prk::new_less_safe(ring::hkdf::HKDF_SHA256, &key_bytes);
-    let ring_okm = ring_prk
-        .expand(&info_wrap, &ring::aead::quic::AES_128)
-        .unwrap();
+    for (alg, ring_alg) in [
+        (&AES_128, &ring::aead::quic::AES_128),
+        (&AES_256, &ring::aead::quic::AES_256),
+        (&CHACHA20, &ring::aead::quic::CHACHA20),
+    ] {
+        for (hkdf_alg, ring_hkdf_alg) in [
+            (hkdf::HKDF_SHA256, ring::hkdf::HKDF_SHA256),
+            (hkdf::HKDF_SHA384, ring::hkdf::HKDF_SHA384),
+        ] {
+            test_header_protection_key_from_okm(alg, ring_alg, hkdf_alg, ring_hkdf_alg);
+        }
+    }
+}
+
+fn test_header_protection_key_from_okm(
+    alg: &'static aws_lc_rs::aead::quic::Algorithm,
+    ring_alg: &'static ring::aead::quic::Algorithm,
+    hkdf_alg: hkdf::Algorithm,
+    ring_hkdf_alg: ring::hkdf::Algorithm,
+) {
+    let key_bytes = test::from_dirty_hex(r"d480429666d48b400633921c5407d1d1");
+    let info = test::from_dirty_hex(r"f0f1f2f3f4f5f6f7f8f9");
+    let info_wrap = [info.as_slice()];
+
+    let prk = hkdf::Prk::new_less_safe(hkdf_alg, &key_bytes);
+    let okm = prk.expand(&info_wrap, alg).unwrap();
+    let hpk = HeaderProtectionKey::from(okm);
+    assert_eq!(alg, hpk.algorithm());
+
+    let ring_prk = ring::hkdf::Prk::new_less_safe(ring_hkdf_alg, &key_bytes);
+    let ring_okm = ring_prk.expand(&info_wrap, ring_alg).unwrap();
     let ring_hpk = ring::aead::quic::HeaderProtectionKey::from(ring_okm);
-    let ring_mask = ring_hpk.new_mask(&sample).unwrap();
-    assert_eq!(mask, ring_mask);
+
+    for sample in [
+        test::from_dirty_hex(r"b0b1b2b3b4b5b6b7b8b9babbbcbdbebf"),
+        test::from_dirty_hex(r"5e5cd55c41f69080575d7999c25a5bfb"),
+        vec![0u8; 16],
+    ] {
+        let mask = hpk.new_mask(&sample).unwrap();
+        let ring_mask = ring_hpk.new_mask(&sample).unwrap();
+        assert_eq!(mask, ring_mask, "{alg:?} {hkdf_alg:?}");
+    }
 }
@@ -114,27 +114,37 @@
 //! assert_eq!(plaintext, decrypted_plaintext);
 //! ```
 
-use crate::{derive_debug_via_id, error::Unspecified, hkdf};
+use crate::{constant_time, derive_debug_via_id, error::Unspecified, hkdf, rand};
 use aead_ctx::AeadCtx;
 use core::{fmt::Debug, ops::RangeFrom};
+use zeroize::Zeroizing;
 
 mod aead_ctx;
 mod aes_gcm;
 mod chacha;
 pub mod chacha20_poly1305_openssh;
+mod committing;
+#[cfg(feature = "low-level")]
+pub mod evp;
 mod nonce;
 pub mod nonce_sequence;
 mod poly1305;
 pub mod quic;
 mod rand_nonce;
+mod stream;
 mod tls;
 mod unbound_key;
 
 pub use self::{
-    aes_gcm::{AES_128_GCM, AES_128_GCM_SIV, AES_256_GCM, AES_256_GCM_SIV},
+    aes_gcm::{
+        AES_128_GCM, AES_128_GCM_SIV, AES_128_GCM_TAG_64, AES_128_GCM_TAG_96, AES_256_GCM,
+        AES_256_GCM_SIV, AES_256_GCM_TAG_64, AES_256_GCM_TAG_96,
+    },
     chacha::CHACHA20_POLY1305,
+    committing::{CommittingKey, COMMITMENT_LEN},
     nonce::{Nonce, NONCE_LEN},
     rand_nonce::RandomizedNonceKey,
+    stream::{StreamOpeningKey, StreamSealingKey, STREAM_NONCE_PREFIX_LEN},
     tls::{TlsProtocolId, TlsRecordOpeningKey, TlsRecordSealingKey},
     unbound_key::UnboundKey,
 };
@@ -433,6 +443,41 @@ impl<N: NonceSequence> SealingKey<N> {
             .seal_in_place_separate_tag(Some(self.nonce_sequence.advance()?), aad.as_ref(), in_out)
             .map(|(_, tag)| tag)
     }
+
+    /// Encrypts and signs (“seals”) data in place using a newly generated random 96-bit nonce,
+    /// appending the tag to the resulting ciphertext. The nonce is returned so that it can be
+    /// sent along with the ciphertext.
+    ///
+    /// The nonce sequence is not advanced. Random nonces must only be used when the number of
+    /// messages sealed under the key is small, and must not be mixed with a `NonceSequence`
+    /// whose nonces could collide with them.
+    ///
+    /// Prefer [`RandomizedNonceKey::seal_in_place_append_tag`].
+    ///
+    // # FIPS
+    // This method must not be used.
+    //
+    /// # Errors
+    /// `error::Unspecified` if the nonce could not be generated or the encryption failed.
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn seal_with_random_nonce<A, InOut>(
+        &self,
+        aad: Aad<A>,
+        in_out: &mut InOut,
+    ) -> Result<Nonce, Unspecified>
+    where
+        A: AsRef<[u8]>,
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::fill(&mut nonce)?;
+        self.key.seal_in_place_append_tag(
+            Some(Nonce::assume_unique_for_key(nonce)),
+            aad.as_ref(),
+            in_out,
+        )
+    }
 }
 
 /// The additionally authenticated data (AAD) for an opening or sealing
@@ -461,9 +506,100 @@ where
 
 impl Aad<[u8; 0]> {
     /// Construct an empty `Aad`.
+    ///
+    /// This is a `const fn`, so it can also be used to initialize constants.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Aad([])
+    }
+}
+
+impl Aad<[u8; 4]> {
+    /// Construct an `Aad` from the big-endian encoding of `n`, such as the length prefix of a
+    /// framed message.
+    #[must_use]
+    pub const fn from_u32_be(n: u32) -> Self {
+        Aad(n.to_be_bytes())
+    }
+}
+
+impl Aad<[u8; 8]> {
+    /// Construct an `Aad` from the big-endian encoding of `n`, such as the length prefix of a
+    /// framed message.
+    #[must_use]
+    pub const fn from_u64_be(n: u64) -> Self {
+        Aad(n.to_be_bytes())
+    }
+}
+
+/// Assembles the additional authenticated data of a message incrementally.
+///
+/// Framing protocols often build the AAD from several fields, such as length-prefixed headers,
+/// that become available one at a time. The fields are appended in order and `finish` produces
+/// the `Aad` to pass to a seal or open operation. Authenticating the result is equivalent to
+/// authenticating the concatenation of all appended fields.
+///
+/// ```rust
+/// # use aws_lc_rs::error::Unspecified;
+/// # fn main() -> Result<(), Unspecified> {
+/// use aws_lc_rs::aead::{AadBuilder, LessSafeKey, Nonce, UnboundKey, AES_128_GCM};
+///
+/// let key = LessSafeKey::new(UnboundKey::new(&AES_128_GCM, &[0u8; 16])?);
+/// let header = b"record header";
+///
+/// let aad = AadBuilder::new()
+///     .append(&u16::try_from(header.len()).unwrap().to_be_bytes())
+///     .append(header)
+///     .finish();
+///
+/// let mut in_out = b"payload".to_vec();
+/// key.seal_in_place_append_tag(Nonce::assume_unique_for_key([0u8; 12]), aad, &mut in_out)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AadBuilder {
+    aad: Vec<u8>,
+}
+
+impl AadBuilder {
+    /// Constructs an empty `AadBuilder`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Constructs an empty `AadBuilder` with space reserved for `capacity` bytes of AAD.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            aad: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Appends `data` to the AAD.
+    #[must_use]
+    pub fn append(mut self, data: &[u8]) -> Self {
+        self.aad.extend_from_slice(data);
+        self
+    }
+
+    /// The number of bytes of AAD appended so far.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.aad.len()
+    }
+
+    /// Whether no AAD has been appended.
     #[must_use]
-    pub fn empty() -> Self {
-        Self::from([])
+    pub fn is_empty(&self) -> bool {
+        self.aad.is_empty()
+    }
+
+    /// Finalizes the AAD.
+    #[must_use]
+    pub fn finish(self) -> Aad<Vec<u8>> {
+        Aad::from(self.aad)
     }
 }
 
@@ -474,11 +610,46 @@ impl hkdf::KeyType for &'static Algorithm {
     }
 }
 
+/// Derives the key and IV of a TLS 1.3 traffic key from `traffic_secret`, as
+/// specified in [RFC 8446, Section 7.3]:
+///
+/// ```text
+/// key = HKDF-Expand-Label(traffic_secret, "key", "", algorithm.key_len())
+/// iv  = HKDF-Expand-Label(traffic_secret, "iv", "", algorithm.nonce_len())
+/// ```
+///
+/// A traffic secret is used as the PRK directly, e.g. via
+/// [`hkdf::Prk::new_less_safe`]. The IV is returned as a `Nonce`; TLS 1.3 forms the
+/// nonce of each record by XORing the IV with the record's sequence number.
+///
+/// [RFC 8446, Section 7.3]: https://www.rfc-editor.org/rfc/rfc8446#section-7.3
+///
+/// # Errors
+/// `error::Unspecified` if the HKDF-Expand operations fail.
+pub fn derive_traffic_key(
+    algorithm: &'static Algorithm,
+    traffic_secret: &hkdf::Prk,
+) -> Result<(UnboundKey, Nonce), Unspecified> {
+    let mut key_bytes = Zeroizing::new([0; MAX_KEY_LEN]);
+    let key_bytes = &mut key_bytes[..algorithm.key_len()];
+    traffic_secret.expand_label(b"key", &[], key_bytes)?;
+    let mut iv = [0; NONCE_LEN];
+    traffic_secret.expand_label(b"iv", &[], &mut iv)?;
+    Ok((
+        UnboundKey::new(algorithm, key_bytes)?,
+        Nonce::assume_unique_for_key(iv),
+    ))
+}
+
 /// Immutable keys for use in situations where `OpeningKey`/`SealingKey` and
 /// `NonceSequence` cannot reasonably be used.
 ///
 /// Prefer [`RandomizedNonceKey`] when practical.
 ///
+/// The AEAD context, including the expanded key schedule, is initialized once when the
+/// `UnboundKey` is constructed and is reused by every operation; only the nonce, additional
+/// data and buffer vary per call.
+///
 // # FIPS
 // The following conditions must be met:
 // * `UnboundKey`'s algorithm is one of:
@@ -551,6 +722,41 @@ impl LessSafeKey {
             .open_within(nonce, aad.as_ref(), in_out, ciphertext_and_tag)
     }
 
+    /// Like [`LessSafeKey::open_in_place()`], except `in_out` is only modified if the tag is
+    /// valid.
+    ///
+    /// The ciphertext and tag are opened in a temporary copy, whose plaintext is copied over the
+    /// ciphertext only once it has been authenticated. When opening fails, `in_out` still holds
+    /// the original ciphertext and tag, so it can be retried, e.g. with a different key.
+    ///
+    // # FIPS
+    // Use this method with one of the following algorithms:
+    // * `AES_128_GCM`
+    // * `AES_256_GCM`
+    //
+    /// # Errors
+    /// `error::Unspecified` when ciphertext is invalid. In this case, `in_out` is unchanged.
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn open_in_place_checked<'in_out, A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        let mut scratch = Zeroizing::new(in_out.to_vec());
+        let plaintext = self
+            .key
+            .open_within(nonce, aad.as_ref(), &mut scratch, 0..)?;
+
+        let in_out = &mut in_out[..plaintext.len()];
+        in_out.copy_from_slice(plaintext);
+        Ok(in_out)
+    }
+
     /// Authenticates and decrypts (“opens”) data into another provided slice.
     ///
     /// `aad` is the additional authenticated data (AAD), if any.
@@ -702,6 +908,62 @@ impl LessSafeKey {
         )
     }
 
+    /// Encrypts and signs (“seals”) `plaintext`, returning a newly allocated buffer containing
+    /// the ciphertext followed by the tag.
+    ///
+    /// This is a convenience wrapper around `seal_in_place_append_tag`.
+    ///
+    /// `nonce` must be unique for every use of the key to seal data.
+    ///
+    // # FIPS
+    // This method must not be used.
+    //
+    /// # Errors
+    /// `error::Unspecified` if encryption operation fails.
+    #[inline]
+    pub fn seal<A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        plaintext: &[u8],
+    ) -> Result<Vec<u8>, Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        let mut in_out = Vec::with_capacity(plaintext.len() + self.algorithm().tag_len());
+        in_out.extend_from_slice(plaintext);
+        self.seal_in_place_append_tag(nonce, aad, &mut in_out)?;
+        Ok(in_out)
+    }
+
+    /// Authenticates and decrypts (“opens”) `ciphertext`, which must be the ciphertext followed
+    /// by the tag, returning a newly allocated buffer containing the plaintext.
+    ///
+    /// This is a convenience wrapper around `open_in_place`.
+    ///
+    // # FIPS
+    // Use this method with one of the following algorithms:
+    // * `AES_128_GCM`
+    // * `AES_256_GCM`
+    //
+    /// # Errors
+    /// `error::Unspecified` when ciphertext is invalid.
+    #[inline]
+    pub fn open<A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        let mut in_out = ciphertext.to_vec();
+        let plaintext_len = self.open_in_place(nonce, aad, &mut in_out)?.len();
+        in_out.truncate(plaintext_len);
+        Ok(in_out)
+    }
+
     /// The key's AEAD algorithm.
     #[inline]
     #[must_use]
@@ -723,10 +985,13 @@ pub struct Algorithm {
     init: fn(key: &[u8], tag_len: usize) -> Result<AeadCtx, Unspecified>,
     key_len: usize,
     id: AlgorithmID,
+    tag_len: usize,
 
     // /// Use `max_input_len!()` to initialize this.
     // TODO: Make this `usize`.
     max_input_len: u64,
+
+    max_aad_len: u64,
 }
 
 impl Algorithm {
@@ -743,7 +1008,7 @@ impl Algorithm {
     #[inline]
     #[must_use]
     pub fn tag_len(&self) -> usize {
-        TAG_LEN
+        self.tag_len
     }
 
     /// The length of the nonces.
@@ -752,6 +1017,18 @@ impl Algorithm {
     pub fn nonce_len(&self) -> usize {
         NONCE_LEN
     }
+
+    /// The maximum length, in bytes, of the associated data.
+    ///
+    /// This is 2^61 - 1 bytes for AES-GCM (NIST SP 800-38D), 2^36 bytes for AES-GCM-SIV
+    /// (RFC 8452) and 2^64 - 1 bytes for ChaCha20-Poly1305 (RFC 8439). Sealing or opening with
+    /// longer associated data fails with `error::Unspecified`, as the length can't be
+    /// authenticated.
+    #[inline]
+    #[must_use]
+    pub fn max_aad_len(&self) -> u64 {
+        self.max_aad_len
+    }
 }
 
 derive_debug_via_id!(Algorithm);
@@ -761,6 +1038,10 @@ derive_debug_via_id!(Algorithm);
 enum AlgorithmID {
     AES_128_GCM,
     AES_256_GCM,
+    AES_128_GCM_TAG_96,
+    AES_256_GCM_TAG_96,
+    AES_128_GCM_TAG_64,
+    AES_256_GCM_TAG_64,
     AES_128_GCM_SIV,
     AES_256_GCM_SIV,
     CHACHA20_POLY1305,
@@ -775,6 +1056,22 @@ impl PartialEq for Algorithm {
 
 impl Eq for Algorithm {}
 
+/// Returns the recommended AEAD algorithm for the current CPU: `AES_256_GCM` if it has hardware
+/// support for AES, and `CHACHA20_POLY1305` otherwise.
+///
+/// This is the cipher preference heuristic commonly used by TLS implementations; both algorithms
+/// are secure, and the choice is purely one of performance. Both peers must of course agree on
+/// the algorithm, so this is only suitable for selecting among the algorithms a protocol
+/// negotiates or records.
+#[must_use]
+pub fn recommended_algorithm() -> &'static Algorithm {
+    if crate::has_aes_hardware() {
+        &AES_256_GCM
+    } else {
+        &CHACHA20_POLY1305
+    }
+}
+
 /// An authentication tag.
 #[must_use]
 #[repr(C)]
@@ -786,10 +1083,22 @@ impl AsRef<[u8]> for Tag {
     }
 }
 
+/// Tags are compared in constant time with respect to their contents, using `CRYPTO_memcmp`.
+///
+/// A derived (byte-by-byte) comparison could return as soon as the first differing byte is found,
+/// letting an attacker who can measure the comparison time forge a tag one byte at a time.
+impl PartialEq for Tag {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time::verify_slices_are_equal(self.as_ref(), other.as_ref()).is_ok()
+    }
+}
+
+impl Eq for Tag {}
+
 #[allow(dead_code)]
 const MAX_KEY_LEN: usize = 32;
 
-// All the AEADs we support use 128-bit tags.
+// All the AEADs we support use 128-bit tags, except for the truncated AES-GCM variants.
 const TAG_LEN: usize = 16;
 
 /// The maximum length of a tag for the algorithms in this module.
@@ -803,6 +1112,121 @@ mod tests {
     #[cfg(feature = "fips")]
     mod fips;
 
+    #[test]
+    fn test_algorithm_lengths() {
+        for (alg, key_len, nonce_len, tag_len) in [
+            (&AES_128_GCM, 16, 12, 16),
+            (&AES_256_GCM, 32, 12, 16),
+            (&AES_128_GCM_SIV, 16, 12, 16),
+            (&AES_256_GCM_SIV, 32, 12, 16),
+            (&CHACHA20_POLY1305, 32, 12, 16),
+        ] {
+            assert_eq!(key_len, alg.key_len(), "{alg:?}");
+            assert_eq!(nonce_len, alg.nonce_len(), "{alg:?}");
+            assert_eq!(tag_len, alg.tag_len(), "{alg:?}");
+            assert_eq!(NONCE_LEN, alg.nonce_len());
+            assert!(alg.tag_len() <= MAX_TAG_LEN);
+            assert!(alg.key_len() <= MAX_KEY_LEN);
+
+            // The accessors are sufficient to size every buffer needed to seal and open.
+            let key = LessSafeKey::new(UnboundKey::new(alg, &vec![0u8; alg.key_len()]).unwrap());
+            let nonce = vec![0u8; alg.nonce_len()];
+            let mut in_out = vec![0u8; 20];
+            key.seal_in_place_append_tag(
+                Nonce::try_assume_unique_for_key(&nonce).unwrap(),
+                Aad::empty(),
+                &mut in_out,
+            )
+            .unwrap();
+            assert_eq!(20 + alg.tag_len(), in_out.len());
+        }
+    }
+
+    #[test]
+    fn test_max_aad_len() {
+        for (alg, max_aad_len) in [
+            (&AES_128_GCM, (1u64 << 61) - 1),
+            (&AES_256_GCM_TAG_96, (1 << 61) - 1),
+            (&AES_128_GCM_SIV, 1 << 36),
+            (&AES_256_GCM_SIV, 1 << 36),
+            (&CHACHA20_POLY1305, u64::MAX),
+        ] {
+            assert_eq!(max_aad_len, alg.max_aad_len(), "{alg:?}");
+
+            // Associated data of the maximum length can't be allocated, so the bound is checked
+            // directly. Every length representable by a slice is below the ChaCha20-Poly1305
+            // bound.
+            let key = UnboundKey::new(alg, &vec![0u8; alg.key_len()]).unwrap();
+            assert!(key.check_aad_len(0).is_ok());
+            if let Ok(max_aad_len) = usize::try_from(max_aad_len) {
+                assert!(key.check_aad_len(max_aad_len).is_ok(), "{alg:?}");
+                if let Some(over_limit) = max_aad_len.checked_add(1) {
+                    assert!(key.check_aad_len(over_limit).is_err(), "{alg:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_aad_builder() {
+        let key_bytes = from_hex("d480429666d48b400633921c5407d1d1").unwrap();
+        let nonce = from_hex("5bf11a0951f0bfc7ea5c9e58").unwrap();
+        let fields: [&[u8]; 3] = [b"version", b"", b"sequence number"];
+
+        let mut builder = AadBuilder::with_capacity(64);
+        assert!(builder.is_empty());
+        for field in fields {
+            let len = u16::try_from(field.len()).unwrap().to_be_bytes();
+            builder = builder.append(&len).append(field);
+        }
+        assert_eq!(2 * fields.len() + 22, builder.len());
+        let incremental = builder.finish();
+
+        let mut concatenated = Vec::new();
+        for field in fields {
+            concatenated.extend_from_slice(&u16::try_from(field.len()).unwrap().to_be_bytes());
+            concatenated.extend_from_slice(field);
+        }
+        assert_eq!(concatenated.as_slice(), incremental.as_ref());
+
+        let key = LessSafeKey::new(UnboundKey::new(&AES_128_GCM, &key_bytes).unwrap());
+        let mut sealed = b"framed payload".to_vec();
+        key.seal_in_place_append_tag(
+            Nonce::try_assume_unique_for_key(&nonce).unwrap(),
+            incremental,
+            &mut sealed,
+        )
+        .unwrap();
+
+        let mut expected = b"framed payload".to_vec();
+        key.seal_in_place_append_tag(
+            Nonce::try_assume_unique_for_key(&nonce).unwrap(),
+            Aad::from(&concatenated),
+            &mut expected,
+        )
+        .unwrap();
+        assert_eq!(expected, sealed);
+
+        let plaintext = key
+            .open_in_place(
+                Nonce::try_assume_unique_for_key(&nonce).unwrap(),
+                Aad::from(&concatenated),
+                &mut sealed,
+            )
+            .unwrap();
+        assert_eq!(b"framed payload", plaintext);
+
+        let mut sealed = expected;
+        let truncated = AadBuilder::new().append(&concatenated[1..]).finish();
+        assert!(key
+            .open_in_place(
+                Nonce::try_assume_unique_for_key(&nonce).unwrap(),
+                truncated,
+                &mut sealed,
+            )
+            .is_err());
+    }
+
     #[test]
     fn test_aes_128() {
         let key = from_hex("000102030405060708090a0b0c0d0e0f").unwrap();
@@ -853,4 +1277,96 @@ mod tests {
 
         assert_eq!(plaintext, in_out[..plaintext.len()]);
     }
+
+    #[test]
+    fn test_aes_gcm_truncated_tag() {
+        let key = from_hex("000102030405060708090a0b0c0d0e0f").unwrap();
+        let nonce = from_hex("5bf11a0951f0bfc7ea5c9e58").unwrap();
+        let nonce: [u8; NONCE_LEN] = nonce.as_slice().try_into().unwrap();
+        let plaintext = from_hex("00112233445566778899aabbccddeeff").unwrap();
+
+        assert_eq!(12, AES_128_GCM_TAG_96.tag_len());
+        assert_eq!(8, AES_128_GCM_TAG_64.tag_len());
+        assert_eq!(12, AES_256_GCM_TAG_96.tag_len());
+        assert_eq!(8, AES_256_GCM_TAG_64.tag_len());
+        assert_ne!(AES_128_GCM, AES_128_GCM_TAG_96);
+
+        let full_key = LessSafeKey::new(UnboundKey::new(&AES_128_GCM, &key).unwrap());
+        let mut full_in_out = plaintext.clone();
+        let full_tag = full_key
+            .seal_in_place_separate_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut full_in_out,
+            )
+            .unwrap();
+
+        for alg in [&AES_128_GCM_TAG_96, &AES_128_GCM_TAG_64] {
+            let key = LessSafeKey::new(UnboundKey::new(alg, &key).unwrap());
+
+            let mut in_out = plaintext.clone();
+            key.seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut in_out,
+            )
+            .unwrap();
+            assert_eq!(plaintext.len() + alg.tag_len(), in_out.len());
+
+            // A truncated GCM tag is a prefix of the full-length tag.
+            let (ciphertext, tag) = in_out.split_at(plaintext.len());
+            assert_eq!(full_in_out.as_slice(), ciphertext);
+            assert_eq!(&full_tag.as_ref()[..alg.tag_len()], tag);
+
+            let mut out_plaintext = vec![0u8; plaintext.len()];
+            key.open_separate_gather(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                ciphertext,
+                tag,
+                &mut out_plaintext,
+            )
+            .unwrap();
+            assert_eq!(plaintext, out_plaintext);
+
+            // A tag of any other length is rejected.
+            assert!(key
+                .open_separate_gather(
+                    Nonce::assume_unique_for_key(nonce),
+                    Aad::empty(),
+                    ciphertext,
+                    full_tag.as_ref(),
+                    &mut out_plaintext,
+                )
+                .is_err());
+            assert!(key
+                .open_separate_gather(
+                    Nonce::assume_unique_for_key(nonce),
+                    Aad::empty(),
+                    ciphertext,
+                    &tag[..alg.tag_len() - 1],
+                    &mut out_plaintext,
+                )
+                .is_err());
+
+            // The full-length key does not accept the truncated tag.
+            let mut truncated = in_out.clone();
+            assert!(full_key
+                .open_in_place(
+                    Nonce::assume_unique_for_key(nonce),
+                    Aad::empty(),
+                    &mut truncated
+                )
+                .is_err());
+
+            let opened = key
+                .open_in_place(
+                    Nonce::assume_unique_for_key(nonce),
+                    Aad::empty(),
+                    &mut in_out,
+                )
+                .unwrap();
+            assert_eq!(plaintext.as_slice(), opened);
+        }
+    }
 }
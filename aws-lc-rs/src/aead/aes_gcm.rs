@@ -1,18 +1,31 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
-use crate::aead::{Algorithm, AlgorithmID};
+use crate::aead::{Algorithm, AlgorithmID, TAG_LEN};
 
 use crate::aead::aead_ctx::AeadCtx;
 use crate::cipher::aes::{AES_128_KEY_LEN, AES_256_KEY_LEN};
 use crate::error::Unspecified;
 
+/// The maximum length of the associated data for AES-GCM: 2^64 - 1 bits ([NIST SP 800-38D]
+/// Section 5.2.1.1), rounded down to whole bytes.
+///
+/// [NIST SP 800-38D]: https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38d.pdf
+const GCM_MAX_AAD_LEN: u64 = (1 << 61) - 1;
+
+/// The maximum length of the associated data for AES-GCM-SIV: 2^36 bytes ([RFC 8452] Section 6).
+///
+/// [RFC 8452]: https://www.rfc-editor.org/rfc/rfc8452#section-6
+const GCM_SIV_MAX_AAD_LEN: u64 = 1 << 36;
+
 /// AES-128 in GCM mode with 128-bit tags and 96 bit nonces.
 pub const AES_128_GCM: Algorithm = Algorithm {
     init: init_128_aead,
     key_len: AES_128_KEY_LEN,
     id: AlgorithmID::AES_128_GCM,
+    tag_len: TAG_LEN,
     max_input_len: u64::MAX,
+    max_aad_len: GCM_MAX_AAD_LEN,
 };
 
 /// AES-256 in GCM mode with 128-bit tags and 96 bit nonces.
@@ -20,7 +33,73 @@ pub const AES_256_GCM: Algorithm = Algorithm {
     init: init_256_aead,
     key_len: AES_256_KEY_LEN,
     id: AlgorithmID::AES_256_GCM,
+    tag_len: TAG_LEN,
+    max_input_len: u64::MAX,
+    max_aad_len: GCM_MAX_AAD_LEN,
+};
+
+/// AES-128 in GCM mode with tags truncated to 96 bits and 96 bit nonces.
+///
+/// Truncated tags reduce the forgery resistance of the AEAD, and [NIST SP 800-38D] Appendix C
+/// bounds the message length and the number of authenticated decryptions for them. Use this
+/// only for protocols that mandate truncated tags, such as SRTP.
+///
+/// [NIST SP 800-38D]: https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38d.pdf
+pub const AES_128_GCM_TAG_96: Algorithm = Algorithm {
+    init: init_128_aead,
+    key_len: AES_128_KEY_LEN,
+    id: AlgorithmID::AES_128_GCM_TAG_96,
+    tag_len: 96 / 8,
+    max_input_len: u64::MAX,
+    max_aad_len: GCM_MAX_AAD_LEN,
+};
+
+/// AES-256 in GCM mode with tags truncated to 96 bits and 96 bit nonces.
+///
+/// Truncated tags reduce the forgery resistance of the AEAD, and [NIST SP 800-38D] Appendix C
+/// bounds the message length and the number of authenticated decryptions for them. Use this
+/// only for protocols that mandate truncated tags, such as SRTP.
+///
+/// [NIST SP 800-38D]: https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38d.pdf
+pub const AES_256_GCM_TAG_96: Algorithm = Algorithm {
+    init: init_256_aead,
+    key_len: AES_256_KEY_LEN,
+    id: AlgorithmID::AES_256_GCM_TAG_96,
+    tag_len: 96 / 8,
+    max_input_len: u64::MAX,
+    max_aad_len: GCM_MAX_AAD_LEN,
+};
+
+/// AES-128 in GCM mode with tags truncated to 64 bits and 96 bit nonces.
+///
+/// Truncated tags reduce the forgery resistance of the AEAD, and [NIST SP 800-38D] Appendix C
+/// bounds the message length and the number of authenticated decryptions for them. Use this
+/// only for protocols that mandate truncated tags, such as SRTP.
+///
+/// [NIST SP 800-38D]: https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38d.pdf
+pub const AES_128_GCM_TAG_64: Algorithm = Algorithm {
+    init: init_128_aead,
+    key_len: AES_128_KEY_LEN,
+    id: AlgorithmID::AES_128_GCM_TAG_64,
+    tag_len: 64 / 8,
+    max_input_len: u64::MAX,
+    max_aad_len: GCM_MAX_AAD_LEN,
+};
+
+/// AES-256 in GCM mode with tags truncated to 64 bits and 96 bit nonces.
+///
+/// Truncated tags reduce the forgery resistance of the AEAD, and [NIST SP 800-38D] Appendix C
+/// bounds the message length and the number of authenticated decryptions for them. Use this
+/// only for protocols that mandate truncated tags, such as SRTP.
+///
+/// [NIST SP 800-38D]: https://nvlpubs.nist.gov/nistpubs/Legacy/SP/nistspecialpublication800-38d.pdf
+pub const AES_256_GCM_TAG_64: Algorithm = Algorithm {
+    init: init_256_aead,
+    key_len: AES_256_KEY_LEN,
+    id: AlgorithmID::AES_256_GCM_TAG_64,
+    tag_len: 64 / 8,
     max_input_len: u64::MAX,
+    max_aad_len: GCM_MAX_AAD_LEN,
 };
 
 /// AES-256 in GCM mode with nonce reuse resistance, 128-bit tags and 96 bit nonces.
@@ -28,7 +107,9 @@ pub const AES_256_GCM_SIV: Algorithm = Algorithm {
     init: init_256_aead_siv,
     key_len: AES_256_KEY_LEN,
     id: AlgorithmID::AES_256_GCM_SIV,
+    tag_len: TAG_LEN,
     max_input_len: u64::MAX,
+    max_aad_len: GCM_SIV_MAX_AAD_LEN,
 };
 
 /// AES-128 in GCM mode with nonce reuse resistance, 128-bit tags and 96 bit nonces.
@@ -36,7 +117,9 @@ pub const AES_128_GCM_SIV: Algorithm = Algorithm {
     init: init_128_aead_siv,
     key_len: AES_128_KEY_LEN,
     id: AlgorithmID::AES_128_GCM_SIV,
+    tag_len: TAG_LEN,
     max_input_len: u64::MAX,
+    max_aad_len: GCM_SIV_MAX_AAD_LEN,
 };
 
 #[inline]
@@ -5,7 +5,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
 use crate::aead::aead_ctx::AeadCtx;
-use crate::aead::{Algorithm, AlgorithmID};
+use crate::aead::{Algorithm, AlgorithmID, TAG_LEN};
 use crate::cipher::chacha::KEY_LEN;
 use crate::error;
 
@@ -18,7 +18,10 @@ pub const CHACHA20_POLY1305: Algorithm = Algorithm {
     init: init_chacha_aead,
     key_len: KEY_LEN,
     id: AlgorithmID::CHACHA20_POLY1305,
+    tag_len: TAG_LEN,
     max_input_len: u64::MAX,
+    // RFC 8439 Section 2.8 allows up to 2^64 - 1 bytes of associated data.
+    max_aad_len: u64::MAX,
 };
 
 #[inline]
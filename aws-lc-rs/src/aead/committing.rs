@@ -0,0 +1,331 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::aead::{Aad, Algorithm, LessSafeKey, Nonce, UnboundKey, MAX_KEY_LEN, NONCE_LEN};
+use crate::error::Unspecified;
+use crate::{constant_time, hkdf, hmac};
+use core::fmt::Debug;
+use zeroize::Zeroizing;
+
+/// The length of the key commitment prepended by [`CommittingKey`].
+pub const COMMITMENT_LEN: usize = 32;
+
+const COMMITMENT_LABEL: &[u8] = b"aws-lc-rs AEAD key commitment";
+
+const KEY_DERIVATION_SALT: &[u8] = b"aws-lc-rs committing AEAD";
+const ENCRYPTION_KEY_LABEL: &[u8] = b"encryption key";
+const COMMITMENT_KEY_LABEL: &[u8] = b"commitment key";
+
+/// An AEAD key whose ciphertexts commit to the key they were sealed with.
+///
+/// Standard AEADs such as AES-GCM and ChaCha20-Poly1305 are not key-committing: an attacker
+/// can construct a single ciphertext that opens successfully under several different keys.
+/// This enables "partitioning oracle" attacks, and breaks schemes where a ciphertext is expected
+/// to be meaningful to only one of several recipients.
+///
+/// `CommittingKey` wraps any of this module's AEAD algorithms. The given key is not used
+/// directly; an encryption key for the underlying AEAD and a separate commitment key are derived
+/// from it, so that no key is shared between the AEAD and HMAC:
+///
+/// ```ascii-art
+/// prk            = HKDF-Extract(salt = "aws-lc-rs committing AEAD", key)
+/// encryption_key = HKDF-Expand(prk, "encryption key", algorithm.key_len())
+/// commitment_key = HKDF-Expand(prk, "commitment key", 32)
+/// ```
+///
+/// using HKDF-SHA256. When sealing, it prepends a commitment to the ciphertext:
+///
+/// ```ascii-art
+/// commitment = HMAC-SHA256(commitment_key, "aws-lc-rs AEAD key commitment" || nonce)
+/// output     = commitment || ciphertext || tag
+/// ```
+///
+/// where the ciphertext and tag are produced with `encryption_key`. When opening, the
+/// commitment is recomputed and compared in constant time before the underlying AEAD is used.
+/// Finding a ciphertext that opens under two keys therefore requires finding an HMAC-SHA256
+/// collision between those keys. This adds [`COMMITMENT_LEN`] bytes to each ciphertext.
+///
+/// The nonce requirements of the underlying algorithm are unchanged.
+pub struct CommittingKey {
+    key: LessSafeKey,
+    commitment_key: hmac::Key,
+}
+
+impl CommittingKey {
+    /// Constructs a `CommittingKey` for `algorithm` from `key_bytes`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `key_bytes.len() != algorithm.key_len()`.
+    pub fn new(algorithm: &'static Algorithm, key_bytes: &[u8]) -> Result<Self, Unspecified> {
+        let mut encryption_key = Zeroizing::new([0u8; MAX_KEY_LEN]);
+        let encryption_key = &mut encryption_key[..algorithm.key_len()];
+        let mut commitment_key = Zeroizing::new([0u8; COMMITMENT_LEN]);
+        derive_keys(key_bytes, encryption_key, commitment_key.as_mut())?;
+
+        Ok(Self {
+            key: LessSafeKey::new(UnboundKey::new(algorithm, encryption_key)?),
+            commitment_key: hmac::Key::new(hmac::HMAC_SHA256, commitment_key.as_ref()),
+        })
+    }
+
+    fn commitment(&self, nonce: &[u8; NONCE_LEN]) -> hmac::Tag {
+        let mut ctx = hmac::Context::with_key(&self.commitment_key);
+        ctx.update(COMMITMENT_LABEL);
+        ctx.update(nonce);
+        ctx.sign()
+    }
+
+    /// Encrypts and signs (“seals”) data in place, prepending the key commitment and
+    /// appending the tag.
+    ///
+    /// On input, `in_out` must contain the plaintext. On success, it contains the
+    /// commitment, followed by the ciphertext, followed by the tag.
+    ///
+    /// `nonce` must be unique for every use of the key to seal data.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if encryption operation fails.
+    pub fn seal_in_place_append_tag<A, InOut>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &mut InOut,
+    ) -> Result<(), Unspecified>
+    where
+        A: AsRef<[u8]>,
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        let commitment = self.commitment(nonce.as_ref());
+        self.key.seal_in_place_append_tag(nonce, aad, in_out)?;
+
+        // `in_out` only supports appending, so append the commitment and rotate it into place.
+        in_out.extend(commitment.as_ref());
+        in_out.as_mut().rotate_right(COMMITMENT_LEN);
+        Ok(())
+    }
+
+    /// Verifies the key commitment, then authenticates and decrypts (“opens”) data in place.
+    ///
+    /// On input, `in_out` must be the commitment followed by the ciphertext followed by the
+    /// tag. When `open_in_place()` returns `Ok(plaintext)`, the plaintext has been written to
+    /// the start of `in_out`; `plaintext` refers to it, without the commitment or the tag.
+    ///
+    /// # Errors
+    /// `error::Unspecified` when the commitment does not match this key, or the ciphertext is
+    /// invalid. In the latter case, `in_out` may have been overwritten in an unspecified way.
+    pub fn open_in_place<'in_out, A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        if in_out.len() < COMMITMENT_LEN {
+            return Err(Unspecified);
+        }
+        let commitment = self.commitment(nonce.as_ref());
+        constant_time::verify_slices_are_equal(commitment.as_ref(), &in_out[..COMMITMENT_LEN])?;
+        self.key.open_within(nonce, aad, in_out, COMMITMENT_LEN..)
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    #[must_use]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.key.algorithm()
+    }
+}
+
+/// Derives the encryption key and the commitment key from `key_bytes`, whose length must be that
+/// of the encryption key.
+fn derive_keys(
+    key_bytes: &[u8],
+    encryption_key: &mut [u8],
+    commitment_key: &mut [u8],
+) -> Result<(), Unspecified> {
+    if key_bytes.len() != encryption_key.len() {
+        return Err(Unspecified);
+    }
+    let prk = hkdf::Salt::new(hkdf::HKDF_SHA256, KEY_DERIVATION_SALT).extract(key_bytes);
+    let derivation = hkdf::KeyDerivation::new(prk);
+    encryption_key
+        .copy_from_slice(&derivation.derive_key(ENCRYPTION_KEY_LABEL, encryption_key.len())?);
+    commitment_key
+        .copy_from_slice(&derivation.derive_key(COMMITMENT_KEY_LABEL, commitment_key.len())?);
+    Ok(())
+}
+
+impl Debug for CommittingKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.debug_struct("CommittingKey")
+            .field("algorithm", self.algorithm())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::derive_keys;
+    use crate::aead::{
+        Aad, CommittingKey, LessSafeKey, Nonce, UnboundKey, AES_128_GCM, AES_256_GCM_SIV,
+        CHACHA20_POLY1305, COMMITMENT_LEN,
+    };
+    use crate::test::from_hex;
+
+    const NONCE: [u8; 12] = [
+        0x5b, 0xf1, 0x1a, 0x09, 0x51, 0xf0, 0xbf, 0xc7, 0xea, 0x5c, 0x9e, 0x58,
+    ];
+
+    #[test]
+    fn round_trip() {
+        for alg in [&AES_128_GCM, &AES_256_GCM_SIV, &CHACHA20_POLY1305] {
+            let key_bytes = vec![0x42u8; alg.key_len()];
+            let key = CommittingKey::new(alg, &key_bytes).unwrap();
+            assert_eq!(alg, key.algorithm());
+
+            let plaintext = b"committing plaintext";
+            let mut in_out = plaintext.to_vec();
+            key.seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(NONCE),
+                Aad::from(b"aad"),
+                &mut in_out,
+            )
+            .unwrap();
+            assert_eq!(
+                COMMITMENT_LEN + plaintext.len() + alg.tag_len(),
+                in_out.len()
+            );
+
+            let mut tampered = in_out.clone();
+            tampered[0] ^= 1;
+            assert!(key
+                .open_in_place(
+                    Nonce::assume_unique_for_key(NONCE),
+                    Aad::from(b"aad"),
+                    &mut tampered
+                )
+                .is_err());
+
+            let opened = key
+                .open_in_place(
+                    Nonce::assume_unique_for_key(NONCE),
+                    Aad::from(b"aad"),
+                    &mut in_out,
+                )
+                .unwrap();
+            assert_eq!(plaintext, opened);
+        }
+    }
+
+    #[test]
+    fn too_short() {
+        let key = CommittingKey::new(&AES_128_GCM, &[0u8; 16]).unwrap();
+        let mut in_out = [0u8; COMMITMENT_LEN - 1];
+        assert!(key
+            .open_in_place(
+                Nonce::assume_unique_for_key(NONCE),
+                Aad::empty(),
+                &mut in_out
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn derived_keys() {
+        // Computed independently with Python's `hmac` and `hashlib` modules.
+        let key_bytes = from_hex("000102030405060708090a0b0c0d0e0f").unwrap();
+        let mut encryption_key = [0u8; 16];
+        let mut commitment_key = [0u8; COMMITMENT_LEN];
+        derive_keys(&key_bytes, &mut encryption_key, &mut commitment_key).unwrap();
+        assert_eq!(
+            from_hex("f15e11e9783fbada44fc63e24c676417").unwrap(),
+            encryption_key
+        );
+        assert_eq!(
+            from_hex("2286d2497dc01afb7b3b798f21c91b22aafbea28a7c02f2c865cf7082860207b").unwrap(),
+            commitment_key
+        );
+        assert_ne!(key_bytes, encryption_key);
+        assert_ne!(encryption_key, commitment_key[..encryption_key.len()]);
+
+        assert!(derive_keys(&key_bytes[1..], &mut encryption_key, &mut commitment_key).is_err());
+
+        // The underlying AEAD uses the derived encryption key, not the given key.
+        let committing = CommittingKey::new(&AES_128_GCM, &key_bytes).unwrap();
+        let mut committed = b"plaintext".to_vec();
+        committing
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(NONCE),
+                Aad::empty(),
+                &mut committed,
+            )
+            .unwrap();
+        let mut sealed = b"plaintext".to_vec();
+        LessSafeKey::new(UnboundKey::new(&AES_128_GCM, &encryption_key).unwrap())
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(NONCE),
+                Aad::empty(),
+                &mut sealed,
+            )
+            .unwrap();
+        assert_eq!(sealed, committed[COMMITMENT_LEN..]);
+    }
+
+    #[test]
+    fn partitioning_ciphertext_rejected() {
+        // A single-block AES-128-GCM ciphertext and tag, with empty AAD, that is valid under the
+        // encryption keys derived from both keys. It was constructed by solving the GHASH
+        // equations for both derived keys simultaneously.
+        let key1 = from_hex("000102030405060708090a0b0c0d0e0f").unwrap();
+        let key2 = from_hex("101112131415161718191a1b1c1d1e1f").unwrap();
+        let ciphertext_and_tag =
+            from_hex("795bf3dc2fb45d8037c68b6e782a967993d5f1485ffa9a476a0b695e7992500e").unwrap();
+
+        // The plain AEAD accepts the ciphertext under either derived key.
+        for (key_bytes, expected) in [
+            (&key1, "512704ad27d53a606e844695a4a07d0a"),
+            (&key2, "cd28a8584a5b0adc87a14129adf5b581"),
+        ] {
+            let mut encryption_key = [0u8; 16];
+            derive_keys(key_bytes, &mut encryption_key, &mut [0u8; COMMITMENT_LEN]).unwrap();
+            let key = LessSafeKey::new(UnboundKey::new(&AES_128_GCM, &encryption_key).unwrap());
+            let mut in_out = ciphertext_and_tag.clone();
+            let plaintext = key
+                .open_in_place(
+                    Nonce::assume_unique_for_key(NONCE),
+                    Aad::empty(),
+                    &mut in_out,
+                )
+                .unwrap();
+            assert_eq!(from_hex(expected).unwrap(), plaintext);
+        }
+
+        // With a commitment to either key, only that key can open it.
+        let committing1 = CommittingKey::new(&AES_128_GCM, &key1).unwrap();
+        let committing2 = CommittingKey::new(&AES_128_GCM, &key2).unwrap();
+        for (committer, other) in [(&committing1, &committing2), (&committing2, &committing1)] {
+            let mut committed = committer.commitment(&NONCE).as_ref().to_vec();
+            committed.extend_from_slice(&ciphertext_and_tag);
+
+            let mut in_out = committed.clone();
+            assert!(other
+                .open_in_place(
+                    Nonce::assume_unique_for_key(NONCE),
+                    Aad::empty(),
+                    &mut in_out
+                )
+                .is_err());
+
+            let mut in_out = committed.clone();
+            assert!(committer
+                .open_in_place(
+                    Nonce::assume_unique_for_key(NONCE),
+                    Aad::empty(),
+                    &mut in_out
+                )
+                .is_ok());
+        }
+    }
+}
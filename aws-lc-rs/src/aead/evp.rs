@@ -0,0 +1,240 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Low-level access to *AWS-LC* `EVP_AEAD` algorithms.
+//!
+//! # ⚠️ Warning
+//! This module bypasses the algorithm, nonce and key-length checks provided by the rest of the
+//! `aead` module. It exists to allow experimentation with AEADs that *aws-lc-rs* does not expose
+//! statically. The caller is responsible for choosing an appropriate algorithm and for never
+//! reusing a nonce with the same key. Prefer [`crate::aead::LessSafeKey`] or
+//! [`crate::aead::SealingKey`]/[`crate::aead::OpeningKey`] whenever the algorithm is supported.
+//!
+//! This module is only available with the `low-level` feature, and its API is not covered by
+//! semantic versioning guarantees.
+
+use crate::error::Unspecified;
+use crate::fips::indicator_check;
+use crate::ptr::LcPtr;
+use aws_lc::{
+    EVP_AEAD_CTX_new, EVP_AEAD_CTX_open, EVP_AEAD_CTX_seal, EVP_AEAD_key_length,
+    EVP_AEAD_max_overhead, EVP_AEAD_max_tag_len, EVP_AEAD_nonce_length, EVP_AEAD, EVP_AEAD_CTX,
+    EVP_AEAD_DEFAULT_TAG_LENGTH,
+};
+use core::fmt::Debug;
+
+/// An `EVP_AEAD_CTX` initialized with an arbitrary `EVP_AEAD` and key.
+pub struct Ctx {
+    aead: *const EVP_AEAD,
+    ctx: LcPtr<EVP_AEAD_CTX>,
+}
+
+// `EVP_AEAD` values are immutable statics, and an initialized `EVP_AEAD_CTX` may be used for
+// concurrent seal and open operations.
+unsafe impl Send for Ctx {}
+unsafe impl Sync for Ctx {}
+
+impl Debug for Ctx {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Ctx")
+            .field("key_len", &self.key_len())
+            .field("nonce_len", &self.nonce_len())
+            .field("max_overhead", &self.max_overhead())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Ctx {
+    /// Initializes a context for `aead` with `key_bytes` and the algorithm's default tag length.
+    ///
+    /// # Safety
+    /// `aead` must be a valid pointer to an `EVP_AEAD` that lives for the rest of the program,
+    /// such as the value returned by one of *AWS-LC*'s `EVP_aead_*` functions.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `key_bytes` is not a valid key for `aead`.
+    pub unsafe fn new(aead: *const EVP_AEAD, key_bytes: &[u8]) -> Result<Self, Unspecified> {
+        Self::with_tag_len(aead, key_bytes, EVP_AEAD_DEFAULT_TAG_LENGTH as usize)
+    }
+
+    /// Initializes a context for `aead` with `key_bytes` and a tag length of `tag_len` bytes.
+    ///
+    /// # Safety
+    /// `aead` must be a valid pointer to an `EVP_AEAD` that lives for the rest of the program,
+    /// such as the value returned by one of *AWS-LC*'s `EVP_aead_*` functions.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `key_bytes` is not a valid key for `aead`, or if `aead` does not
+    /// support `tag_len`.
+    pub unsafe fn with_tag_len(
+        aead: *const EVP_AEAD,
+        key_bytes: &[u8],
+        tag_len: usize,
+    ) -> Result<Self, Unspecified> {
+        if aead.is_null() {
+            return Err(Unspecified);
+        }
+
+        let ctx = LcPtr::new(EVP_AEAD_CTX_new(
+            aead,
+            key_bytes.as_ptr(),
+            key_bytes.len(),
+            tag_len,
+        ))?;
+
+        Ok(Self { aead, ctx })
+    }
+
+    /// The length of the key expected by the algorithm.
+    #[must_use]
+    pub fn key_len(&self) -> usize {
+        unsafe { EVP_AEAD_key_length(self.aead) }
+    }
+
+    /// The length of the nonce expected by the algorithm.
+    #[must_use]
+    pub fn nonce_len(&self) -> usize {
+        unsafe { EVP_AEAD_nonce_length(self.aead) }
+    }
+
+    /// The maximum number of bytes that sealing adds to a plaintext.
+    #[must_use]
+    pub fn max_overhead(&self) -> usize {
+        unsafe { EVP_AEAD_max_overhead(self.aead) }
+    }
+
+    /// The maximum tag length supported by the algorithm.
+    #[must_use]
+    pub fn max_tag_len(&self) -> usize {
+        unsafe { EVP_AEAD_max_tag_len(self.aead) }
+    }
+
+    /// Encrypts and authenticates `plaintext` and authenticates `aad`, returning the ciphertext
+    /// with the tag appended.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `nonce` is not valid for the algorithm, or if the underlying
+    /// operation fails.
+    pub fn seal(&self, nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, Unspecified> {
+        let max_out_len = plaintext
+            .len()
+            .checked_add(self.max_overhead())
+            .ok_or(Unspecified)?;
+        let mut out = vec![0u8; max_out_len];
+        let mut out_len = 0usize;
+
+        if 1 != indicator_check!(unsafe {
+            EVP_AEAD_CTX_seal(
+                *self.ctx.as_const(),
+                out.as_mut_ptr(),
+                &mut out_len,
+                max_out_len,
+                nonce.as_ptr(),
+                nonce.len(),
+                plaintext.as_ptr(),
+                plaintext.len(),
+                aad.as_ptr(),
+                aad.len(),
+            )
+        }) {
+            return Err(Unspecified);
+        }
+
+        out.truncate(out_len);
+        Ok(out)
+    }
+
+    /// Authenticates and decrypts `ciphertext` (with the tag appended) and authenticates `aad`,
+    /// returning the plaintext.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if authentication fails, or if `nonce` is not valid for the algorithm.
+    pub fn open(
+        &self,
+        nonce: &[u8],
+        aad: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, Unspecified> {
+        let mut out = vec![0u8; ciphertext.len()];
+        let mut out_len = 0usize;
+
+        if 1 != indicator_check!(unsafe {
+            EVP_AEAD_CTX_open(
+                *self.ctx.as_const(),
+                out.as_mut_ptr(),
+                &mut out_len,
+                ciphertext.len(),
+                nonce.as_ptr(),
+                nonce.len(),
+                ciphertext.as_ptr(),
+                ciphertext.len(),
+                aad.as_ptr(),
+                aad.len(),
+            )
+        }) {
+            return Err(Unspecified);
+        }
+
+        out.truncate(out_len);
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ctx;
+    use crate::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_128_GCM};
+    use aws_lc::{EVP_aead_aes_128_gcm, EVP_aead_chacha20_poly1305};
+
+    #[test]
+    fn test_aes_128_gcm_round_trip() {
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+            0x0e, 0x0f,
+        ];
+        let nonce = [
+            0x5b, 0xf1, 0x1a, 0x09, 0x51, 0xf0, 0xbf, 0xc7, 0xea, 0x5c, 0x9e, 0x58,
+        ];
+        let aad = b"additional data";
+        let plaintext = b"low-level AEAD round trip";
+
+        let ctx = unsafe { Ctx::new(EVP_aead_aes_128_gcm(), &key) }.unwrap();
+        assert_eq!(16, ctx.key_len());
+        assert_eq!(12, ctx.nonce_len());
+        assert_eq!(16, ctx.max_overhead());
+        assert_eq!(16, ctx.max_tag_len());
+
+        let ciphertext = ctx.seal(&nonce, aad, plaintext).unwrap();
+        assert_eq!(plaintext.len() + 16, ciphertext.len());
+        assert_eq!(
+            plaintext,
+            ctx.open(&nonce, aad, &ciphertext).unwrap().as_slice()
+        );
+
+        // The output matches the statically registered algorithm.
+        let less_safe = LessSafeKey::new(UnboundKey::new(&AES_128_GCM, &key).unwrap());
+        let mut in_out = plaintext.to_vec();
+        less_safe
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(aad),
+                &mut in_out,
+            )
+            .unwrap();
+        assert_eq!(in_out, ciphertext);
+
+        let mut tampered = ciphertext.clone();
+        tampered[0] ^= 1;
+        assert!(ctx.open(&nonce, aad, &tampered).is_err());
+        assert!(ctx.open(&nonce, b"other data", &ciphertext).is_err());
+        assert!(ctx.open(&nonce[..8], aad, &ciphertext).is_err());
+    }
+
+    #[test]
+    fn test_invalid_key() {
+        assert!(unsafe { Ctx::new(EVP_aead_aes_128_gcm(), &[0u8; 15]) }.is_err());
+        assert!(unsafe { Ctx::new(EVP_aead_chacha20_poly1305(), &[0u8; 16]) }.is_err());
+        assert!(unsafe { Ctx::new(core::ptr::null(), &[0u8; 16]) }.is_err());
+        assert!(unsafe { Ctx::with_tag_len(EVP_aead_aes_128_gcm(), &[0u8; 16], 17) }.is_err());
+    }
+}
@@ -5,6 +5,8 @@
 
 mod counter32;
 mod counter64;
+mod single_use;
 
 pub use counter32::{Counter32, Counter32Builder};
 pub use counter64::{Counter64, Counter64Builder};
+pub use single_use::SingleUseRandom;
@@ -11,6 +11,8 @@ use crate::iv::FixedLength;
 /// values are used to construct each nonce.
 /// A limit can be set on the number of nonces allowed to be generated, by default this limit is
 /// `u32::MAX`.
+/// The counter does not wrap around: once the nonce for counter value `u32::MAX` has been
+/// generated, every further call to `advance` fails.
 ///
 /// See [Section 3.2 of RFC 5116](https://www.rfc-editor.org/rfc/rfc5116#section-3.2).
 #[allow(clippy::module_name_repetitions)]
@@ -19,6 +21,7 @@ pub struct Counter32 {
     generated: u32,
     identifier: [u8; 8],
     counter: u32,
+    exhausted: bool,
 }
 
 /// `NonceSequenceBuilder` facilitates the building of a `Counter32`.
@@ -77,6 +80,7 @@ impl Counter32Builder {
             generated: 0,
             identifier: self.identifier,
             counter: self.counter,
+            exhausted: false,
         }
     }
 }
@@ -110,14 +114,18 @@ impl Counter32 {
 impl NonceSequence for Counter32 {
     fn advance(&mut self) -> Result<Nonce, Unspecified> {
         self.generated = self.generated.checked_add(1).ok_or(Unspecified)?;
-        if self.generated > self.limit {
+        if self.generated > self.limit || self.exhausted {
             return Err(Unspecified);
         }
         let counter_bytes: [u8; 4] = self.counter.to_be_bytes();
         let mut nonce_bytes = [0u8; NONCE_LEN];
         nonce_bytes[..8].copy_from_slice(&self.identifier);
         nonce_bytes[8..].copy_from_slice(&counter_bytes);
-        self.counter = self.counter.wrapping_add(1);
+        // Wrapping around would repeat the counter values the sequence may have started from.
+        match self.counter.checked_add(1) {
+            Some(counter) => self.counter = counter,
+            None => self.exhausted = true,
+        }
         Ok(Nonce(FixedLength::from(nonce_bytes)))
     }
 }
@@ -195,4 +203,19 @@ mod tests {
         assert_eq!(1, cns.generated());
         assert!(cns.advance().is_err());
     }
+
+    #[test]
+    fn test_counter32_overflow() {
+        let mut cns = Counter32Builder::new().counter(u32::MAX - 1).build();
+        let nonce = cns.advance().unwrap();
+        assert_eq!(&(u32::MAX - 1).to_be_bytes(), &nonce.as_ref()[8..]);
+        let nonce = cns.advance().unwrap();
+        assert_eq!(&u32::MAX.to_be_bytes(), &nonce.as_ref()[8..]);
+        assert_eq!(u32::MAX, cns.counter());
+
+        // The counter would wrap around to zero, so the sequence is exhausted.
+        assert!(cns.advance().is_err());
+        assert!(cns.advance().is_err());
+        assert_eq!(u32::MAX, cns.counter());
+    }
 }
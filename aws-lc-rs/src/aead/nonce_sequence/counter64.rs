@@ -11,6 +11,8 @@ use crate::iv::FixedLength;
 /// values are used to construct each nonce.
 /// A limit can be set on the number of nonces allowed to be generated, by default this limit is
 /// `u64::MAX`.
+/// The counter does not wrap around: once the nonce for counter value `u64::MAX` has been
+/// generated, every further call to `advance` fails.
 /// See [Section 3.2 of RFC 5116](https://www.rfc-editor.org/rfc/rfc5116#section-3.2).
 #[allow(clippy::module_name_repetitions)]
 pub struct Counter64 {
@@ -18,6 +20,7 @@ pub struct Counter64 {
     generated: u64,
     identifier: [u8; 4],
     counter: u64,
+    exhausted: bool,
 }
 
 /// `NonceSequenceBuilder` facilitates the building of a `Counter64`.
@@ -76,6 +79,7 @@ impl Counter64Builder {
             generated: 0,
             identifier: self.identifier,
             counter: self.counter,
+            exhausted: false,
         }
     }
 }
@@ -109,14 +113,18 @@ impl Counter64 {
 impl NonceSequence for Counter64 {
     fn advance(&mut self) -> Result<Nonce, Unspecified> {
         self.generated = self.generated.checked_add(1).ok_or(Unspecified)?;
-        if self.generated > self.limit {
+        if self.generated > self.limit || self.exhausted {
             return Err(Unspecified);
         }
         let bytes: [u8; 8] = self.counter.to_be_bytes();
         let mut nonce_bytes = [0u8; NONCE_LEN];
         nonce_bytes[..4].copy_from_slice(&self.identifier);
         nonce_bytes[4..].copy_from_slice(&bytes);
-        self.counter = self.counter.wrapping_add(1);
+        // Wrapping around would repeat the counter values the sequence may have started from.
+        match self.counter.checked_add(1) {
+            Some(counter) => self.counter = counter,
+            None => self.exhausted = true,
+        }
         Ok(Nonce(FixedLength::from(nonce_bytes)))
     }
 }
@@ -184,4 +192,19 @@ mod tests {
         assert_eq!(1, cns.generated());
         assert!(cns.advance().is_err());
     }
+
+    #[test]
+    fn test_counter64_overflow() {
+        let mut cns = Counter64Builder::new().counter(u64::MAX - 1).build();
+        let nonce = cns.advance().unwrap();
+        assert_eq!(&(u64::MAX - 1).to_be_bytes(), &nonce.as_ref()[4..]);
+        let nonce = cns.advance().unwrap();
+        assert_eq!(&u64::MAX.to_be_bytes(), &nonce.as_ref()[4..]);
+        assert_eq!(u64::MAX, cns.counter());
+
+        // The counter would wrap around to zero, so the sequence is exhausted.
+        assert!(cns.advance().is_err());
+        assert!(cns.advance().is_err());
+        assert_eq!(u64::MAX, cns.counter());
+    }
 }
@@ -0,0 +1,110 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::aead::{Nonce, NonceSequence, NONCE_LEN};
+use crate::error::Unspecified;
+use crate::iv::FixedLength;
+use crate::rand;
+
+/// `SingleUseRandom` is an implementation of the `NonceSequence` trait for keys that protect
+/// exactly one message.
+/// A random 96-bit nonce is generated when the `SingleUseRandom` is constructed. The first call
+/// to `advance` returns that nonce, and every subsequent call fails. The nonce can be read with
+/// `nonce` so that it can be sent along with the ciphertext.
+///
+/// Random nonces must only be used when the number of messages protected under a key is
+/// small; a `SingleUseRandom` guarantees that number is one.
+#[allow(clippy::module_name_repetitions)]
+pub struct SingleUseRandom {
+    nonce: [u8; NONCE_LEN],
+    used: bool,
+}
+
+impl SingleUseRandom {
+    /// Constructs a `SingleUseRandom` with a newly generated random nonce.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the random nonce could not be generated.
+    pub fn new() -> Result<Self, Unspecified> {
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::fill(&mut nonce)?;
+        Ok(Self { nonce, used: false })
+    }
+
+    /// Provides the nonce that is (or was) returned by the first call to `advance`.
+    #[must_use]
+    pub fn nonce(&self) -> [u8; NONCE_LEN] {
+        self.nonce
+    }
+
+    /// Indicates whether the nonce has been returned by `advance`.
+    #[must_use]
+    pub fn used(&self) -> bool {
+        self.used
+    }
+}
+
+impl NonceSequence for SingleUseRandom {
+    fn advance(&mut self) -> Result<Nonce, Unspecified> {
+        if self.used {
+            return Err(Unspecified);
+        }
+        self.used = true;
+        Ok(Nonce(FixedLength::from(self.nonce)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aead::nonce_sequence::SingleUseRandom;
+    use crate::aead::{
+        Aad, BoundKey, LessSafeKey, Nonce, NonceSequence, SealingKey, UnboundKey, AES_128_GCM,
+    };
+
+    const KEY: [u8; 16] = [
+        0xa5, 0xf3, 0x8d, 0x0d, 0x2d, 0x7c, 0x48, 0x56, 0xe7, 0xf3, 0xc3, 0x63, 0x0d, 0x40, 0x5b,
+        0x9e,
+    ];
+
+    #[test]
+    fn test_single_use_random() {
+        let mut nonce_sequence = SingleUseRandom::new().unwrap();
+        let expected = nonce_sequence.nonce();
+        assert!(!nonce_sequence.used());
+        assert_eq!(&expected, nonce_sequence.advance().unwrap().as_ref());
+        assert!(nonce_sequence.used());
+        assert!(nonce_sequence.advance().is_err());
+        assert_eq!(expected, nonce_sequence.nonce());
+
+        assert_ne!(expected, SingleUseRandom::new().unwrap().nonce());
+    }
+
+    #[test]
+    fn test_single_use_random_sealing_key() {
+        let nonce_sequence = SingleUseRandom::new().unwrap();
+        let nonce = nonce_sequence.nonce();
+        let mut sealing_key =
+            SealingKey::new(UnboundKey::new(&AES_128_GCM, &KEY).unwrap(), nonce_sequence);
+
+        let mut in_out = b"single message".to_vec();
+        sealing_key
+            .seal_in_place_append_tag(Aad::empty(), &mut in_out)
+            .unwrap();
+
+        let mut second = b"second message".to_vec();
+        assert!(sealing_key
+            .seal_in_place_append_tag(Aad::empty(), &mut second)
+            .is_err());
+        assert_eq!(b"second message", second.as_slice());
+
+        let opening_key = LessSafeKey::new(UnboundKey::new(&AES_128_GCM, &KEY).unwrap());
+        let plaintext = opening_key
+            .open_in_place(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut in_out,
+            )
+            .unwrap();
+        assert_eq!(b"single message", plaintext);
+    }
+}
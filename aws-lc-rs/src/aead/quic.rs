@@ -3,16 +3,21 @@
 // Modifications copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
-//! QUIC Header Protection.
+//! QUIC Header Protection and Packet Protection.
 //!
-//! See draft-ietf-quic-tls.
+//! See [RFC 9001](https://www.rfc-editor.org/rfc/rfc9001).
 
+use crate::aead::{
+    Aad, LessSafeKey, Nonce, Tag, UnboundKey, AES_128_GCM, AES_256_GCM, CHACHA20_POLY1305,
+    NONCE_LEN,
+};
 use crate::cipher::aes::encrypt_block_aes;
 use crate::cipher::block;
 use crate::cipher::chacha::encrypt_block_chacha20;
 use crate::cipher::key::SymmetricCipherKey;
 use crate::hkdf::KeyType;
 use crate::{derive_debug_via_id, error, hkdf};
+use zeroize::Zeroizing;
 
 /// A key for generating QUIC Header Protection masks.
 pub struct HeaderProtectionKey {
@@ -22,7 +27,7 @@ pub struct HeaderProtectionKey {
 
 impl From<hkdf::Okm<'_, &'static Algorithm>> for HeaderProtectionKey {
     fn from(okm: hkdf::Okm<&'static Algorithm>) -> Self {
-        let mut key_bytes = [0; super::MAX_KEY_LEN];
+        let mut key_bytes = Zeroizing::new([0; super::MAX_KEY_LEN]);
         let algorithm = *okm.len();
         let key_bytes = &mut key_bytes[..algorithm.key_len()];
         okm.fill(key_bytes).unwrap();
@@ -31,6 +36,33 @@ impl From<hkdf::Okm<'_, &'static Algorithm>> for HeaderProtectionKey {
 }
 
 impl HeaderProtectionKey {
+    /// Derives the header protection key from a QUIC traffic `secret` (RFC 9001, Section 5.1).
+    ///
+    /// The hash used for the derivation is SHA-384 for `AES_256` and SHA-256 otherwise,
+    /// matching the TLS 1.3 cipher suites. Header protection keys are not changed by a key
+    /// update (RFC 9001, Section 6).
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `secret` length is not the output length of the hash.
+    pub fn from_secret(
+        algorithm: &'static Algorithm,
+        secret: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        let hkdf_algorithm = if algorithm == &AES_256 {
+            hkdf::HKDF_SHA384
+        } else {
+            hkdf::HKDF_SHA256
+        };
+        if secret.len() != hkdf_algorithm.len() {
+            return Err(error::Unspecified);
+        }
+        let prk = hkdf::Prk::new_less_safe(hkdf_algorithm, secret);
+        let mut key_bytes = Zeroizing::new([0; super::MAX_KEY_LEN]);
+        let key_bytes = &mut key_bytes[..algorithm.key_len()];
+        prk.expand_label(b"quic hp", &[], key_bytes)?;
+        Self::new(algorithm, key_bytes)
+    }
+
     /// Create a new header protection key.
     ///
     /// # Errors
@@ -48,12 +80,15 @@ impl HeaderProtectionKey {
     /// Generate a new QUIC Header Protection mask.
     ///
     /// # Errors
-    /// `error::Unspecified` when `sample` length is not `self.algorithm().sample_len()`.
+    /// `error::Unspecified` when `sample` length is not `self.algorithm().sample_len()`, or if
+    /// the underlying cipher fails to produce the mask.
     #[inline]
     pub fn new_mask(&self, sample: &[u8]) -> Result<[u8; 5], error::Unspecified> {
-        let sample = <&[u8; SAMPLE_LEN]>::try_from(sample)?;
+        if sample.len() != self.algorithm.sample_len() {
+            return Err(error::Unspecified);
+        }
 
-        cipher_new_mask(&self.inner, *sample)
+        cipher_new_mask(&self.inner, sample)
     }
 
     /// The key's algorithm.
@@ -64,6 +99,170 @@ impl HeaderProtectionKey {
     }
 }
 
+/// A key for QUIC packet protection (RFC 9001, Section 5.3).
+///
+/// The nonce for each packet is formed by XOR-ing the packet number, encoded as a big-endian
+/// 62-bit integer left-padded with zeros, with the IV.
+pub struct PacketKey {
+    key: LessSafeKey,
+    iv: [u8; NONCE_LEN],
+    secret: Option<TrafficSecret>,
+}
+
+/// The traffic secret a `PacketKey` was derived from, kept for key updates.
+struct TrafficSecret {
+    algorithm: hkdf::Algorithm,
+    value: Zeroizing<Vec<u8>>,
+}
+
+impl PacketKey {
+    /// Constructs a new packet protection key.
+    ///
+    /// `algorithm` must be one of the AEADs used by TLS 1.3: `AES_128_GCM`, `AES_256_GCM` or
+    /// `CHACHA20_POLY1305`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `algorithm` is not supported, if `key_bytes` length is not
+    /// `algorithm.key_len()`, or if `iv` length is not `NONCE_LEN`.
+    pub fn new(
+        algorithm: &'static super::Algorithm,
+        key_bytes: &[u8],
+        iv: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        if ![&AES_128_GCM, &AES_256_GCM, &CHACHA20_POLY1305].contains(&algorithm) {
+            return Err(error::Unspecified);
+        }
+        Ok(Self {
+            key: LessSafeKey::new(UnboundKey::new(algorithm, key_bytes)?),
+            iv: <[u8; NONCE_LEN]>::try_from(iv)?,
+            secret: None,
+        })
+    }
+
+    /// Derives the packet protection key and IV from a QUIC traffic `secret`
+    /// (RFC 9001, Section 5.1).
+    ///
+    /// The hash used for the derivation is SHA-384 for `AES_256_GCM` and SHA-256 otherwise,
+    /// matching the TLS 1.3 cipher suites. Keys constructed this way support
+    /// [`PacketKey::next_generation`].
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `algorithm` is not supported, or if `secret` length is not the
+    /// output length of the hash.
+    pub fn from_secret(
+        algorithm: &'static super::Algorithm,
+        secret: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        let hkdf_algorithm = if algorithm == &AES_256_GCM {
+            hkdf::HKDF_SHA384
+        } else {
+            hkdf::HKDF_SHA256
+        };
+        if secret.len() != hkdf_algorithm.len() {
+            return Err(error::Unspecified);
+        }
+        let prk = hkdf::Prk::new_less_safe(hkdf_algorithm, secret);
+        let mut key_bytes = Zeroizing::new([0; super::MAX_KEY_LEN]);
+        let key_bytes = &mut key_bytes[..algorithm.key_len()];
+        prk.expand_label(b"quic key", &[], key_bytes)?;
+        let mut iv = [0u8; NONCE_LEN];
+        prk.expand_label(b"quic iv", &[], &mut iv)?;
+
+        let mut key = Self::new(algorithm, key_bytes, &iv)?;
+        key.secret = Some(TrafficSecret {
+            algorithm: hkdf_algorithm,
+            value: Zeroizing::new(secret.to_vec()),
+        });
+        Ok(key)
+    }
+
+    /// Derives the key for the next key phase (RFC 9001, Section 6).
+    ///
+    /// The next traffic secret is derived from the current one with the "quic ku" label, and
+    /// the packet protection key and IV are derived from it. The header protection key is not
+    /// updated.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if this key was not constructed with [`PacketKey::from_secret`].
+    pub fn next_generation(&self) -> Result<Self, error::Unspecified> {
+        let secret = self.secret.as_ref().ok_or(error::Unspecified)?;
+        let mut next_secret = Zeroizing::new(vec![0u8; secret.value.len()]);
+        hkdf::Prk::new_less_safe(secret.algorithm, &secret.value).expand_label(
+            b"quic ku",
+            &[],
+            &mut next_secret,
+        )?;
+        Self::from_secret(self.algorithm(), &next_secret)
+    }
+
+    /// Encrypts `payload` in place, authenticating `header`, and returns the tag.
+    ///
+    /// `header` is the unprotected packet header, including the packet number.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if encryption fails.
+    pub fn encrypt_in_place(
+        &self,
+        packet_number: u64,
+        header: &[u8],
+        payload: &mut [u8],
+    ) -> Result<Tag, error::Unspecified> {
+        self.key
+            .seal_in_place_separate_tag(self.nonce(packet_number), Aad::from(header), payload)
+    }
+
+    /// Decrypts `payload` in place, authenticating `header`, and returns the plaintext.
+    ///
+    /// `payload` is the ciphertext followed by the tag. On success the plaintext is
+    /// the prefix of `payload` of length `payload.len() - self.tag_len()`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `payload` or `header` fail authentication.
+    pub fn decrypt_in_place<'in_out>(
+        &self,
+        packet_number: u64,
+        header: &[u8],
+        payload: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], error::Unspecified> {
+        self.key
+            .open_in_place(self.nonce(packet_number), Aad::from(header), payload)
+    }
+
+    /// The length of the tag appended to each packet payload.
+    #[inline]
+    #[must_use]
+    pub fn tag_len(&self) -> usize {
+        self.algorithm().tag_len()
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    #[must_use]
+    pub fn algorithm(&self) -> &'static super::Algorithm {
+        self.key.algorithm()
+    }
+
+    fn nonce(&self, packet_number: u64) -> Nonce {
+        let mut nonce = self.iv;
+        for (n, p) in nonce[NONCE_LEN - 8..]
+            .iter_mut()
+            .zip(packet_number.to_be_bytes())
+        {
+            *n ^= p;
+        }
+        Nonce::assume_unique_for_key(nonce)
+    }
+}
+
+impl core::fmt::Debug for PacketKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PacketKey")
+            .field("algorithm", self.algorithm())
+            .finish_non_exhaustive()
+    }
+}
+
+// All the header protection algorithms we support sample 128 bits of ciphertext.
 const SAMPLE_LEN: usize = super::TAG_LEN;
 
 /// QUIC sample for new key masks
@@ -74,6 +273,7 @@ pub struct Algorithm {
     init: fn(key: &[u8]) -> Result<SymmetricCipherKey, error::Unspecified>,
 
     key_len: usize,
+    sample_len: usize,
     id: AlgorithmID,
 }
 
@@ -96,7 +296,7 @@ impl Algorithm {
     #[inline]
     #[must_use]
     pub fn sample_len(&self) -> usize {
-        SAMPLE_LEN
+        self.sample_len
     }
 }
 
@@ -123,6 +323,7 @@ impl Eq for Algorithm {}
 pub static AES_128: Algorithm = Algorithm {
     key_len: 16,
     init: SymmetricCipherKey::aes128,
+    sample_len: SAMPLE_LEN,
     id: AlgorithmID::AES_128,
 };
 
@@ -130,6 +331,7 @@ pub static AES_128: Algorithm = Algorithm {
 pub static AES_256: Algorithm = Algorithm {
     key_len: 32,
     init: SymmetricCipherKey::aes256,
+    sample_len: SAMPLE_LEN,
     id: AlgorithmID::AES_256,
 };
 
@@ -137,33 +339,30 @@ pub static AES_256: Algorithm = Algorithm {
 pub static CHACHA20: Algorithm = Algorithm {
     key_len: 32,
     init: SymmetricCipherKey::chacha20,
+    sample_len: SAMPLE_LEN,
     id: AlgorithmID::CHACHA20,
 };
 
 #[inline]
 fn cipher_new_mask(
     cipher_key: &SymmetricCipherKey,
-    sample: Sample,
+    sample: &[u8],
 ) -> Result<[u8; 5], error::Unspecified> {
-    let block = block::Block::from(&sample);
+    let sample = <&Sample>::try_from(sample)?;
+    let block = block::Block::from(sample);
 
     let encrypted_block = match cipher_key {
         SymmetricCipherKey::Aes128 { enc_key, .. } | SymmetricCipherKey::Aes256 { enc_key, .. } => {
             encrypt_block_aes(enc_key, block)
         }
         SymmetricCipherKey::ChaCha20 { raw_key } => {
+            // RFC 9001, Section 5.4.4: the first 4 bytes of the sample are the block counter
+            // (little-endian) and the remaining 12 bytes are the nonce.
             let plaintext = block.as_ref();
-            let counter_bytes: &[u8; 4] = plaintext[0..=3]
-                .try_into()
-                .map_err(|_| error::Unspecified)?;
-            let nonce: &[u8; 12] = plaintext[4..=15]
-                .try_into()
-                .map_err(|_| error::Unspecified)?;
+            let counter = u32::from_le_bytes(<[u8; 4]>::try_from(&plaintext[0..=3])?);
+            let nonce = <&[u8; 12]>::try_from(&plaintext[4..=15])?;
             let input = block::Block::zero();
-            unsafe {
-                let counter = core::mem::transmute::<[u8; 4], u32>(*counter_bytes).to_le();
-                encrypt_block_chacha20(raw_key, input, nonce, counter)?
-            }
+            encrypt_block_chacha20(raw_key, input, nonce, counter)?
         }
     };
 
@@ -174,9 +373,45 @@ fn cipher_new_mask(
 
 #[cfg(test)]
 mod test {
-    use crate::aead::quic::{Algorithm, HeaderProtectionKey};
+    use crate::aead::quic::{
+        Algorithm, HeaderProtectionKey, PacketKey, AES_128, AES_256, CHACHA20,
+    };
     use crate::test;
 
+    #[test]
+    fn test_sample_len() {
+        for alg in [&AES_128, &AES_256, &CHACHA20] {
+            assert_eq!(16, alg.sample_len());
+
+            let key = HeaderProtectionKey::new(alg, &vec![0u8; alg.key_len()]).unwrap();
+            assert!(key.new_mask(&vec![0u8; alg.sample_len()]).is_ok());
+            for len in [
+                0,
+                alg.sample_len() - 1,
+                alg.sample_len() + 1,
+                2 * alg.sample_len(),
+            ] {
+                assert!(key.new_mask(&vec![0u8; len]).is_err(), "{alg:?} {len}");
+            }
+        }
+    }
+
+    // RFC 9001, Appendix A.5.
+    #[test]
+    fn test_chacha20_mask() {
+        let key = HeaderProtectionKey::new(
+            &CHACHA20,
+            &test::from_hex("25a282b9e82f06f21f488917a4fc8f1b73573685608597d0efcb076b0ab7a7a4")
+                .unwrap(),
+        )
+        .unwrap();
+        let sample = test::from_hex("5e5cd55c41f69080575d7999c25a5bfb").unwrap();
+        assert_eq!(
+            test::from_hex("aefefe7d03").unwrap(),
+            key.new_mask(&sample).unwrap()
+        );
+    }
+
     #[test]
     fn test_types() {
         test::compile_time_assert_send::<Algorithm>();
@@ -184,5 +419,8 @@ mod test {
 
         test::compile_time_assert_send::<HeaderProtectionKey>();
         test::compile_time_assert_sync::<HeaderProtectionKey>();
+
+        test::compile_time_assert_send::<PacketKey>();
+        test::compile_time_assert_sync::<PacketKey>();
     }
 }
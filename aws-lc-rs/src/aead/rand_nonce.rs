@@ -35,7 +35,11 @@ impl RandomizedNonceKey {
                 algorithm.tag_len(),
                 algorithm.nonce_len(),
             ),
-            AlgorithmID::AES_128_GCM_SIV
+            AlgorithmID::AES_128_GCM_TAG_96
+            | AlgorithmID::AES_256_GCM_TAG_96
+            | AlgorithmID::AES_128_GCM_TAG_64
+            | AlgorithmID::AES_256_GCM_TAG_64
+            | AlgorithmID::AES_128_GCM_SIV
             | AlgorithmID::AES_256_GCM_SIV
             | AlgorithmID::CHACHA20_POLY1305 => return Err(Unspecified),
         }?;
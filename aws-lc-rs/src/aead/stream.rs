@@ -0,0 +1,360 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::aead::{Aad, Algorithm, LessSafeKey, Nonce, UnboundKey, NONCE_LEN};
+use crate::error::Unspecified;
+use core::fmt::Debug;
+use zeroize::Zeroize;
+
+/// The length of the nonce prefix that identifies a stream.
+pub const STREAM_NONCE_PREFIX_LEN: usize = 7;
+
+/// Returns `nonce_prefix || counter || last`, the nonce of a frame in the STREAM construction.
+fn frame_nonce(nonce_prefix: &[u8; STREAM_NONCE_PREFIX_LEN], counter: u32, last: bool) -> Nonce {
+    let mut nonce = [0u8; NONCE_LEN];
+    nonce[..STREAM_NONCE_PREFIX_LEN].copy_from_slice(nonce_prefix);
+    nonce[STREAM_NONCE_PREFIX_LEN..NONCE_LEN - 1].copy_from_slice(&counter.to_be_bytes());
+    nonce[NONCE_LEN - 1] = u8::from(last);
+    Nonce::assume_unique_for_key(nonce)
+}
+
+/// An AEAD key for encrypting a message as a stream of frames.
+///
+/// Each frame is sealed separately, using the nonce construction of the STREAM online
+/// authenticated encryption scheme of [Hoang, Reyhanitabar, Rogaway and Vizár]: the nonce of a
+/// frame is `nonce_prefix || counter || last`, where `counter` is the 32-bit big-endian index of
+/// the frame and `last` is `1` for the final frame and `0` otherwise. Frames therefore cannot be
+/// reordered, dropped, or truncated from the end of a stream without detection by
+/// [`StreamOpeningKey`].
+///
+/// The nonce prefix must be unique for every stream sealed with the same key. A stream has at
+/// most 2^32 frames.
+///
+/// [Hoang, Reyhanitabar, Rogaway and Vizár]: https://eprint.iacr.org/2015/189
+pub struct StreamSealingKey {
+    key: LessSafeKey,
+    nonce_prefix: [u8; STREAM_NONCE_PREFIX_LEN],
+    counter: u32,
+}
+
+impl StreamSealingKey {
+    /// Constructs a `StreamSealingKey` for the stream identified by `nonce_prefix`.
+    #[must_use]
+    pub fn new(key: UnboundKey, nonce_prefix: [u8; STREAM_NONCE_PREFIX_LEN]) -> Self {
+        Self {
+            key: LessSafeKey::new(key),
+            nonce_prefix,
+            counter: 0,
+        }
+    }
+
+    /// Seals the next frame of the stream, returning the ciphertext with the tag appended.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if only the final frame is left, or if encryption fails.
+    pub fn seal_frame<A>(&mut self, aad: Aad<A>, plaintext: &[u8]) -> Result<Vec<u8>, Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        // The last counter value is reserved for `seal_last_frame`.
+        if self.counter == u32::MAX {
+            return Err(Unspecified);
+        }
+        let nonce = frame_nonce(&self.nonce_prefix, self.counter, false);
+        self.counter += 1;
+        self.key.seal(nonce, aad, plaintext)
+    }
+
+    /// Seals the final frame of the stream, returning the ciphertext with the tag appended.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if encryption fails.
+    pub fn seal_last_frame<A>(self, aad: Aad<A>, plaintext: &[u8]) -> Result<Vec<u8>, Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        let nonce = frame_nonce(&self.nonce_prefix, self.counter, true);
+        self.key.seal(nonce, aad, plaintext)
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    #[must_use]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.key.algorithm()
+    }
+}
+
+impl Debug for StreamSealingKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.debug_struct("StreamSealingKey")
+            .field("algorithm", self.algorithm())
+            .finish_non_exhaustive()
+    }
+}
+
+/// An AEAD key for decrypting a stream of frames sealed by [`StreamSealingKey`].
+///
+/// Opened frames are buffered rather than released: the plaintext of the whole stream is only
+/// returned by `finish`, once every frame has been authenticated and the final frame has been
+/// opened. If any frame fails to open, the buffered plaintext is discarded and `finish` fails,
+/// so no unauthenticated or truncated plaintext is ever returned.
+pub struct StreamOpeningKey {
+    key: LessSafeKey,
+    nonce_prefix: [u8; STREAM_NONCE_PREFIX_LEN],
+    counter: u32,
+    plaintext: Vec<u8>,
+    state: OpeningState,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum OpeningState {
+    Open,
+    Complete,
+    Failed,
+}
+
+impl StreamOpeningKey {
+    /// Constructs a `StreamOpeningKey` for the stream identified by `nonce_prefix`.
+    #[must_use]
+    pub fn new(key: UnboundKey, nonce_prefix: [u8; STREAM_NONCE_PREFIX_LEN]) -> Self {
+        Self {
+            key: LessSafeKey::new(key),
+            nonce_prefix,
+            counter: 0,
+            plaintext: Vec::new(),
+            state: OpeningState::Open,
+        }
+    }
+
+    /// Authenticates and decrypts the next frame of the stream, buffering its plaintext.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the frame is invalid, or if the stream has already failed or
+    /// been completed. After an error, `finish` fails.
+    pub fn open_frame<A>(&mut self, aad: Aad<A>, frame: &[u8]) -> Result<(), Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        self.open(aad, frame, false)
+    }
+
+    /// Authenticates and decrypts the final frame of the stream, buffering its plaintext.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the frame is invalid (including if it is not the final frame), or
+    /// if the stream has already failed or been completed. After an error, `finish` fails.
+    pub fn open_last_frame<A>(&mut self, aad: Aad<A>, frame: &[u8]) -> Result<(), Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        self.open(aad, frame, true)
+    }
+
+    fn open<A>(&mut self, aad: Aad<A>, frame: &[u8], last: bool) -> Result<(), Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        // The last counter value can only be used by the final frame.
+        let result = if self.state == OpeningState::Open && (last || self.counter != u32::MAX) {
+            let nonce = frame_nonce(&self.nonce_prefix, self.counter, last);
+            self.key.open(nonce, aad, frame)
+        } else {
+            Err(Unspecified)
+        };
+        match result {
+            Ok(mut plaintext) => {
+                self.plaintext.extend_from_slice(&plaintext);
+                plaintext.zeroize();
+                if last {
+                    self.state = OpeningState::Complete;
+                } else {
+                    self.counter += 1;
+                }
+                Ok(())
+            }
+            Err(_) => {
+                self.plaintext.zeroize();
+                self.state = OpeningState::Failed;
+                Err(Unspecified)
+            }
+        }
+    }
+
+    /// Returns the plaintext of the stream.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if any frame failed to open, or if the final frame has not been
+    /// opened. The buffered plaintext is discarded.
+    pub fn finish(mut self) -> Result<Vec<u8>, Unspecified> {
+        if self.state == OpeningState::Complete {
+            Ok(core::mem::take(&mut self.plaintext))
+        } else {
+            self.plaintext.zeroize();
+            Err(Unspecified)
+        }
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    #[must_use]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.key.algorithm()
+    }
+}
+
+impl Debug for StreamOpeningKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.debug_struct("StreamOpeningKey")
+            .field("algorithm", self.algorithm())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aead::{
+        Aad, StreamOpeningKey, StreamSealingKey, UnboundKey, AES_256_GCM, CHACHA20_POLY1305,
+        STREAM_NONCE_PREFIX_LEN,
+    };
+
+    const NONCE_PREFIX: [u8; STREAM_NONCE_PREFIX_LEN] = [0x5b, 0xf1, 0x1a, 0x09, 0x51, 0xf0, 0xbf];
+
+    const FRAMES: [&[u8]; 3] = [b"first frame, ", b"second frame, ", b"last frame"];
+
+    fn seal_frames(key_bytes: &[u8]) -> Vec<Vec<u8>> {
+        let mut key = StreamSealingKey::new(
+            UnboundKey::new(&AES_256_GCM, key_bytes).unwrap(),
+            NONCE_PREFIX,
+        );
+        let mut frames = vec![
+            key.seal_frame(Aad::from(b"aad"), FRAMES[0]).unwrap(),
+            key.seal_frame(Aad::from(b"aad"), FRAMES[1]).unwrap(),
+        ];
+        frames.push(key.seal_last_frame(Aad::from(b"aad"), FRAMES[2]).unwrap());
+        frames
+    }
+
+    fn opening_key(key_bytes: &[u8]) -> StreamOpeningKey {
+        StreamOpeningKey::new(
+            UnboundKey::new(&AES_256_GCM, key_bytes).unwrap(),
+            NONCE_PREFIX,
+        )
+    }
+
+    #[test]
+    fn round_trip() {
+        let key_bytes = [0x42u8; 32];
+        let frames = seal_frames(&key_bytes);
+
+        let mut key = opening_key(&key_bytes);
+        assert_eq!(&AES_256_GCM, key.algorithm());
+        key.open_frame(Aad::from(b"aad"), &frames[0]).unwrap();
+        key.open_frame(Aad::from(b"aad"), &frames[1]).unwrap();
+        key.open_last_frame(Aad::from(b"aad"), &frames[2]).unwrap();
+        assert_eq!(FRAMES.concat(), key.finish().unwrap());
+
+        // Nothing may follow the last frame.
+        let mut key = opening_key(&key_bytes);
+        key.open_last_frame(Aad::from(b"aad"), &frames[2])
+            .unwrap_err();
+        let mut key = opening_key(&key_bytes);
+        for frame in &frames[..2] {
+            key.open_frame(Aad::from(b"aad"), frame).unwrap();
+        }
+        key.open_last_frame(Aad::from(b"aad"), &frames[2]).unwrap();
+        assert!(key.open_frame(Aad::from(b"aad"), &frames[2]).is_err());
+        assert!(key.finish().is_err());
+    }
+
+    #[test]
+    fn corrupted_middle_frame() {
+        let key_bytes = [0x42u8; 32];
+        let mut frames = seal_frames(&key_bytes);
+        frames[1][0] ^= 1;
+
+        let mut key = opening_key(&key_bytes);
+        key.open_frame(Aad::from(b"aad"), &frames[0]).unwrap();
+        assert!(key.open_frame(Aad::from(b"aad"), &frames[1]).is_err());
+        // Later frames are rejected, even though they are valid on their own.
+        assert!(key.open_last_frame(Aad::from(b"aad"), &frames[2]).is_err());
+        assert!(key.finish().is_err());
+    }
+
+    #[test]
+    fn truncated_or_reordered_stream() {
+        let key_bytes = [0x42u8; 32];
+        let frames = seal_frames(&key_bytes);
+
+        // A stream without its last frame is incomplete.
+        let mut key = opening_key(&key_bytes);
+        key.open_frame(Aad::from(b"aad"), &frames[0]).unwrap();
+        key.open_frame(Aad::from(b"aad"), &frames[1]).unwrap();
+        assert!(key.finish().is_err());
+
+        // A non-final frame cannot be passed off as the last one.
+        let mut key = opening_key(&key_bytes);
+        key.open_frame(Aad::from(b"aad"), &frames[0]).unwrap();
+        assert!(key.open_last_frame(Aad::from(b"aad"), &frames[1]).is_err());
+        assert!(key.finish().is_err());
+
+        // Frames must be opened in order.
+        let mut key = opening_key(&key_bytes);
+        assert!(key.open_frame(Aad::from(b"aad"), &frames[1]).is_err());
+
+        // Frames are bound to the stream's nonce prefix and the AAD.
+        let mut key = StreamOpeningKey::new(
+            UnboundKey::new(&AES_256_GCM, &key_bytes).unwrap(),
+            [0u8; STREAM_NONCE_PREFIX_LEN],
+        );
+        assert!(key.open_frame(Aad::from(b"aad"), &frames[0]).is_err());
+        let mut key = opening_key(&key_bytes);
+        assert!(key.open_frame(Aad::from(b"other"), &frames[0]).is_err());
+    }
+
+    #[test]
+    fn last_counter_value() {
+        let key_bytes = [0x42u8; 32];
+        let mut sealing_key = StreamSealingKey::new(
+            UnboundKey::new(&AES_256_GCM, &key_bytes).unwrap(),
+            NONCE_PREFIX,
+        );
+        sealing_key.counter = u32::MAX - 1;
+        let frame = sealing_key.seal_frame(Aad::empty(), FRAMES[0]).unwrap();
+        // Only the final frame can use the last counter value, and a stream can still be ended.
+        assert!(sealing_key.seal_frame(Aad::empty(), FRAMES[1]).is_err());
+        let last_frame = sealing_key
+            .seal_last_frame(Aad::empty(), FRAMES[2])
+            .unwrap();
+
+        let mut key = opening_key(&key_bytes);
+        key.counter = u32::MAX - 1;
+        key.open_frame(Aad::empty(), &frame).unwrap();
+        key.open_last_frame(Aad::empty(), &last_frame).unwrap();
+        assert_eq!([FRAMES[0], FRAMES[2]].concat(), key.finish().unwrap());
+
+        let mut key = opening_key(&key_bytes);
+        key.counter = u32::MAX - 1;
+        key.open_frame(Aad::empty(), &frame).unwrap();
+        assert!(key.open_frame(Aad::empty(), &last_frame).is_err());
+        assert!(key.finish().is_err());
+    }
+
+    #[test]
+    fn empty_stream() {
+        let key_bytes = [0x42u8; 32];
+        let key = StreamSealingKey::new(
+            UnboundKey::new(&CHACHA20_POLY1305, &key_bytes).unwrap(),
+            NONCE_PREFIX,
+        );
+        let frame = key.seal_last_frame(Aad::empty(), b"").unwrap();
+        assert_eq!(CHACHA20_POLY1305.tag_len(), frame.len());
+
+        let mut key = StreamOpeningKey::new(
+            UnboundKey::new(&CHACHA20_POLY1305, &key_bytes).unwrap(),
+            NONCE_PREFIX,
+        );
+        key.open_last_frame(Aad::empty(), &frame).unwrap();
+        assert!(key.finish().unwrap().is_empty());
+    }
+}
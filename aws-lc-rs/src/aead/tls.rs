@@ -74,7 +74,11 @@ impl TlsRecordSealingKey {
                 aead_ctx::AeadDirection::Seal,
             ),
             (
-                AlgorithmID::AES_128_GCM_SIV
+                AlgorithmID::AES_128_GCM_TAG_96
+                | AlgorithmID::AES_256_GCM_TAG_96
+                | AlgorithmID::AES_128_GCM_TAG_64
+                | AlgorithmID::AES_256_GCM_TAG_64
+                | AlgorithmID::AES_128_GCM_SIV
                 | AlgorithmID::AES_256_GCM_SIV
                 | AlgorithmID::CHACHA20_POLY1305,
                 _,
@@ -219,7 +223,11 @@ impl TlsRecordOpeningKey {
                 aead_ctx::AeadDirection::Open,
             ),
             (
-                AlgorithmID::AES_128_GCM_SIV
+                AlgorithmID::AES_128_GCM_TAG_96
+                | AlgorithmID::AES_256_GCM_TAG_96
+                | AlgorithmID::AES_128_GCM_TAG_64
+                | AlgorithmID::AES_256_GCM_TAG_64
+                | AlgorithmID::AES_128_GCM_SIV
                 | AlgorithmID::AES_256_GCM_SIV
                 | AlgorithmID::CHACHA20_POLY1305,
                 _,
@@ -10,6 +10,7 @@ use aws_lc::{
 };
 use core::fmt::Debug;
 use core::{mem::MaybeUninit, ops::RangeFrom, ptr::null};
+use zeroize::Zeroizing;
 
 /// The maximum length of a nonce returned by our AEAD API.
 const MAX_NONCE_LEN: usize = NONCE_LEN;
@@ -18,9 +19,16 @@ const MAX_NONCE_LEN: usize = NONCE_LEN;
 const MAX_TAG_NONCE_BUFFER_LEN: usize = MAX_TAG_LEN + MAX_NONCE_LEN;
 
 /// An AEAD key without a designated role or nonce sequence.
+///
+/// `UnboundKey::try_clone` returns a key that owns a newly initialized context derived from a
+/// copy of the key bytes, so each thread can be given its own key without the caller having to
+/// retain the key material. The copy of the key bytes is zeroized when the key is dropped.
 pub struct UnboundKey {
     ctx: AeadCtx,
     algorithm: &'static Algorithm,
+    /// The key bytes the context was initialized with. This is `None` only for keys constructed
+    /// internally from an `AeadCtx`, which are never exposed as an `UnboundKey`.
+    key_bytes: Option<Zeroizing<[u8; MAX_KEY_LEN]>>,
 }
 
 #[allow(clippy::missing_fields_in_debug)]
@@ -37,12 +45,29 @@ impl UnboundKey {
     /// # Errors
     /// `error::Unspecified` if `key_bytes.len() != algorithm.key_len()`.
     pub fn new(algorithm: &'static Algorithm, key_bytes: &[u8]) -> Result<Self, Unspecified> {
+        let ctx = (algorithm.init)(key_bytes, algorithm.tag_len())?;
+        let mut stored_key_bytes = Zeroizing::new([0u8; MAX_KEY_LEN]);
+        stored_key_bytes
+            .get_mut(..key_bytes.len())
+            .ok_or(Unspecified)?
+            .copy_from_slice(key_bytes);
         Ok(Self {
-            ctx: (algorithm.init)(key_bytes, algorithm.tag_len())?,
+            ctx,
             algorithm,
+            key_bytes: Some(stored_key_bytes),
         })
     }
 
+    /// Constructs a new `UnboundKey` for the same algorithm and key, with its own context
+    /// initialized from the stored key bytes.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the new context could not be initialized.
+    pub fn try_clone(&self) -> Result<Self, Unspecified> {
+        let key_bytes = self.key_bytes.as_ref().ok_or(Unspecified)?;
+        Self::new(self.algorithm, &key_bytes[..self.algorithm.key_len()])
+    }
+
     #[inline]
     pub(crate) fn open_within<'in_out>(
         &self,
@@ -57,6 +82,7 @@ impl UnboundKey {
             .checked_sub(self.algorithm().tag_len())
             .ok_or(Unspecified)?;
         self.check_per_nonce_max_bytes(ciphertext_len)?;
+        self.check_aad_len(aad.len())?;
 
         match self.ctx {
             AeadCtx::AES_128_GCM_RANDNONCE(_) | AeadCtx::AES_256_GCM_RANDNONCE(_) => {
@@ -82,6 +108,7 @@ impl UnboundKey {
         out_plaintext: &mut [u8],
     ) -> Result<(), Unspecified> {
         self.check_per_nonce_max_bytes(in_ciphertext.len())?;
+        self.check_aad_len(aad.len())?;
 
         // ensure that the lengths match
         {
@@ -93,6 +120,10 @@ impl UnboundKey {
             }
         }
 
+        if in_tag.len() != self.algorithm().tag_len() {
+            return Err(Unspecified);
+        }
+
         unsafe {
             let aead_ctx = self.ctx.as_ref();
             let nonce = nonce.as_ref();
@@ -126,6 +157,7 @@ impl UnboundKey {
         InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
     {
         self.check_per_nonce_max_bytes(in_out.as_mut().len())?;
+        self.check_aad_len(aad.len())?;
         match nonce {
             Some(nonce) => self.seal_combined(nonce, aad, in_out),
             None => self.seal_combined_randnonce(aad, in_out),
@@ -140,6 +172,7 @@ impl UnboundKey {
         in_out: &mut [u8],
     ) -> Result<(Nonce, Tag), Unspecified> {
         self.check_per_nonce_max_bytes(in_out.len())?;
+        self.check_aad_len(aad.len())?;
         match nonce {
             Some(nonce) => self.seal_separate(nonce, aad, in_out),
             None => self.seal_separate_randnonce(aad, in_out),
@@ -157,6 +190,7 @@ impl UnboundKey {
         extra_out_and_tag: &mut [u8],
     ) -> Result<(), Unspecified> {
         self.check_per_nonce_max_bytes(in_out.len())?;
+        self.check_aad_len(aad.len())?;
         // ensure that the extra lengths match
         {
             let actual = extra_in.len() + self.algorithm().tag_len();
@@ -207,6 +241,14 @@ impl UnboundKey {
         Ok(())
     }
 
+    #[inline]
+    pub(crate) fn check_aad_len(&self, aad_len: usize) -> Result<(), Unspecified> {
+        if aad_len as u64 > self.algorithm().max_aad_len {
+            return Err(Unspecified);
+        }
+        Ok(())
+    }
+
     #[inline]
     #[allow(clippy::needless_pass_by_value)]
     fn open_combined(
@@ -490,13 +532,14 @@ impl From<AeadCtx> for UnboundKey {
         Self {
             ctx: value,
             algorithm,
+            key_bytes: None,
         }
     }
 }
 
 impl From<hkdf::Okm<'_, &'static Algorithm>> for UnboundKey {
     fn from(okm: hkdf::Okm<&'static Algorithm>) -> Self {
-        let mut key_bytes = [0; MAX_KEY_LEN];
+        let mut key_bytes = Zeroizing::new([0; MAX_KEY_LEN]);
         let key_bytes = &mut key_bytes[..okm.len().key_len];
         let algorithm = *okm.len();
         okm.fill(key_bytes).unwrap();
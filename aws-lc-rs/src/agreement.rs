@@ -49,6 +49,13 @@
 //!
 //! # Ok::<(), aws_lc_rs::error::Unspecified>(())
 //! ```
+//!
+//! # Unsupported curves
+//!
+//! X448 ([RFC 7748]) is not available. *AWS-LC* defines an identifier for the curve but does not
+//! provide an implementation of the X448 function, so there is nothing for this module to expose.
+//!
+//! [RFC 7748]: https://www.rfc-editor.org/rfc/rfc7748
 mod ephemeral;
 
 pub use ephemeral::{agree_ephemeral, EphemeralPrivateKey};
@@ -105,6 +112,16 @@ impl AlgorithmID {
         }
     }
 
+    #[inline]
+    const fn compressed_pub_key_len(&self) -> usize {
+        match self {
+            AlgorithmID::ECDH_P256 => 33,
+            AlgorithmID::ECDH_P384 => 49,
+            AlgorithmID::ECDH_P521 => 67,
+            AlgorithmID::X25519 => 32,
+        }
+    }
+
     #[inline]
     const fn private_key_len(&self) -> usize {
         match self {
@@ -140,16 +157,28 @@ impl Debug for Algorithm {
 }
 
 /// ECDH using the NSA Suite B P-256 (secp256r1) curve.
+///
+/// Peer public keys are accepted as SEC 1 encoded points in either the
+/// uncompressed (65-byte) or compressed (33-byte) form. Public keys computed by
+/// this crate are always in the uncompressed form.
 pub const ECDH_P256: Algorithm = Algorithm {
     id: AlgorithmID::ECDH_P256,
 };
 
 /// ECDH using the NSA Suite B P-384 (secp384r1) curve.
+///
+/// Peer public keys are accepted as SEC 1 encoded points in either the
+/// uncompressed (97-byte) or compressed (49-byte) form. Public keys computed by
+/// this crate are always in the uncompressed form.
 pub const ECDH_P384: Algorithm = Algorithm {
     id: AlgorithmID::ECDH_P384,
 };
 
 /// ECDH using the NSA Suite B P-521 (secp521r1) curve.
+///
+/// Peer public keys are accepted as SEC 1 encoded points in either the
+/// uncompressed (133-byte) or compressed (67-byte) form. Public keys computed by
+/// this crate are always in the uncompressed form.
 pub const ECDH_P521: Algorithm = Algorithm {
     id: AlgorithmID::ECDH_P521,
 };
@@ -663,7 +692,9 @@ where
         return Err(error_value);
     }
     let peer_pub_bytes = peer_public_key.bytes.as_ref();
-    if peer_pub_bytes.len() != expected_pub_key_len {
+    if peer_pub_bytes.len() != expected_pub_key_len
+        && peer_pub_bytes.len() != expected_alg.id.compressed_pub_key_len()
+    {
         return Err(error_value);
     }
 
@@ -998,6 +1029,93 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_agreement_ecdh_p384_compressed_peer_key() {
+        let alg = &ECDH_P384;
+        let uncompressed = test::from_dirty_hex(
+            "04E558DBEF53EECDE3D3FCCFC1AEA08A89A987475D12FD950D83CFA41732BC509D0D1AC43A0336DEF96FDA41D0774A3571DCFBEC7AACF3196472169E838430367F66EEBE3C6E70C416DD5F0C68759DD1FFF83FA40142209DFF5EAAD96DB9E6386C",
+        );
+        // The y-coordinate of the peer point is even.
+        let compressed = test::from_dirty_hex(
+            "02E558DBEF53EECDE3D3FCCFC1AEA08A89A987475D12FD950D83CFA41732BC509D0D1AC43A0336DEF96FDA41D0774A3571",
+        );
+        assert_eq!(97, uncompressed.len());
+        assert_eq!(49, compressed.len());
+
+        let my_private = test::from_dirty_hex(
+            "099F3C7034D4A2C699884D73A375A67F7624EF7C6B3C0F160647B67414DCE655E35B538041E649EE3FAEF896783AB194",
+        );
+        let my_private = PrivateKey::from_private_key(alg, &my_private).unwrap();
+        let output = test::from_dirty_hex(
+            "11187331C279962D93D604243FD592CB9D0A926F422E47187521287E7156C5C4D603135569B9E9D09CF5D4A270F59746",
+        );
+
+        for peer_public in [&uncompressed, &compressed] {
+            let result = agree(
+                &my_private,
+                &UnparsedPublicKey::new(alg, peer_public),
+                (),
+                |key_material| {
+                    assert_eq!(key_material, &output[..]);
+                    Ok(())
+                },
+            );
+            assert_eq!(result, Ok(()));
+        }
+
+        // A compressed-length encoding must use a compressed-point prefix.
+        let mut wrong_prefix = compressed.clone();
+        wrong_prefix[0] = 0x04;
+        assert!(agree(
+            &my_private,
+            &UnparsedPublicKey::new(alg, &wrong_prefix),
+            (),
+            |_| Ok(())
+        )
+        .is_err());
+
+        // Truncated encodings of either form are rejected.
+        for peer_public in [&uncompressed[..96], &compressed[..48]] {
+            assert!(agree(
+                &my_private,
+                &UnparsedPublicKey::new(alg, peer_public),
+                (),
+                |_| Ok(())
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn test_agreement_ecdh_compressed_peer_key_round_trip() {
+        for alg in [&ECDH_P256, &ECDH_P384, &ECDH_P521] {
+            let my_private = PrivateKey::generate(alg).unwrap();
+            let peer_private = PrivateKey::generate(alg).unwrap();
+            let peer_public = peer_private.compute_public_key().unwrap();
+            let peer_public = peer_public.as_ref();
+
+            let coordinate_len = (peer_public.len() - 1) / 2;
+            let mut compressed = vec![0x02 | (peer_public[peer_public.len() - 1] & 1)];
+            compressed.extend_from_slice(&peer_public[1..=coordinate_len]);
+
+            let expected = agree(
+                &my_private,
+                &UnparsedPublicKey::new(alg, peer_public),
+                (),
+                |key_material| Ok(key_material.to_vec()),
+            )
+            .unwrap();
+            let actual = agree(
+                &my_private,
+                &UnparsedPublicKey::new(alg, &compressed),
+                (),
+                |key_material| Ok(key_material.to_vec()),
+            )
+            .unwrap();
+            assert_eq!(expected, actual);
+        }
+    }
+
     #[test]
     fn test_agreement_ecdh_p521() {
         let alg = &ECDH_P521;
@@ -0,0 +1,105 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+const STANDARD_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+const URL_SAFE_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Converts bytes to an unpadded base64 string using the standard alphabet ([RFC 4648 Section 4]).
+///
+/// [RFC 4648 Section 4]: https://www.rfc-editor.org/rfc/rfc4648#section-4
+pub fn encode<T: AsRef<[u8]>>(bytes: T) -> String {
+    encode_with_alphabet(STANDARD_ALPHABET, bytes.as_ref())
+}
+
+/// Converts bytes to an unpadded base64url string ([RFC 4648 Section 5]).
+///
+/// [RFC 4648 Section 5]: https://www.rfc-editor.org/rfc/rfc4648#section-5
+pub fn encode_url<T: AsRef<[u8]>>(bytes: T) -> String {
+    encode_with_alphabet(URL_SAFE_ALPHABET, bytes.as_ref())
+}
+
+fn encode_with_alphabet(alphabet: &[u8; 64], bytes: &[u8]) -> String {
+    let mut encoding = String::with_capacity((bytes.len() * 4 + 2) / 3);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied().unwrap_or(0);
+        let b2 = chunk.get(2).copied().unwrap_or(0);
+        let sextets = [
+            b0 >> 2,
+            ((b0 & 0x03) << 4) | (b1 >> 4),
+            ((b1 & 0x0f) << 2) | (b2 >> 6),
+            b2 & 0x3f,
+        ];
+        for sextet in &sextets[..=chunk.len()] {
+            encoding.push(char::from(alphabet[usize::from(*sextet)]));
+        }
+    }
+    encoding
+}
+
+fn decode_char(alphabet: &[u8; 64], ch: u8) -> Option<u8> {
+    match ch {
+        b'A'..=b'Z' => Some(ch - b'A'),
+        b'a'..=b'z' => Some(ch - b'a' + 26),
+        b'0'..=b'9' => Some(ch - b'0' + 52),
+        _ if ch == alphabet[62] => Some(62),
+        _ if ch == alphabet[63] => Some(63),
+        _ => None,
+    }
+}
+
+/// Converts a base64 string using the standard alphabet to a vector of bytes.
+///
+/// ASCII whitespace is ignored and trailing `=` padding is accepted but not required.
+/// # Errors
+/// Returns an error if `b64_str` contains a character outside of the standard base64 alphabet,
+/// has an impossible length, or has non-zero trailing bits.
+pub fn decode(b64_str: &str) -> Result<Vec<u8>, &'static str> {
+    decode_with_alphabet(STANDARD_ALPHABET, b64_str)
+}
+
+/// Converts a base64url string to a vector of bytes.
+///
+/// ASCII whitespace is ignored and trailing `=` padding is accepted but not required.
+/// # Errors
+/// Returns an error if `b64_str` contains a character outside of the base64url alphabet, has an
+/// impossible length, or has non-zero trailing bits.
+pub fn decode_url(b64_str: &str) -> Result<Vec<u8>, &'static str> {
+    decode_with_alphabet(URL_SAFE_ALPHABET, b64_str)
+}
+
+fn decode_with_alphabet(alphabet: &[u8; 64], b64_str: &str) -> Result<Vec<u8>, &'static str> {
+    let mut sextets: Vec<u8> = b64_str
+        .bytes()
+        .filter(|ch| !ch.is_ascii_whitespace())
+        .collect();
+    while sextets.last() == Some(&b'=') {
+        sextets.pop();
+    }
+    for ch in &mut sextets {
+        *ch = decode_char(alphabet, *ch).ok_or("Invalid base64 string")?;
+    }
+    if sextets.len() % 4 == 1 {
+        return Err("Invalid base64 length");
+    }
+
+    let mut bytes = Vec::<u8>::with_capacity(sextets.len() * 3 / 4);
+    for chunk in sextets.chunks(4) {
+        let s0 = chunk[0];
+        let s1 = chunk[1];
+        let s2 = chunk.get(2).copied().unwrap_or(0);
+        let s3 = chunk.get(3).copied().unwrap_or(0);
+        let decoded = [(s0 << 2) | (s1 >> 4), (s1 << 4) | (s2 >> 2), (s2 << 6) | s3];
+        let decoded_len = chunk.len() - 1;
+        // Reject encodings whose unused trailing bits are set, so that each byte string has
+        // exactly one accepted encoding.
+        if decoded[decoded_len..].iter().any(|b| *b != 0) {
+            return Err("Invalid base64 trailing bits");
+        }
+        bytes.extend_from_slice(&decoded[..decoded_len]);
+    }
+    Ok(bytes)
+}
@@ -159,7 +159,7 @@ use aws_lc::{
 use core::fmt::Debug;
 use core::mem::MaybeUninit;
 use key::SymmetricCipherKey;
-use zeroize::Zeroize;
+use zeroize::{Zeroize, Zeroizing};
 
 /// The number of bytes in an AES 128-bit key
 pub const AES_128_KEY_LEN: usize = 16;
@@ -329,7 +329,7 @@ impl Debug for UnboundCipherKey {
 
 impl From<hkdf::Okm<'_, &'static Algorithm>> for UnboundCipherKey {
     fn from(okm: hkdf::Okm<&'static Algorithm>) -> Self {
-        let mut key_bytes = [0; MAX_CIPHER_KEY_LEN];
+        let mut key_bytes = Zeroizing::new([0; MAX_CIPHER_KEY_LEN]);
         let key_bytes = &mut key_bytes[..okm.len().key_len];
         let algorithm = *okm.len();
         okm.fill(key_bytes).unwrap();
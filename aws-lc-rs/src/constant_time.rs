@@ -26,3 +26,46 @@ pub fn verify_slices_are_equal(a: &[u8], b: &[u8]) -> Result<(), error::Unspecif
         _ => Err(error::Unspecified),
     }
 }
+
+/// Copies `src` into the end of `dst`, setting the leading `dst.len() - src.len()` bytes of
+/// `dst` to zero.
+///
+/// This left-pads a big-endian value to a fixed width. The copy is done in constant time with
+/// respect to the contents of `src`, but NOT with respect to the lengths of `src` and `dst`.
+///
+/// # Errors
+/// `error::Unspecified` when `src` is longer than `dst`.
+#[inline]
+pub fn copy_padded(dst: &mut [u8], src: &[u8]) -> Result<(), error::Unspecified> {
+    let padding_len = dst.len().checked_sub(src.len()).ok_or(error::Unspecified)?;
+    let (padding, value) = dst.split_at_mut(padding_len);
+    padding.fill(0);
+    value.copy_from_slice(src);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constant_time::copy_padded;
+
+    #[test]
+    fn test_copy_padded() {
+        let src = [0xffu8; 30];
+        let mut dst = [0xaau8; 32];
+        copy_padded(&mut dst, &src).unwrap();
+        assert_eq!([0, 0], dst[..2]);
+        assert_eq!(src, dst[2..]);
+
+        let mut dst = [0xaau8; 30];
+        copy_padded(&mut dst, &src).unwrap();
+        assert_eq!(src, dst);
+
+        let mut dst = [0xaau8; 29];
+        assert!(copy_padded(&mut dst, &src).is_err());
+        assert_eq!([0xaau8; 29], dst);
+
+        let mut dst = [0xaau8; 4];
+        copy_padded(&mut dst, &[]).unwrap();
+        assert_eq!([0u8; 4], dst);
+    }
+}
@@ -37,7 +37,8 @@ use crate::error::Unspecified;
 use crate::ptr::ConstPointer;
 use aws_lc::{
     EVP_DigestFinal, EVP_DigestUpdate, EVP_sha1, EVP_sha224, EVP_sha256, EVP_sha384, EVP_sha3_256,
-    EVP_sha3_384, EVP_sha3_512, EVP_sha512, EVP_sha512_256, EVP_MD,
+    EVP_sha3_384, EVP_sha3_512, EVP_sha512, EVP_sha512_256, NID_sha224, NID_sha256, NID_sha384,
+    NID_sha3_256, NID_sha3_384, NID_sha3_512, NID_sha512, NID_sha512_256, EVP_MD,
 };
 use core::mem::MaybeUninit;
 use digest_ctx::DigestContext;
@@ -204,6 +205,104 @@ pub fn digest(algorithm: &'static Algorithm, data: &[u8]) -> Digest {
     }
 }
 
+/// Returns the digest of all data read from `reader` until EOF.
+///
+/// The data is read in chunks, so arbitrarily large inputs such as files can be hashed without
+/// being buffered in memory. Reads that fail with `ErrorKind::Interrupted` are retried.
+///
+/// # Examples:
+///
+/// ```
+/// use aws_lc_rs::digest;
+/// use std::io::Cursor;
+///
+/// let mut reader = Cursor::new(b"hello, world");
+/// let actual = digest::digest_reader(&digest::SHA256, &mut reader).unwrap();
+///
+/// assert_eq!(
+///     digest::digest(&digest::SHA256, b"hello, world").as_ref(),
+///     actual.as_ref()
+/// );
+/// ```
+///
+/// # Errors
+/// Any error returned by `reader`, other than `ErrorKind::Interrupted`.
+pub fn digest_reader<R: std::io::Read + ?Sized>(
+    algorithm: &'static Algorithm,
+    reader: &mut R,
+) -> std::io::Result<Digest> {
+    let mut context = Context::new(algorithm);
+    let mut buffer = [0u8; 8192];
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => return Ok(context.finish()),
+            Ok(len) => context.update(&buffer[..len]),
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Returns the digest algorithm identified by the AWS-LC/OpenSSL `nid`, or `None` if it does
+/// not identify one of the SHA-2 or SHA-3 algorithms of this module.
+///
+/// The legacy SHA-1 algorithm is never returned, so that it can't be selected by parsed input;
+/// use `SHA1_FOR_LEGACY_USE_ONLY` explicitly where it is required.
+#[must_use]
+#[allow(non_upper_case_globals)]
+pub fn from_nid(nid: i32) -> Option<&'static Algorithm> {
+    match nid {
+        NID_sha224 => Some(&SHA224),
+        NID_sha256 => Some(&SHA256),
+        NID_sha384 => Some(&SHA384),
+        NID_sha512 => Some(&SHA512),
+        NID_sha512_256 => Some(&SHA512_256),
+        NID_sha3_256 => Some(&SHA3_256),
+        NID_sha3_384 => Some(&SHA3_384),
+        NID_sha3_512 => Some(&SHA3_512),
+        _ => None,
+    }
+}
+
+/// The DER-encoded `hashAlgs` arc (2.16.840.1.101.3.4.2) under which NIST assigns the object
+/// identifiers of the SHA-2 and SHA-3 algorithms.
+const NIST_HASH_ALGS_OID: [u8; 8] = [0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02];
+
+/// Returns the digest algorithm identified by `oid`, or `None` if it does not identify one of the
+/// SHA-2 or SHA-3 algorithms of this module.
+///
+/// `oid` is the DER encoding of the object identifier's value, without the tag and length, e.g.
+/// as found in the `algorithm` field of an X.509 `AlgorithmIdentifier`. As with `from_nid`, the
+/// legacy SHA-1 algorithm is never returned.
+///
+/// # Example
+///
+/// ```
+/// use aws_lc_rs::digest;
+///
+/// // id-sha256 (2.16.840.1.101.3.4.2.1)
+/// let oid = [0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01];
+/// assert_eq!(Some(&digest::SHA256), digest::from_oid(&oid));
+/// ```
+#[must_use]
+pub fn from_oid(oid: &[u8]) -> Option<&'static Algorithm> {
+    let (arc, id) = oid.split_at(oid.len().checked_sub(1)?);
+    if arc != NIST_HASH_ALGS_OID {
+        return None;
+    }
+    match id {
+        [0x01] => Some(&SHA256),
+        [0x02] => Some(&SHA384),
+        [0x03] => Some(&SHA512),
+        [0x04] => Some(&SHA224),
+        [0x06] => Some(&SHA512_256),
+        [0x08] => Some(&SHA3_256),
+        [0x09] => Some(&SHA3_384),
+        [0x0a] => Some(&SHA3_512),
+        _ => None,
+    }
+}
+
 /// A calculated digest value.
 ///
 /// Use [`Self::as_ref`] to get the value as a `&[u8]`.
@@ -304,7 +403,7 @@ impl Algorithm {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Hash)]
 pub(crate) enum AlgorithmID {
     SHA1,
     SHA224,
@@ -362,6 +461,64 @@ mod tests {
     #[cfg(feature = "fips")]
     mod fips;
 
+    mod reader {
+        use crate::digest;
+        use std::io::{Cursor, Error, ErrorKind, Read};
+
+        /// Returns at most one byte per read, and is interrupted before every other byte.
+        struct TrickleReader<'a> {
+            data: &'a [u8],
+            interrupt: bool,
+        }
+
+        impl Read for TrickleReader<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                self.interrupt = !self.interrupt;
+                if self.interrupt {
+                    return Err(Error::new(ErrorKind::Interrupted, "interrupted"));
+                }
+                let len = self.data.len().min(buf.len()).min(1);
+                buf[..len].copy_from_slice(&self.data[..len]);
+                self.data = &self.data[len..];
+                Ok(len)
+            }
+        }
+
+        struct FailingReader;
+
+        impl Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                Err(Error::new(ErrorKind::BrokenPipe, "broken"))
+            }
+        }
+
+        #[test]
+        fn test_digest_reader() {
+            let data: Vec<u8> = (0..20_000u32).map(|i| (i % 251) as u8).collect();
+            for alg in [
+                &digest::SHA1_FOR_LEGACY_USE_ONLY,
+                &digest::SHA256,
+                &digest::SHA512,
+            ] {
+                for len in [0, 1, 8191, 8192, 8193, data.len()] {
+                    let expected = digest::digest(alg, &data[..len]);
+                    let actual = digest::digest_reader(alg, &mut Cursor::new(&data[..len]));
+                    assert_eq!(expected.as_ref(), actual.unwrap().as_ref());
+                }
+
+                let mut trickle = TrickleReader {
+                    data: &data[..100],
+                    interrupt: false,
+                };
+                let actual = digest::digest_reader(alg, &mut trickle).unwrap();
+                assert_eq!(digest::digest(alg, &data[..100]).as_ref(), actual.as_ref());
+            }
+
+            let err = digest::digest_reader(&digest::SHA256, &mut FailingReader).unwrap_err();
+            assert_eq!(ErrorKind::BrokenPipe, err.kind());
+        }
+    }
+
     mod max_input {
         extern crate alloc;
 
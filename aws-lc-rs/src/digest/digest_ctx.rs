@@ -3,7 +3,11 @@
 
 use crate::digest::{match_digest_type, Algorithm};
 use crate::error::Unspecified;
-use aws_lc::{EVP_DigestInit_ex, EVP_MD_CTX_cleanup, EVP_MD_CTX_copy, EVP_MD_CTX_init, EVP_MD_CTX};
+use crate::fips::indicator_check;
+use aws_lc::{
+    EVP_DigestInit_ex, EVP_DigestVerify, EVP_DigestVerifyFinal, EVP_DigestVerifyUpdate,
+    EVP_MD_CTX_cleanup, EVP_MD_CTX_copy, EVP_MD_CTX_init, EVP_MD_CTX,
+};
 use core::mem::MaybeUninit;
 use core::ptr::null_mut;
 
@@ -36,6 +40,43 @@ impl DigestContext {
     pub(crate) fn as_ptr(&self) -> *const EVP_MD_CTX {
         &self.0
     }
+
+    /// Verifies `signature` over the concatenation of `segments`. The context must have been
+    /// initialized with `EVP_DigestVerifyInit`.
+    pub(crate) fn verify(
+        &mut self,
+        segments: &[&[u8]],
+        signature: &[u8],
+    ) -> Result<(), Unspecified> {
+        if let [msg] = segments {
+            if 1 != indicator_check!(unsafe {
+                EVP_DigestVerify(
+                    self.as_mut_ptr(),
+                    signature.as_ptr(),
+                    signature.len(),
+                    msg.as_ptr(),
+                    msg.len(),
+                )
+            }) {
+                return Err(Unspecified);
+            }
+            return Ok(());
+        }
+
+        for segment in segments {
+            if 1 != unsafe {
+                EVP_DigestVerifyUpdate(self.as_mut_ptr(), segment.as_ptr().cast(), segment.len())
+            } {
+                return Err(Unspecified);
+            }
+        }
+        if 1 != indicator_check!(unsafe {
+            EVP_DigestVerifyFinal(self.as_mut_ptr(), signature.as_ptr(), signature.len())
+        }) {
+            return Err(Unspecified);
+        }
+        Ok(())
+    }
 }
 
 unsafe impl Send for DigestContext {}
@@ -5,6 +5,7 @@
 
 use core::fmt;
 use core::fmt::{Debug, Formatter};
+use core::hash::{Hash, Hasher};
 use core::mem::MaybeUninit;
 use core::ops::Deref;
 use core::ptr::null;
@@ -23,14 +24,15 @@ use aws_lc::EC_KEY_check_key;
 use aws_lc::{
     point_conversion_form_t, BN_bn2bin_padded, BN_num_bytes, ECDSA_SIG_from_bytes,
     ECDSA_SIG_get0_r, ECDSA_SIG_get0_s, ECDSA_SIG_new, ECDSA_SIG_set0, ECDSA_SIG_to_bytes,
-    EC_GROUP_get_curve_name, EC_GROUP_new_by_curve_name, EC_KEY_get0_group,
-    EC_KEY_get0_private_key, EC_KEY_get0_public_key, EC_KEY_new, EC_KEY_set_group,
-    EC_KEY_set_private_key, EC_KEY_set_public_key, EC_POINT_mul, EC_POINT_new, EC_POINT_oct2point,
-    EC_POINT_point2oct, EVP_DigestVerify, EVP_DigestVerifyInit, EVP_PKEY_CTX_new_id,
+    ECDSA_do_verify, EC_GROUP_get0_order, EC_GROUP_get_curve_name, EC_GROUP_new_by_curve_name,
+    EC_GROUP_new_curve_GFp, EC_GROUP_set_generator, EC_KEY_get0_group, EC_KEY_get0_private_key,
+    EC_KEY_get0_public_key, EC_KEY_new, EC_KEY_set_group, EC_KEY_set_private_key,
+    EC_KEY_set_public_key, EC_POINT_is_at_infinity, EC_POINT_mul, EC_POINT_new, EC_POINT_oct2point,
+    EC_POINT_point2oct, EVP_DigestVerifyInit, EVP_PKEY_CTX_new_id,
     EVP_PKEY_CTX_set_ec_paramgen_curve_nid, EVP_PKEY_assign_EC_KEY, EVP_PKEY_get0_EC_KEY,
-    EVP_PKEY_keygen, EVP_PKEY_keygen_init, EVP_PKEY_new, NID_X9_62_prime256v1, NID_secp256k1,
-    NID_secp384r1, NID_secp521r1, BIGNUM, ECDSA_SIG, EC_GROUP, EC_KEY, EC_POINT, EVP_PKEY,
-    EVP_PKEY_EC,
+    EVP_PKEY_id, EVP_PKEY_keygen, EVP_PKEY_keygen_init, EVP_PKEY_new, NID_X9_62_prime256v1,
+    NID_brainpoolP256r1, NID_brainpoolP384r1, NID_brainpoolP512r1, NID_secp256k1, NID_secp384r1,
+    NID_secp521r1, BIGNUM, ECDSA_SIG, EC_GROUP, EC_KEY, EC_POINT, EVP_PKEY, EVP_PKEY_EC,
 };
 
 use crate::digest::digest_ctx::DigestContext;
@@ -39,10 +41,16 @@ use crate::error::{KeyRejected, Unspecified};
 use crate::fips::indicator_check;
 use crate::ptr::{ConstPointer, DetachableLcPtr, LcPtr, Pointer};
 use crate::signature::{Signature, VerificationAlgorithm};
-use crate::{digest, hex, sealed};
+use crate::{constant_time, digest, hex, sealed};
 
+mod brainpool;
+#[cfg(feature = "low-level")]
+mod custom_curve;
 pub(crate) mod key_pair;
 
+#[cfg(feature = "low-level")]
+pub use custom_curve::CustomCurve;
+
 const ELEM_MAX_BITS: usize = 521;
 pub(crate) const ELEM_MAX_BYTES: usize = (ELEM_MAX_BITS + 7) / 8;
 
@@ -51,6 +59,40 @@ pub(crate) const SCALAR_MAX_BYTES: usize = ELEM_MAX_BYTES;
 /// The maximum length, in bytes, of an encoded public key.
 pub(crate) const PUBLIC_KEY_MAX_LEN: usize = 1 + (2 * ELEM_MAX_BYTES);
 
+/// The length, in bytes, of an uncompressed P-256 public key.
+pub const ECDSA_P256_PUBLIC_KEY_LEN: usize = 1 + 2 * 32;
+/// The length, in bytes, of an uncompressed P-384 public key.
+pub const ECDSA_P384_PUBLIC_KEY_LEN: usize = 1 + 2 * 48;
+/// The length, in bytes, of an uncompressed P-521 public key.
+pub const ECDSA_P521_PUBLIC_KEY_LEN: usize = 1 + 2 * 66;
+
+/// The length, in bytes, of a fixed-length (`*_FIXED`) P-256 signature.
+pub const ECDSA_P256_SIGNATURE_FIXED_LEN: usize = 2 * 32;
+/// The length, in bytes, of a fixed-length (`*_FIXED`) P-384 signature.
+pub const ECDSA_P384_SIGNATURE_FIXED_LEN: usize = 2 * 48;
+/// The length, in bytes, of a fixed-length (`*_FIXED`) P-521 signature.
+pub const ECDSA_P521_SIGNATURE_FIXED_LEN: usize = 2 * 66;
+
+/// The maximum length, in bytes, of an ASN.1 (`*_ASN1`) P-256 signature. This is the size of
+/// the buffer required by `EcdsaKeyPair::sign_into`.
+pub const ECDSA_P256_SIGNATURE_ASN1_MAX_LEN: usize = 72;
+/// The maximum length, in bytes, of an ASN.1 (`*_ASN1`) P-384 signature. This is the size of
+/// the buffer required by `EcdsaKeyPair::sign_into`.
+pub const ECDSA_P384_SIGNATURE_ASN1_MAX_LEN: usize = 104;
+/// The maximum length, in bytes, of an ASN.1 (`*_ASN1`) P-521 signature. This is the size of
+/// the buffer required by `EcdsaKeyPair::sign_into`.
+pub const ECDSA_P521_SIGNATURE_ASN1_MAX_LEN: usize = 141;
+
+/// The maximum length, in bytes, of an ECDSA signature in either format, for any of the supported
+/// curves. A buffer of this size is sufficient for `EcdsaKeyPair::sign_into` with any algorithm.
+///
+/// This is the length of an ASN.1 signature on the curve with the largest scalars, where *r* and
+/// *s* are of maximum length with the leading high bit set on each. Then each component will have
+/// a tag, a one-byte length, and a one-byte “I'm not negative” prefix, and the outer sequence
+/// will have a two-byte length.
+pub const ECDSA_SIGNATURE_MAX_LEN: usize = 1/*tag:SEQUENCE*/ + 2/*len*/ +
+    (2 * (1/*tag:INTEGER*/ + 1/*len*/ + 1/*zero*/ + SCALAR_MAX_BYTES));
+
 /// The maximum length of a PKCS#8 documents generated by *aws-lc-rs* for ECC keys.
 ///
 /// This is NOT the maximum length of a PKCS#8 document that can be consumed by
@@ -71,6 +113,87 @@ pub struct EcdsaVerificationAlgorithm {
     pub(super) sig_format: EcdsaSignatureFormat,
 }
 
+impl Hash for EcdsaVerificationAlgorithm {
+    // `bits` is determined by `id`, so it is left out.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.digest.id.hash(state);
+        self.sig_format.hash(state);
+    }
+}
+
+impl EcdsaVerificationAlgorithm {
+    /// The elliptic curve used by this algorithm.
+    #[inline]
+    #[must_use]
+    pub fn curve_id(&self) -> CurveId {
+        self.id.curve_id()
+    }
+
+    /// Verifies `signature` of `msg` using `public_key`, after checking explicitly that
+    /// `public_key` is in the prime-order subgroup generated by the curve's base point.
+    ///
+    /// On a curve with a cofactor greater than one, a point can be on the curve without being in
+    /// the prime-order subgroup. This method rejects such points by checking that `n * P` is the
+    /// point at infinity, where `n` is the order of the subgroup. All curves currently supported
+    /// have a cofactor of one, so for them every point on the curve passes this check and the
+    /// result is the same as that of `verify_sig`; the check costs one additional scalar
+    /// multiplication.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `public_key` is not in the prime-order subgroup, or if the
+    /// signature is not valid.
+    pub fn verify_strict(
+        &self,
+        public_key: &[u8],
+        msg: &[u8],
+        signature: &[u8],
+    ) -> Result<(), Unspecified> {
+        let ec_group = ec_group_from_nid(self.id.nid())?;
+        let ec_point = ec_point_from_bytes(&ec_group, public_key)?;
+        verify_point_in_subgroup(&ec_group, &ec_point)?;
+        self.verify_sig(public_key, msg, signature)
+    }
+}
+
+/// Identifies the elliptic curve of an ECDSA algorithm.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CurveId {
+    /// NIST P-256 (secp256r1)
+    P256,
+
+    /// NIST P-384 (secp384r1)
+    P384,
+
+    /// NIST P-521 (secp521r1)
+    P521,
+
+    /// secp256k1
+    P256K1,
+
+    /// brainpoolP256r1 (RFC 5639)
+    BrainpoolP256R1,
+
+    /// brainpoolP384r1 (RFC 5639)
+    BrainpoolP384R1,
+
+    /// brainpoolP512r1 (RFC 5639)
+    BrainpoolP512R1,
+}
+
+/// The byte order of raw public key coordinates.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Endianness {
+    /// Most significant byte first, as in SEC 1 and X.509.
+    #[default]
+    Big,
+
+    /// Least significant byte first, as used by some legacy systems.
+    Little,
+}
+
 /// An ECDSA signing algorithm.
 #[derive(Debug, Eq, PartialEq)]
 pub struct EcdsaSigningAlgorithm(pub(crate) &'static EcdsaVerificationAlgorithm);
@@ -86,19 +209,39 @@ impl Deref for EcdsaSigningAlgorithm {
 impl sealed::Sealed for EcdsaVerificationAlgorithm {}
 impl sealed::Sealed for EcdsaSigningAlgorithm {}
 
-#[derive(Debug, Eq, PartialEq)]
+impl EcdsaSigningAlgorithm {
+    /// Returns `true` if `scalar`, interpreted as a big-endian unsigned integer, is a valid
+    /// private key scalar for this algorithm's curve, i.e. it is in the range `[1, n)` where `n`
+    /// is the order of the curve.
+    ///
+    /// `scalar` may be shorter than the curve's scalar length, in which case it is treated as if
+    /// it were left-padded with zeros. Scalars longer than the curve's scalar length are rejected.
+    /// The comparison against the curve order is performed in constant time with respect to the
+    /// value of `scalar`.
+    ///
+    /// This is useful for pre-validating imported private keys.
+    #[must_use]
+    pub fn scalar_in_range(&self, scalar: &[u8]) -> bool {
+        scalar_in_range(self.id, scalar).unwrap_or(false)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
 pub(crate) enum EcdsaSignatureFormat {
     ASN1,
     Fixed,
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Hash)]
 #[allow(non_camel_case_types)]
 pub(crate) enum AlgorithmID {
     ECDSA_P256,
     ECDSA_P384,
     ECDSA_P521,
     ECDSA_P256K1,
+    ECDSA_BRAINPOOL_P256R1,
+    ECDSA_BRAINPOOL_P384R1,
+    ECDSA_BRAINPOOL_P512R1,
 }
 
 impl AlgorithmID {
@@ -109,12 +252,30 @@ impl AlgorithmID {
             AlgorithmID::ECDSA_P384 => NID_secp384r1,
             AlgorithmID::ECDSA_P521 => NID_secp521r1,
             AlgorithmID::ECDSA_P256K1 => NID_secp256k1,
+            AlgorithmID::ECDSA_BRAINPOOL_P256R1 => NID_brainpoolP256r1,
+            AlgorithmID::ECDSA_BRAINPOOL_P384R1 => NID_brainpoolP384r1,
+            AlgorithmID::ECDSA_BRAINPOOL_P512R1 => NID_brainpoolP512r1,
+        }
+    }
+    pub(crate) fn curve_id(&self) -> CurveId {
+        match self {
+            AlgorithmID::ECDSA_P256 => CurveId::P256,
+            AlgorithmID::ECDSA_P384 => CurveId::P384,
+            AlgorithmID::ECDSA_P521 => CurveId::P521,
+            AlgorithmID::ECDSA_P256K1 => CurveId::P256K1,
+            AlgorithmID::ECDSA_BRAINPOOL_P256R1 => CurveId::BrainpoolP256R1,
+            AlgorithmID::ECDSA_BRAINPOOL_P384R1 => CurveId::BrainpoolP384R1,
+            AlgorithmID::ECDSA_BRAINPOOL_P512R1 => CurveId::BrainpoolP512R1,
         }
     }
+
     pub(crate) fn private_key_size(&self) -> usize {
         match self {
-            AlgorithmID::ECDSA_P256 | AlgorithmID::ECDSA_P256K1 => 32,
-            AlgorithmID::ECDSA_P384 => 48,
+            AlgorithmID::ECDSA_P256
+            | AlgorithmID::ECDSA_P256K1
+            | AlgorithmID::ECDSA_BRAINPOOL_P256R1 => 32,
+            AlgorithmID::ECDSA_P384 | AlgorithmID::ECDSA_BRAINPOOL_P384R1 => 48,
+            AlgorithmID::ECDSA_BRAINPOOL_P512R1 => 64,
             AlgorithmID::ECDSA_P521 => 66,
         }
     }
@@ -127,6 +288,114 @@ pub struct PublicKey {
     octets: Box<[u8]>,
 }
 
+#[cfg(feature = "jwk")]
+impl PublicKey {
+    pub(crate) fn algorithm(&self) -> &'static EcdsaSigningAlgorithm {
+        self.algorithm
+    }
+}
+
+impl PublicKey {
+    /// Parses `bytes` as a public key on the curve of `algorithm`, encoded in either the
+    /// compressed or the uncompressed form of the Octet-String-to-Elliptic-Curve-Point algorithm
+    /// in [SEC 1: Elliptic Curve Cryptography, Version 2.0].
+    ///
+    /// The encoding is retained as given; use `normalize` to obtain the uncompressed form.
+    ///
+    /// [SEC 1: Elliptic Curve Cryptography, Version 2.0]:
+    ///     http://www.secg.org/sec1-v2.pdf
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if `bytes` is not the encoding of a point on the curve.
+    pub fn from_bytes(
+        algorithm: &'static EcdsaSigningAlgorithm,
+        bytes: &[u8],
+    ) -> Result<Self, KeyRejected> {
+        let ec_group = ec_group_from_nid(algorithm.id.nid())?;
+        ec_point_from_bytes(&ec_group, bytes).map_err(|_| KeyRejected::invalid_encoding())?;
+        Ok(Self {
+            algorithm,
+            octets: bytes.into(),
+        })
+    }
+
+    /// Constructs a public key on the curve of `algorithm` from its raw affine coordinates `x`
+    /// and `y`, each encoded in `endianness` byte order.
+    ///
+    /// Each coordinate must be exactly the length of a field element of the curve, e.g. 32 bytes
+    /// for P-256, including any leading (for `Endianness::Big`) or trailing (for
+    /// `Endianness::Little`) zero bytes. The key is retained in the uncompressed form.
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if a coordinate has the wrong length or `(x, y)` is not a point on
+    /// the curve.
+    pub fn from_coordinates(
+        algorithm: &'static EcdsaSigningAlgorithm,
+        x: &[u8],
+        y: &[u8],
+        endianness: Endianness,
+    ) -> Result<Self, KeyRejected> {
+        let field_len = algorithm.id.private_key_size();
+        if x.len() != field_len || y.len() != field_len {
+            return Err(KeyRejected::invalid_encoding());
+        }
+        let mut octets = [0u8; PUBLIC_KEY_MAX_LEN];
+        let octets = &mut octets[..1 + 2 * field_len];
+        octets[0] = 0x04;
+        let (octets_x, octets_y) = octets[1..].split_at_mut(field_len);
+        octets_x.copy_from_slice(x);
+        octets_y.copy_from_slice(y);
+        if endianness == Endianness::Little {
+            octets_x.reverse();
+            octets_y.reverse();
+        }
+        Self::from_bytes(algorithm, octets)
+    }
+
+    /// Returns the public key re-encoded in the uncompressed form, regardless of the encoding it
+    /// was constructed from, so that keys can be compared and stored consistently.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the point could not be re-encoded.
+    pub fn normalize(&self) -> Result<Self, Unspecified> {
+        let ec_group = ec_group_from_nid(self.algorithm.id.nid())?;
+        let ec_point = ec_point_from_bytes(&ec_group, self.as_ref())?;
+        let mut buffer = [0u8; PUBLIC_KEY_MAX_LEN];
+        let out_len = unsafe {
+            ec_point_to_bytes(
+                &ec_group.as_const(),
+                &ec_point.as_const(),
+                &mut buffer,
+                point_conversion_form_t::POINT_CONVERSION_UNCOMPRESSED,
+            )
+        }?;
+        Ok(Self {
+            algorithm: self.algorithm,
+            octets: buffer[..out_len].into(),
+        })
+    }
+
+    /// Returns whether `other` is the same encoding of a public key as this one, e.g. to check a
+    /// peer's key against a pinned key.
+    ///
+    /// The comparison is done in constant time with respect to the contents of the keys, but not
+    /// their lengths. Keys are compared as encoded, so a compressed and an uncompressed encoding
+    /// of the same point do not match; use `normalize` to compare keys given in either form.
+    #[must_use]
+    pub fn matches(&self, other: &[u8]) -> bool {
+        constant_time::verify_slices_are_equal(self.as_ref(), other).is_ok()
+    }
+
+    /// Verifies `signature` over `msg` using this key and the verification algorithm of the
+    /// signing algorithm it was created for.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the signature is not valid.
+    pub fn verify(&self, msg: &[u8], signature: &[u8]) -> Result<(), Unspecified> {
+        self.algorithm.verify_sig(self.as_ref(), msg, signature)
+    }
+}
+
 impl AsDer<PublicKeyX509Der<'static>> for PublicKey {
     /// Provides the public key as a DER-encoded (X.509) `SubjectPublicKeyInfo` structure.
     /// # Errors
@@ -166,7 +435,8 @@ impl AsRef<[u8]> for PublicKey {
     #[inline]
     /// Serializes the public key in an uncompressed form (X9.62) using the
     /// Octet-String-to-Elliptic-Curve-Point algorithm in
-    /// [SEC 1: Elliptic Curve Cryptography, Version 2.0].
+    /// [SEC 1: Elliptic Curve Cryptography, Version 2.0]. A key constructed with
+    /// `PublicKey::from_bytes` is serialized in the form it was given in.
     fn as_ref(&self) -> &[u8] {
         self.octets.as_ref()
     }
@@ -196,28 +466,181 @@ impl VerificationAlgorithm for EcdsaVerificationAlgorithm {
         public_key: &[u8],
         msg: &[u8],
         signature: &[u8],
+    ) -> Result<(), Unspecified> {
+        self.verify_sig_segments(public_key, &[msg], signature)
+    }
+
+    fn verify_sig_segments(
+        &self,
+        public_key: &[u8],
+        segments: &[&[u8]],
+        signature: &[u8],
+    ) -> Result<(), Unspecified> {
+        let evp_pkey = evp_pkey_from_public_key(self.id, public_key)?;
+        self.verify_with_evp_pkey(&evp_pkey, segments, signature)
+    }
+}
+
+impl EcdsaVerificationAlgorithm {
+    fn verify_with_evp_pkey(
+        &self,
+        evp_pkey: &LcPtr<EVP_PKEY>,
+        segments: &[&[u8]],
+        signature: &[u8],
     ) -> Result<(), Unspecified> {
         match self.sig_format {
             EcdsaSignatureFormat::ASN1 => {
-                verify_asn1_signature(self.id, self.digest, public_key, msg, signature)
+                verify_asn1_signature(self.digest, evp_pkey, segments, signature)
             }
             EcdsaSignatureFormat::Fixed => {
-                verify_fixed_signature(self.id, self.digest, public_key, msg, signature)
+                verify_fixed_signature(self.id, self.digest, evp_pkey, segments, signature)
             }
         }
     }
 }
 
+/// The maximum length, in bytes, of the context of `verify_with_context` and
+/// `EcdsaKeyPair::sign_with_context`.
+pub const ECDSA_CONTEXT_MAX_LEN: usize = 255;
+
+/// Returns the `0x00 || len(context)` prefix of a domain-separated message.
+#[inline]
+pub(crate) fn context_prefix(context: &[u8]) -> Result<[u8; 2], Unspecified> {
+    let len = u8::try_from(context.len()).map_err(|_| Unspecified)?;
+    Ok([0x00, len])
+}
+
+/// Verifies `signature` over `msg` under the domain separation `context`.
+///
+/// The signed message is `0x00 || len(context) || context || msg`, where `len(context)` is a
+/// single byte. This is the message encoding that ML-DSA uses for its context strings
+/// ([FIPS 204], Algorithm 2); the result is then hashed and verified as usual by `alg`. Signatures
+/// for it are produced by `EcdsaKeyPair::sign_with_context`.
+///
+/// **This construction is not part of any ECDSA standard.** Signatures made with a context do not
+/// interoperate with other implementations unless they apply the same encoding. A key used with
+/// contexts should not also be used to sign plain messages, as a plain message may coincide with
+/// an encoded one.
+///
+/// [FIPS 204]: https://csrc.nist.gov/pubs/fips/204/final
+///
+/// # Errors
+/// `error::Unspecified` if `context` is longer than `ECDSA_CONTEXT_MAX_LEN`, or if the signature
+/// is not valid.
+pub fn verify_with_context(
+    alg: &'static EcdsaVerificationAlgorithm,
+    public_key: &[u8],
+    context: &[u8],
+    msg: &[u8],
+    signature: &[u8],
+) -> Result<(), Unspecified> {
+    let prefix = context_prefix(context)?;
+    alg.verify_sig_segments(public_key, &[&prefix, context, msg], signature)
+}
+
+/// Verifies a fixed-length P-256 ECDSA signature over `msg` with SHA-256.
+///
+/// This gives the same result as verifying with `ECDSA_P256_SHA256_FIXED`, but takes the
+/// uncompressed public key and the signature as fixed-size arrays, and computes the digest on the
+/// stack rather than going through the `EVP_DigestVerify` interface. It makes no heap
+/// allocations of its own; *AWS-LC* still allocates the key and signature objects it verifies
+/// with.
+///
+/// # Errors
+/// `error::Unspecified` if the public key is invalid or the signature is not valid.
+pub fn verify_p256_fixed(
+    public_key: &[u8; ECDSA_P256_PUBLIC_KEY_LEN],
+    msg: &[u8],
+    signature: &[u8; ECDSA_P256_SIGNATURE_FIXED_LEN],
+) -> Result<(), Unspecified> {
+    let digest = digest::digest(&digest::SHA256, msg);
+    let digest = digest.as_ref();
+
+    let ec_group = ec_group_from_nid(NID_X9_62_prime256v1)?;
+    let ec_point = ec_point_from_bytes(&ec_group, public_key)?;
+    let ec_key = LcPtr::new(unsafe { EC_KEY_new() })?;
+    if 1 != unsafe { EC_KEY_set_group(*ec_key, *ec_group) } {
+        return Err(Unspecified);
+    }
+    if 1 != unsafe { EC_KEY_set_public_key(*ec_key, *ec_point) } {
+        return Err(Unspecified);
+    }
+    check_ec_key(&ec_key.as_const())?;
+
+    let sig = unsafe { ecdsa_sig_from_fixed(&AlgorithmID::ECDSA_P256, signature) }?;
+    if 1 != unsafe { ECDSA_do_verify(digest.as_ptr(), digest.len(), *sig, *ec_key) } {
+        return Err(Unspecified);
+    }
+    Ok(())
+}
+
+/// Verifies a signature over `msg` given as its components `r` and `s`, each a big-endian
+/// integer, as used by protocols that encode signatures in their own wire format.
+///
+/// `alg` determines the curve and digest; its signature format is ignored. `r` and `s` may be
+/// shorter than the curve's scalar size, or left-padded with zeros up to it, but must be in the
+/// range `[1, n)` where `n` is the order of the curve.
+///
+/// # Errors
+/// `error::Unspecified` if `r` or `s` is out of range, or if the signature is not valid.
+pub fn verify_rs(
+    alg: &'static EcdsaVerificationAlgorithm,
+    public_key: &[u8],
+    msg: &[u8],
+    r: &[u8],
+    s: &[u8],
+) -> Result<(), Unspecified> {
+    for component in [r, s] {
+        if !scalar_in_range(alg.id, component)? {
+            return Err(Unspecified);
+        }
+    }
+    let sig = unsafe { ecdsa_sig_from_components(r, s)? };
+    let evp_pkey = evp_pkey_from_public_key(alg.id, public_key)?;
+    verify_ecdsa_sig(alg.digest, &evp_pkey, &[msg], &sig)
+}
+
+/// Computes the public key `d * G` of the private scalar `private_key`, for reconstructing a key
+/// pair from a bare scalar, e.g. one derived with BIP-32.
+///
+/// `private_key` is a big-endian unsigned integer that must be in the range `[1, n)`, where `n`
+/// is the order of the curve of `alg`. The public key is returned in the uncompressed SEC 1
+/// encoding, the same as the public key of an `EcdsaKeyPair`.
+///
+/// # Errors
+/// `error::Unspecified` if `private_key` is out of range.
+pub fn derive_public_key(
+    alg: &'static EcdsaSigningAlgorithm,
+    private_key: &[u8],
+) -> Result<PublicKey, Unspecified> {
+    if !alg.scalar_in_range(private_key) {
+        return Err(Unspecified);
+    }
+    let ec_group = ec_group_from_nid(alg.id.nid())?;
+    let private_bn = LcPtr::<BIGNUM>::try_from(private_key)?;
+    let evp_pkey = evp_pkey_from_private(&ec_group.as_const(), &private_bn.as_const())?;
+    marshal_public_key(&evp_pkey.as_const(), alg)
+}
+
 fn verify_fixed_signature(
     alg: &'static AlgorithmID,
     digest: &'static digest::Algorithm,
-    public_key: &[u8],
-    msg: &[u8],
+    evp_pkey: &LcPtr<EVP_PKEY>,
+    msg: &[&[u8]],
     signature: &[u8],
+) -> Result<(), Unspecified> {
+    let sig = unsafe { ecdsa_sig_from_fixed(alg, signature)? };
+    verify_ecdsa_sig(digest, evp_pkey, msg, &sig)
+}
+
+fn verify_ecdsa_sig(
+    digest: &'static digest::Algorithm,
+    evp_pkey: &LcPtr<EVP_PKEY>,
+    msg: &[&[u8]],
+    sig: &LcPtr<ECDSA_SIG>,
 ) -> Result<(), Unspecified> {
     let mut out_bytes = null_mut::<u8>();
     let mut out_bytes_len = MaybeUninit::<usize>::uninit();
-    let sig = unsafe { ecdsa_sig_from_fixed(alg, signature)? };
     if 1 != unsafe {
         ECDSA_SIG_to_bytes(&mut out_bytes, out_bytes_len.as_mut_ptr(), *sig.as_const())
     } {
@@ -225,41 +648,32 @@ fn verify_fixed_signature(
     }
     let out_bytes = LcPtr::new(out_bytes)?;
     let signature = unsafe { out_bytes.as_slice(out_bytes_len.assume_init()) };
-    verify_asn1_signature(alg, digest, public_key, msg, signature)
+    verify_asn1_signature(digest, evp_pkey, msg, signature)
 }
 
 fn verify_asn1_signature(
-    alg: &'static AlgorithmID,
     digest: &'static digest::Algorithm,
-    public_key: &[u8],
-    msg: &[u8],
+    evp_pkey: &LcPtr<EVP_PKEY>,
+    msg: &[&[u8]],
     signature: &[u8],
 ) -> Result<(), Unspecified> {
-    let pkey = evp_pkey_from_public_key(alg, public_key)?;
-
     let mut md_ctx = DigestContext::new_uninit();
 
     let digest = digest::match_digest_type(&digest.id);
 
     if 1 != unsafe {
-        EVP_DigestVerifyInit(md_ctx.as_mut_ptr(), null_mut(), *digest, null_mut(), *pkey)
-    } {
-        return Err(Unspecified);
-    }
-
-    if 1 != indicator_check!(unsafe {
-        EVP_DigestVerify(
+        EVP_DigestVerifyInit(
             md_ctx.as_mut_ptr(),
-            signature.as_ptr(),
-            signature.len(),
-            msg.as_ptr(),
-            msg.len(),
+            null_mut(),
+            *digest,
+            null_mut(),
+            **evp_pkey,
         )
-    }) {
+    } {
         return Err(Unspecified);
     }
 
-    Ok(())
+    md_ctx.verify(msg, signature)
 }
 
 #[inline]
@@ -274,26 +688,46 @@ fn evp_pkey_from_public_key(
     Ok(pkey)
 }
 
+/// Returns the `EC_KEY` of `evp_pkey`, rejecting keys of any other type as the wrong algorithm.
+#[inline]
+fn evp_pkey_ec_key(evp_pkey: &ConstPointer<EVP_PKEY>) -> Result<ConstPointer<EC_KEY>, KeyRejected> {
+    if EVP_PKEY_EC != unsafe { EVP_PKEY_id(**evp_pkey) } {
+        return Err(KeyRejected::wrong_algorithm());
+    }
+    Ok(ConstPointer::new(unsafe {
+        EVP_PKEY_get0_EC_KEY(**evp_pkey)
+    })?)
+}
+
+/// Rejects `ec_key` as the wrong algorithm unless its group is the named curve
+/// `expected_curve_nid`.
 fn verify_ec_key_nid(
     ec_key: &ConstPointer<EC_KEY>,
     expected_curve_nid: i32,
 ) -> Result<(), KeyRejected> {
-    let ec_group = ConstPointer::new(unsafe { EC_KEY_get0_group(**ec_key) })?;
-    let key_nid = unsafe { EC_GROUP_get_curve_name(*ec_group) };
-
-    if key_nid != expected_curve_nid {
+    if ec_key_curve_nid(ec_key)? != expected_curve_nid {
         return Err(KeyRejected::wrong_algorithm());
     }
     Ok(())
 }
 
+fn ec_key_curve_nid(ec_key: &ConstPointer<EC_KEY>) -> Result<i32, KeyRejected> {
+    let ec_group = ConstPointer::new(unsafe { EC_KEY_get0_group(**ec_key) })?;
+    Ok(unsafe { EC_GROUP_get_curve_name(*ec_group) })
+}
+
+/// Returns the NID of the named curve of the EC key `evp_pkey`.
+pub(crate) fn evp_pkey_curve_nid(evp_pkey: &ConstPointer<EVP_PKEY>) -> Result<i32, KeyRejected> {
+    ec_key_curve_nid(&evp_pkey_ec_key(evp_pkey)?)
+}
+
 #[inline]
 #[cfg(not(feature = "fips"))]
 pub(crate) fn verify_evp_key_nid(
     evp_pkey: &ConstPointer<EVP_PKEY>,
     expected_curve_nid: i32,
 ) -> Result<(), KeyRejected> {
-    let ec_key = ConstPointer::new(unsafe { EVP_PKEY_get0_EC_KEY(**evp_pkey) })?;
+    let ec_key = evp_pkey_ec_key(evp_pkey)?;
     verify_ec_key_nid(&ec_key, expected_curve_nid)?;
 
     Ok(())
@@ -304,16 +738,20 @@ fn validate_evp_key(
     evp_pkey: &ConstPointer<EVP_PKEY>,
     expected_curve_nid: i32,
 ) -> Result<(), KeyRejected> {
-    let ec_key = ConstPointer::new(unsafe { EVP_PKEY_get0_EC_KEY(**evp_pkey) })?;
+    let ec_key = evp_pkey_ec_key(evp_pkey)?;
     verify_ec_key_nid(&ec_key, expected_curve_nid)?;
+    check_ec_key(&ec_key)
+}
 
+#[inline]
+fn check_ec_key(ec_key: &ConstPointer<EC_KEY>) -> Result<(), KeyRejected> {
     #[cfg(not(feature = "fips"))]
-    if 1 != unsafe { EC_KEY_check_key(*ec_key) } {
+    if 1 != unsafe { EC_KEY_check_key(**ec_key) } {
         return Err(KeyRejected::inconsistent_components());
     }
 
     #[cfg(feature = "fips")]
-    if 1 != indicator_check!(unsafe { EC_KEY_check_fips(*ec_key) }) {
+    if 1 != indicator_check!(unsafe { EC_KEY_check_fips(**ec_key) }) {
         return Err(KeyRejected::inconsistent_components());
     }
 
@@ -378,10 +816,32 @@ pub(crate) unsafe fn marshal_ec_public_key_to_buffer(
 
     let ec_point = ConstPointer::new(EC_KEY_get0_public_key(**ec_key))?;
 
-    let out_len = ec_point_to_bytes(&ec_group, &ec_point, buffer)?;
+    let out_len = ec_point_to_bytes(
+        &ec_group,
+        &ec_point,
+        buffer,
+        point_conversion_form_t::POINT_CONVERSION_UNCOMPRESSED,
+    )?;
     Ok(out_len)
 }
 
+pub(crate) unsafe fn marshal_public_key_compressed(
+    evp_pkey: &ConstPointer<EVP_PKEY>,
+) -> Result<Box<[u8]>, Unspecified> {
+    let ec_key = ConstPointer::new(EVP_PKEY_get0_EC_KEY(**evp_pkey))?;
+    let ec_group = ConstPointer::new(EC_KEY_get0_group(*ec_key))?;
+    let ec_point = ConstPointer::new(EC_KEY_get0_public_key(*ec_key))?;
+
+    let mut buffer = [0u8; PUBLIC_KEY_MAX_LEN];
+    let out_len = ec_point_to_bytes(
+        &ec_group,
+        &ec_point,
+        &mut buffer,
+        point_conversion_form_t::POINT_CONVERSION_COMPRESSED,
+    )?;
+    Ok(buffer[..out_len].into())
+}
+
 pub(crate) fn marshal_public_key(
     evp_pkey: &ConstPointer<EVP_PKEY>,
     algorithm: &'static EcdsaSigningAlgorithm,
@@ -523,9 +983,31 @@ pub(crate) unsafe fn evp_key_from_public_private(
 
 #[inline]
 pub(crate) fn ec_group_from_nid(nid: i32) -> Result<LcPtr<EC_GROUP>, ()> {
+    if let Some(curve) = brainpool::curve(nid) {
+        return curve.ec_group();
+    }
     LcPtr::new(unsafe { EC_GROUP_new_by_curve_name(nid) })
 }
 
+/// Constructs the group of the curve `y^2 = x^3 + a*x + b` over the prime field of order `p`,
+/// generated by the SEC 1 encoded point `generator` of order `order`.
+pub(crate) fn ec_group_from_parameters(
+    p: &LcPtr<BIGNUM>,
+    a: &LcPtr<BIGNUM>,
+    b: &LcPtr<BIGNUM>,
+    generator: &[u8],
+    order: &LcPtr<BIGNUM>,
+    cofactor: &LcPtr<BIGNUM>,
+) -> Result<LcPtr<EC_GROUP>, Unspecified> {
+    let ec_group = LcPtr::new(unsafe { EC_GROUP_new_curve_GFp(**p, **a, **b, null_mut()) })?;
+    // `EC_POINT_oct2point` rejects points that are not on the curve.
+    let generator = ec_point_from_bytes(&ec_group, generator)?;
+    if 1 != unsafe { EC_GROUP_set_generator(*ec_group, *generator, **order, **cofactor) } {
+        return Err(Unspecified);
+    }
+    Ok(ec_group)
+}
+
 #[inline]
 pub(crate) fn ec_point_from_bytes(
     ec_group: &LcPtr<EC_GROUP>,
@@ -548,14 +1030,29 @@ pub(crate) fn ec_point_from_bytes(
     Ok(ec_point)
 }
 
+/// Checks that `n * ec_point` is the point at infinity, where `n` is the order of `ec_group`.
+fn verify_point_in_subgroup(
+    ec_group: &LcPtr<EC_GROUP>,
+    ec_point: &LcPtr<EC_POINT>,
+) -> Result<(), Unspecified> {
+    let order = ConstPointer::new(unsafe { EC_GROUP_get0_order(**ec_group) })?;
+    let product = LcPtr::new(unsafe { EC_POINT_new(**ec_group) })?;
+    if 1 != unsafe { EC_POINT_mul(**ec_group, *product, null(), **ec_point, *order, null_mut()) } {
+        return Err(Unspecified);
+    }
+    if 1 != unsafe { EC_POINT_is_at_infinity(**ec_group, *product) } {
+        return Err(Unspecified);
+    }
+    Ok(())
+}
+
 #[inline]
 unsafe fn ec_point_to_bytes(
     ec_group: &ConstPointer<EC_GROUP>,
     ec_point: &ConstPointer<EC_POINT>,
     buf: &mut [u8; PUBLIC_KEY_MAX_LEN],
+    pt_conv_form: point_conversion_form_t,
 ) -> Result<usize, Unspecified> {
-    let pt_conv_form = point_conversion_form_t::POINT_CONVERSION_UNCOMPRESSED;
-
     let out_len = EC_POINT_point2oct(
         **ec_group,
         **ec_point,
@@ -571,29 +1068,109 @@ unsafe fn ec_point_to_bytes(
     Ok(out_len)
 }
 
+/// Computes `scalar * point`, or `scalar * G` where `G` is the generator of the curve if `point`
+/// is `None`, on the curve of `alg`.
+///
+/// `scalar` is a big-endian unsigned integer that must be in the range `[1, n)`, where `n` is the
+/// order of the curve. `point` must be a SEC 1 encoded point on the curve. The result is returned
+/// in the uncompressed SEC 1 encoding.
+///
+/// This function is only available with the `low-level` feature. It is intended for protocols,
+/// such as ECIES or VRFs, that are built directly on the curve's group operation.
+///
+/// # Errors
+/// `error::Unspecified` if `scalar` is out of range, or if `point` is not a valid point on the
+/// curve.
+#[cfg(feature = "low-level")]
+pub fn point_mul(
+    alg: &'static EcdsaSigningAlgorithm,
+    scalar: &[u8],
+    point: Option<&[u8]>,
+) -> Result<Box<[u8]>, Unspecified> {
+    if !alg.scalar_in_range(scalar) {
+        return Err(Unspecified);
+    }
+    let ec_group = ec_group_from_nid(alg.id.nid())?;
+    let scalar = LcPtr::<BIGNUM>::try_from(scalar)?;
+    let result = LcPtr::new(unsafe { EC_POINT_new(*ec_group) })?;
+
+    if 1 != match point {
+        None => unsafe { EC_POINT_mul(*ec_group, *result, *scalar, null(), null(), null_mut()) },
+        Some(point) => {
+            // `EC_POINT_oct2point` rejects points that are not on the curve.
+            let point = ec_point_from_bytes(&ec_group, point)?;
+            unsafe { EC_POINT_mul(*ec_group, *result, null(), *point, *scalar, null_mut()) }
+        }
+    } {
+        return Err(Unspecified);
+    }
+
+    let mut buffer = [0u8; PUBLIC_KEY_MAX_LEN];
+    let out_len = unsafe {
+        ec_point_to_bytes(
+            &ec_group.as_const(),
+            &result.as_const(),
+            &mut buffer,
+            point_conversion_form_t::POINT_CONVERSION_UNCOMPRESSED,
+        )
+    }?;
+    Ok(buffer[..out_len].into())
+}
+
 #[inline]
 fn ecdsa_asn1_to_fixed(alg_id: &'static AlgorithmID, sig: &[u8]) -> Result<Signature, Unspecified> {
-    let expected_number_size = alg_id.private_key_size();
+    let num_size_bytes = alg_id.private_key_size();
 
     let ecdsa_sig = LcPtr::new(unsafe { ECDSA_SIG_from_bytes(sig.as_ptr(), sig.len()) })?;
-
     let r_bn = ConstPointer::new(unsafe { ECDSA_SIG_get0_r(*ecdsa_sig) })?;
-    let r_buffer = r_bn.to_be_bytes();
-
     let s_bn = ConstPointer::new(unsafe { ECDSA_SIG_get0_s(*ecdsa_sig) })?;
-    let s_buffer = s_bn.to_be_bytes();
 
-    Ok(Signature::new(|slice| {
-        let (r_start, r_end) = (expected_number_size - r_buffer.len(), expected_number_size);
-        let (s_start, s_end) = (
-            2 * expected_number_size - s_buffer.len(),
-            2 * expected_number_size,
-        );
+    // `BN_bn2bin_padded` writes each component directly into the signature, left-padded with
+    // zeros without branching on the length of the value, and fails if it does not fit.
+    Signature::try_new(|slice| {
+        let (r_out, s_out) = slice[..2 * num_size_bytes].split_at_mut(num_size_bytes);
+        if 1 != unsafe { BN_bn2bin_padded(r_out.as_mut_ptr(), num_size_bytes, *r_bn) }
+            || 1 != unsafe { BN_bn2bin_padded(s_out.as_mut_ptr(), num_size_bytes, *s_bn) }
+        {
+            return Err(Unspecified);
+        }
+        Ok(2 * num_size_bytes)
+    })
+}
 
-        slice[r_start..r_end].copy_from_slice(r_buffer.as_slice());
-        slice[s_start..s_end].copy_from_slice(s_buffer.as_slice());
-        2 * expected_number_size
-    }))
+fn scalar_in_range(alg_id: &'static AlgorithmID, scalar: &[u8]) -> Result<bool, ()> {
+    let order_len = alg_id.private_key_size();
+    if scalar.len() > order_len {
+        return Ok(false);
+    }
+
+    let ec_group = ec_group_from_nid(alg_id.nid())?;
+    let order = ConstPointer::new(unsafe { EC_GROUP_get0_order(*ec_group) })?;
+    let mut order_bytes = [0u8; SCALAR_MAX_BYTES];
+    let order_bytes = &mut order_bytes[..order_len];
+    if 1 != unsafe { BN_bn2bin_padded(order_bytes.as_mut_ptr(), order_len, *order) } {
+        return Err(());
+    }
+
+    // Compute `scalar - order` from the least significant byte, keeping only the final borrow,
+    // which is set exactly when `scalar < order`. Also accumulate whether `scalar` is non-zero.
+    let padding_len = order_len - scalar.len();
+    let mut borrow = 0u16;
+    let mut non_zero = 0u8;
+    for (i, order_byte) in order_bytes.iter().enumerate().rev() {
+        let scalar_byte = if i < padding_len {
+            0
+        } else {
+            scalar[i - padding_len]
+        };
+        let difference = u16::from(scalar_byte)
+            .wrapping_sub(u16::from(*order_byte))
+            .wrapping_sub(borrow);
+        borrow = (difference >> 8) & 1;
+        non_zero |= scalar_byte;
+    }
+
+    Ok((borrow == 1) & (non_zero != 0))
 }
 
 #[inline]
@@ -605,8 +1182,14 @@ unsafe fn ecdsa_sig_from_fixed(
     if signature.len() != 2 * num_size_bytes {
         return Err(());
     }
-    let r_bn = DetachableLcPtr::<BIGNUM>::try_from(&signature[..num_size_bytes])?;
-    let s_bn = DetachableLcPtr::<BIGNUM>::try_from(&signature[num_size_bytes..])?;
+    let (r, s) = signature.split_at(num_size_bytes);
+    ecdsa_sig_from_components(r, s)
+}
+
+#[inline]
+unsafe fn ecdsa_sig_from_components(r: &[u8], s: &[u8]) -> Result<LcPtr<ECDSA_SIG>, ()> {
+    let r_bn = DetachableLcPtr::<BIGNUM>::try_from(r)?;
+    let s_bn = DetachableLcPtr::<BIGNUM>::try_from(s)?;
 
     let ecdsa_sig = LcPtr::new(ECDSA_SIG_new())?;
 
@@ -621,12 +1204,119 @@ unsafe fn ecdsa_sig_from_fixed(
 
 #[cfg(test)]
 mod tests {
-    use crate::encoding::{AsDer, PublicKeyX509Der};
+    use crate::encoding::{AsBigEndian, AsDer, PublicKeyX509Der};
     use crate::signature::EcdsaKeyPair;
     use crate::signature::{KeyPair, ECDSA_P256_SHA256_FIXED_SIGNING};
     use crate::test::from_dirty_hex;
     use crate::{signature, test};
 
+    #[cfg(feature = "unstable-ffi")]
+    #[test]
+    fn test_as_evp_pkey_ptr() {
+        use aws_lc::{EVP_PKEY_id, EVP_PKEY_EC};
+
+        let key_pair = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_FIXED_SIGNING).unwrap();
+        let evp_pkey = key_pair.as_evp_pkey_ptr();
+        assert!(!evp_pkey.is_null());
+        assert_eq!(EVP_PKEY_EC, unsafe { EVP_PKEY_id(evp_pkey) });
+    }
+
+    #[cfg(feature = "low-level")]
+    #[test]
+    fn test_point_mul() {
+        use crate::ec::point_mul;
+        use crate::encoding::EcPrivateKeyBin;
+
+        for alg in [
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256K1_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+        ] {
+            let a = EcdsaKeyPair::generate(alg).unwrap();
+            let b = EcdsaKeyPair::generate(alg).unwrap();
+            let a_scalar: EcPrivateKeyBin = a.private_key().as_be_bytes().unwrap();
+            let b_scalar: EcPrivateKeyBin = b.private_key().as_be_bytes().unwrap();
+
+            // scalar * G is the public key.
+            let a_public = point_mul(alg, a_scalar.as_ref(), None).unwrap();
+            assert_eq!(a.public_key().as_ref(), a_public.as_ref());
+
+            // a * (b * G) == b * (a * G)
+            let ab = point_mul(alg, a_scalar.as_ref(), Some(b.public_key().as_ref())).unwrap();
+            let ba = point_mul(alg, b_scalar.as_ref(), Some(a_public.as_ref())).unwrap();
+            assert_eq!(ab, ba);
+
+            // Scalars out of range are rejected.
+            assert!(point_mul(alg, &[0u8], None).is_err());
+            assert!(point_mul(alg, &[0xffu8; 67], None).is_err());
+
+            // Points not on the curve are rejected.
+            let mut off_curve = a_public.to_vec();
+            let last = off_curve.len() - 1;
+            off_curve[last] ^= 1;
+            assert!(point_mul(alg, b_scalar.as_ref(), Some(&off_curve)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_scalar_in_range() {
+        for (alg, order) in [
+            (
+                &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+                "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+            ),
+            (
+                &signature::ECDSA_P256K1_SHA256_ASN1_SIGNING,
+                "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+            ),
+            (
+                &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+                "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+            ),
+            (
+                &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+                "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\
+                 fa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+            ),
+        ] {
+            let order = from_dirty_hex(order);
+            let len = order.len();
+
+            let zero = vec![0u8; len];
+            assert!(!alg.scalar_in_range(&zero));
+            assert!(!alg.scalar_in_range(&[]));
+            assert!(!alg.scalar_in_range(&[0]));
+
+            let mut one = vec![0u8; len];
+            one[len - 1] = 1;
+            assert!(alg.scalar_in_range(&one));
+            assert!(alg.scalar_in_range(&[1]));
+
+            let mut order_minus_one = order.clone();
+            order_minus_one[len - 1] -= 1;
+            assert!(alg.scalar_in_range(&order_minus_one));
+
+            assert!(!alg.scalar_in_range(&order));
+
+            let mut order_plus_one = order.clone();
+            order_plus_one[len - 1] += 1;
+            assert!(!alg.scalar_in_range(&order_plus_one));
+
+            assert!(!alg.scalar_in_range(&vec![0xff; len]));
+
+            // Too long, even though the value is in range.
+            let mut padded_one = vec![0u8; len + 1];
+            padded_one[len] = 1;
+            assert!(!alg.scalar_in_range(&padded_one));
+        }
+
+        // A generated key's private scalar is always in range.
+        let key_pair = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_FIXED_SIGNING).unwrap();
+        let private_key = key_pair.private_key().as_be_bytes().unwrap();
+        assert!(ECDSA_P256_SHA256_FIXED_SIGNING.scalar_in_range(private_key.as_ref()));
+    }
+
     #[test]
     fn test_from_pkcs8() {
         let input = from_dirty_hex(
@@ -639,8 +1329,12 @@ mod tests {
         let result = EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &input);
         assert!(result.is_ok());
         let key_pair = result.unwrap();
-        assert_eq!("EcdsaKeyPair { public_key: EcdsaPublicKey(\"04cf0d13a3a7577231ea1b66cf4021cd54f21f4ac4f5f2fdd28e05bc7d2bd099d1374cd08d2ef654d6f04498db462f73e0282058dd661a4c9b0437af3f7af6e724\") }",
-                   format!("{key_pair:?}"));
+        assert_eq!(
+            "EcdsaKeyPair { algorithm: ECDSA_P256 }",
+            format!("{key_pair:?}")
+        );
+        assert_eq!("EcdsaPublicKey(\"04cf0d13a3a7577231ea1b66cf4021cd54f21f4ac4f5f2fdd28e05bc7d2bd099d1374cd08d2ef654d6f04498db462f73e0282058dd661a4c9b0437af3f7af6e724\")",
+                   format!("{:?}", key_pair.public_key()));
         assert_eq!(
             "EcdsaPrivateKey(ECDSA_P256)",
             format!("{:?}", key_pair.private_key())
@@ -685,4 +1379,27 @@ mod tests {
         let actual_result = unparsed_pub_key.verify(msg.as_bytes(), &sig);
         assert!(actual_result.is_ok(), "Key: {}", test::to_hex(public_key));
     }
+
+    #[test]
+    fn test_ecdsa_asn1_to_fixed_too_long() {
+        use crate::ec::ecdsa_asn1_to_fixed;
+
+        let alg = ECDSA_P256_SHA256_FIXED_SIGNING.id;
+        let r = "022100c0ffee0000000000000000000000000000000000000000000000000000000001";
+        let s = "020101";
+        let too_long_s = "022101ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+
+        let sig = from_dirty_hex(&format!("3026{r}{s}"));
+        let fixed = ecdsa_asn1_to_fixed(alg, &sig).unwrap();
+        let mut expected = from_dirty_hex(&r[6..]);
+        expected.extend_from_slice(&[0u8; 31]);
+        expected.push(1);
+        assert_eq!(expected, fixed.as_ref());
+
+        // r and s fit in an ASN.1 INTEGER but not in the fixed-length encoding.
+        let sig = from_dirty_hex(&format!("3046{r}{too_long_s}"));
+        assert!(ecdsa_asn1_to_fixed(alg, &sig).is_err());
+        let sig = from_dirty_hex(&format!("3046{too_long_s}{r}"));
+        assert!(ecdsa_asn1_to_fixed(alg, &sig).is_err());
+    }
 }
@@ -0,0 +1,218 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Elliptic curves defined by explicit domain parameters.
+
+use crate::digest;
+use crate::ec::{
+    ec_group_from_parameters, ec_point_from_bytes, evp_pkey_from_public_point,
+    verify_asn1_signature, verify_point_in_subgroup,
+};
+use crate::error::Unspecified;
+use crate::ptr::LcPtr;
+use aws_lc::{
+    BN_CTX_new, BN_is_prime_ex, BN_is_zero, BN_mod_add, BN_mod_mul, BN_mod_sqr, BN_new,
+    BN_prime_checks_for_validation, BIGNUM, BN_CTX, EC_GROUP,
+};
+use core::fmt::{self, Debug, Formatter};
+use core::ptr::null_mut;
+
+/// An elliptic curve `y^2 = x^3 + a*x + b` over a prime field, defined by its explicit domain
+/// parameters.
+///
+/// This allows ECDSA signatures to be verified on curves that have no built-in support, for
+/// research or for interoperating with legacy systems. Prefer the algorithms of the `signature`
+/// module wherever possible: their curves have dedicated, constant-time implementations, while a
+/// custom curve uses generic arithmetic.
+///
+/// This type is only available with the `low-level` feature.
+pub struct CustomCurve {
+    ec_group: LcPtr<EC_GROUP>,
+}
+
+impl CustomCurve {
+    /// Constructs a curve from its domain parameters: the prime `p` of the field, the
+    /// coefficients `a` and `b`, the `generator` as a SEC 1 encoded point, and its `order` and
+    /// the `cofactor` of the curve. Each integer is big-endian and unsigned.
+    ///
+    /// The parameters are validated: `p` and `order` must be prime, the curve must not be
+    /// singular (`4*a^3 + 27*b^2 != 0 mod p`), and `generator` must be a point on the curve of
+    /// order `order`. *AWS-LC* only supports custom curves of prime order, so `cofactor` must be
+    /// one, and `order` must have the same bit length as `p`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the parameters do not define a valid curve.
+    pub fn new(
+        p: &[u8],
+        a: &[u8],
+        b: &[u8],
+        generator: &[u8],
+        order: &[u8],
+        cofactor: &[u8],
+    ) -> Result<Self, Unspecified> {
+        let ctx = LcPtr::new(unsafe { BN_CTX_new() })?;
+        let p = LcPtr::<BIGNUM>::try_from(p)?;
+        let a = LcPtr::<BIGNUM>::try_from(a)?;
+        let b = LcPtr::<BIGNUM>::try_from(b)?;
+        let order = LcPtr::<BIGNUM>::try_from(order)?;
+        let cofactor = LcPtr::<BIGNUM>::try_from(cofactor)?;
+
+        if !is_prime(&p, &ctx) || !is_prime(&order, &ctx) || is_singular(&p, &a, &b, &ctx)? {
+            return Err(Unspecified);
+        }
+
+        let ec_group = ec_group_from_parameters(&p, &a, &b, generator, &order, &cofactor)?;
+        let generator = ec_point_from_bytes(&ec_group, generator)?;
+        verify_point_in_subgroup(&ec_group, &generator)?;
+
+        Ok(Self { ec_group })
+    }
+
+    /// Verifies the ASN.1 DER-encoded ECDSA `signature` of `msg`, digested with `digest`, using
+    /// `public_key`, a SEC 1 encoded point on this curve.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `public_key` is not a point on the curve, or if the signature is
+    /// not valid.
+    pub fn verify(
+        &self,
+        digest: &'static digest::Algorithm,
+        public_key: &[u8],
+        msg: &[u8],
+        signature: &[u8],
+    ) -> Result<(), Unspecified> {
+        let ec_point = ec_point_from_bytes(&self.ec_group, public_key)?;
+        let evp_pkey = evp_pkey_from_public_point(&self.ec_group, &ec_point)?;
+        verify_asn1_signature(digest, &evp_pkey, &[msg], signature)
+    }
+}
+
+impl Debug for CustomCurve {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("CustomCurve").finish_non_exhaustive()
+    }
+}
+
+unsafe impl Send for CustomCurve {}
+unsafe impl Sync for CustomCurve {}
+
+fn is_prime(candidate: &LcPtr<BIGNUM>, ctx: &LcPtr<BN_CTX>) -> bool {
+    1 == unsafe {
+        BN_is_prime_ex(
+            **candidate,
+            BN_prime_checks_for_validation,
+            **ctx,
+            null_mut(),
+        )
+    }
+}
+
+/// Returns whether `4*a^3 + 27*b^2 = 0 mod p`, in which case the curve is singular.
+fn is_singular(
+    p: &LcPtr<BIGNUM>,
+    a: &LcPtr<BIGNUM>,
+    b: &LcPtr<BIGNUM>,
+    ctx: &LcPtr<BN_CTX>,
+) -> Result<bool, Unspecified> {
+    let four = LcPtr::<BIGNUM>::try_from(4u64)?;
+    let twenty_seven = LcPtr::<BIGNUM>::try_from(27u64)?;
+    let a_term = LcPtr::new(unsafe { BN_new() })?;
+    let b_term = LcPtr::new(unsafe { BN_new() })?;
+    if 1 != unsafe { BN_mod_sqr(*a_term, **a, **p, **ctx) }
+        || 1 != unsafe { BN_mod_mul(*a_term, *a_term, **a, **p, **ctx) }
+        || 1 != unsafe { BN_mod_mul(*a_term, *a_term, *four, **p, **ctx) }
+        || 1 != unsafe { BN_mod_sqr(*b_term, **b, **p, **ctx) }
+        || 1 != unsafe { BN_mod_mul(*b_term, *b_term, *twenty_seven, **p, **ctx) }
+        || 1 != unsafe { BN_mod_add(*a_term, *a_term, *b_term, **p, **ctx) }
+    {
+        return Err(Unspecified);
+    }
+    Ok(1 == unsafe { BN_is_zero(*a_term) })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ec::CustomCurve;
+    use crate::rand::SystemRandom;
+    use crate::signature::{EcdsaKeyPair, KeyPair, ECDSA_P256_SHA256_ASN1_SIGNING};
+    use crate::{digest, test};
+
+    // The domain parameters of P-256, from SEC 2, Section 2.4.2.
+    const P: &str = "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+    const A: &str = "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc";
+    const B: &str = "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b";
+    const G: &str = "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296\
+                     4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5";
+    const N: &str = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+
+    fn p256(p: &str, a: &str, b: &str, g: &str, n: &str, h: &[u8]) -> Option<CustomCurve> {
+        CustomCurve::new(
+            &test::from_hex(p).unwrap(),
+            &test::from_hex(a).unwrap(),
+            &test::from_hex(b).unwrap(),
+            &test::from_dirty_hex(g),
+            &test::from_hex(n).unwrap(),
+            h,
+        )
+        .ok()
+    }
+
+    #[test]
+    fn test_custom_curve_p256() {
+        let curve = p256(P, A, B, G, N, &[1]).unwrap();
+
+        // RFC 6979, A.2.5: ECDSA, 256 Bits (Prime Field), with SHA-256, message = "sample"
+        let public_key = test::from_dirty_hex(
+            "0460FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6
+             7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299",
+        );
+        let sig = test::from_dirty_hex(
+            "3046022100EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716
+             022100F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8",
+        );
+        curve
+            .verify(&digest::SHA256, &public_key, b"sample", &sig)
+            .unwrap();
+        assert!(curve
+            .verify(&digest::SHA256, &public_key, b"test", &sig)
+            .is_err());
+        assert!(curve
+            .verify(&digest::SHA384, &public_key, b"sample", &sig)
+            .is_err());
+
+        // Signatures made on the named curve verify on the reconstructed one.
+        let rng = SystemRandom::new();
+        let key_pair = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+        let sig = key_pair.sign(&rng, b"hello, world").unwrap();
+        curve
+            .verify(
+                &digest::SHA256,
+                key_pair.public_key().as_ref(),
+                b"hello, world",
+                sig.as_ref(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_custom_curve_invalid_parameters() {
+        // `p` is not prime.
+        let not_prime = "ffffffff00000001000000000000000000000000fffffffffffffffffffffffd";
+        assert!(p256(not_prime, A, B, G, N, &[1]).is_none());
+        // The order is not prime.
+        let not_prime = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632553";
+        assert!(p256(P, A, B, G, not_prime, &[1]).is_none());
+        // The order is prime, but not the order of the generator.
+        let wrong_order = "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+        assert!(p256(P, A, B, G, wrong_order, &[1]).is_none());
+        // The generator is not on the curve.
+        let off_curve = "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296\
+                         4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f4";
+        assert!(p256(P, A, B, off_curve, N, &[1]).is_none());
+        // y^2 = x^3 is singular.
+        let zero = "00";
+        assert!(p256(P, zero, zero, G, N, &[1]).is_none());
+        // Only curves of prime order are supported.
+        assert!(p256(P, A, B, G, N, &[2]).is_none());
+    }
+}
@@ -8,14 +8,19 @@ use core::fmt::{Debug, Formatter};
 use core::mem::MaybeUninit;
 use core::ptr::{null, null_mut};
 
-use aws_lc::{EVP_DigestSign, EVP_DigestSignInit, EVP_PKEY_get0_EC_KEY, EVP_PKEY};
+#[cfg(feature = "danger-test-only")]
+use aws_lc::{ECDSA_SIG_to_bytes, ECDSA_sign_with_nonce_and_leak_private_key_for_testing};
+use aws_lc::{EVP_DigestSign, EVP_DigestSignInit, EVP_PKEY_get0_EC_KEY, BIGNUM, EVP_PKEY};
 
 use crate::digest::digest_ctx::DigestContext;
 #[cfg(feature = "fips")]
 use crate::ec::validate_evp_key;
 #[cfg(not(feature = "fips"))]
 use crate::ec::verify_evp_key_nid;
-use crate::ec::{evp_key_generate, EcdsaSignatureFormat, EcdsaSigningAlgorithm, PublicKey};
+use crate::ec::{
+    ec_group_from_nid, evp_key_generate, evp_pkey_curve_nid, CurveId, EcdsaSignatureFormat,
+    EcdsaSigningAlgorithm, PublicKey,
+};
 
 use crate::encoding::{AsBigEndian, AsDer, EcPrivateKeyBin, EcPrivateKeyRfc5915Der};
 use crate::error::{KeyRejected, Unspecified};
@@ -23,8 +28,13 @@ use crate::fips::indicator_check;
 use crate::pkcs8::{Document, Version};
 use crate::ptr::{ConstPointer, DetachableLcPtr, LcPtr};
 use crate::rand::SecureRandom;
-use crate::signature::{KeyPair, Signature};
+use crate::signature::{
+    KeyPair, Signature, VerificationAlgorithm, ECDSA_P256K1_SHA256_ASN1_SIGNING,
+    ECDSA_P256_SHA256_ASN1_SIGNING, ECDSA_P384_SHA384_ASN1_SIGNING, ECDSA_P521_SHA512_ASN1_SIGNING,
+    MAX_LEN,
+};
 use crate::{digest, ec};
+use zeroize::Zeroizing;
 
 /// An ECDSA key pair, used for signing.
 #[allow(clippy::module_name_repetitions)]
@@ -36,7 +46,10 @@ pub struct EcdsaKeyPair {
 
 impl Debug for EcdsaKeyPair {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
-        f.write_str(&format!("EcdsaKeyPair {{ public_key: {:?} }}", self.pubkey))
+        f.write_str(&format!(
+            "EcdsaKeyPair {{ algorithm: {:?} }}",
+            self.algorithm.id
+        ))
     }
 }
 
@@ -54,6 +67,12 @@ impl KeyPair for EcdsaKeyPair {
     }
 }
 
+impl From<&EcdsaKeyPair> for PublicKey {
+    fn from(key_pair: &EcdsaKeyPair) -> Self {
+        key_pair.pubkey.clone()
+    }
+}
+
 impl EcdsaKeyPair {
     #[allow(clippy::needless_pass_by_value)]
     fn new(
@@ -80,12 +99,52 @@ impl EcdsaKeyPair {
         Ok(Self::new(alg, evp_pkey)?)
     }
 
-    /// Constructs an ECDSA key pair by parsing an unencrypted PKCS#8 v1
+    /// Generates a new key pair using `rng` as the only source of randomness.
+    ///
+    /// This is intended for tests that need to reproduce a key pair from a seeded, deterministic
+    /// `rng`; use [`Self::generate`] otherwise. The private key is sampled from the output of
+    /// `rng` by rejection sampling, so the same `rng` output always yields the same key pair.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `rng` fails, if `rng` does not produce a valid private key within
+    /// a bounded number of attempts, or on internal error.
+    pub fn generate_with_rng(
+        alg: &'static EcdsaSigningAlgorithm,
+        rng: &dyn SecureRandom,
+    ) -> Result<Self, Unspecified> {
+        const MAX_ATTEMPTS: usize = 100;
+
+        let scalar_len = alg.id.private_key_size();
+        let excess_bits = scalar_len * 8 - alg.bits as usize;
+        let mut scalar = Zeroizing::new([0u8; ec::SCALAR_MAX_BYTES]);
+        let scalar = &mut scalar[..scalar_len];
+
+        for _ in 0..MAX_ATTEMPTS {
+            rng.fill(scalar)?;
+            // Clear the bits above the order's bit length so that each attempt is accepted
+            // with probability of at least one half.
+            scalar[0] &= 0xff >> excess_bits;
+            if !alg.scalar_in_range(scalar) {
+                continue;
+            }
+
+            let ec_group = ec_group_from_nid(alg.id.nid())?;
+            let private_bn = LcPtr::<BIGNUM>::try_from(&*scalar)?;
+            let evp_pkey = ec::evp_pkey_from_private(&ec_group.as_const(), &private_bn.as_const())?;
+
+            return Ok(Self::new(alg, evp_pkey)?);
+        }
+
+        Err(Unspecified)
+    }
+
+    /// Constructs an ECDSA key pair by parsing an unencrypted PKCS#8 v1 or v2
     /// id-ecPublicKey `ECPrivateKey` key.
     ///
     /// # Errors
     /// `error::KeyRejected` if bytes do not encode an ECDSA key pair or if the key is otherwise not
-    /// acceptable.
+    /// acceptable. A key of another type, or on a curve other than that of `alg`, is rejected as
+    /// `WrongAlgorithm`.
     pub fn from_pkcs8(
         alg: &'static EcdsaSigningAlgorithm,
         pkcs8: &[u8],
@@ -103,6 +162,42 @@ impl EcdsaKeyPair {
         Ok(key_pair)
     }
 
+    /// Constructs an ECDSA key pair by parsing an unencrypted PKCS#8 v1 or v2
+    /// id-ecPublicKey `ECPrivateKey` key on any supported curve, and returns the
+    /// curve along with the key pair.
+    ///
+    /// The signing algorithm is chosen from the curve named by the document:
+    /// ASN.1 DER-encoded signatures with SHA-256 for P-256 and secp256k1,
+    /// SHA-384 for P-384 and SHA-512 for P-521. Use [`Self::from_pkcs8`] when a
+    /// different algorithm is needed.
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if bytes do not encode an ECDSA key pair, if the curve
+    /// of the key cannot be used for signing, or if the key is otherwise not
+    /// acceptable.
+    pub fn from_pkcs8_any(pkcs8: &[u8]) -> Result<(CurveId, Self), KeyRejected> {
+        // Includes a call to `EC_KEY_check_key`
+        let evp_pkey = LcPtr::<EVP_PKEY>::try_from(pkcs8)?;
+
+        let nid = evp_pkey_curve_nid(&evp_pkey.as_const())?;
+        let alg = [
+            &ECDSA_P256_SHA256_ASN1_SIGNING,
+            &ECDSA_P384_SHA384_ASN1_SIGNING,
+            &ECDSA_P521_SHA512_ASN1_SIGNING,
+            &ECDSA_P256K1_SHA256_ASN1_SIGNING,
+        ]
+        .into_iter()
+        .find(|alg| alg.id.nid() == nid)
+        .ok_or_else(KeyRejected::wrong_algorithm)?;
+
+        #[cfg(feature = "fips")]
+        validate_evp_key(&evp_pkey.as_const(), alg.id.nid())?;
+
+        let key_pair = Self::new(alg, evp_pkey)?;
+
+        Ok((alg.id.curve_id(), key_pair))
+    }
+
     /// Generates a new key pair and returns the key pair serialized as a
     /// PKCS#8 v1 document.
     ///
@@ -129,6 +224,33 @@ impl EcdsaKeyPair {
         self.evp_pkey.marshall_private_key(Version::V1)
     }
 
+    /// Generates a new key pair and returns the key pair serialized as a
+    /// PKCS#8 v2 document, which also carries the public key.
+    ///
+    /// # *ring* Compatibility
+    /// Our implementation ignores the `SecureRandom` parameter.
+    ///
+    /// # Errors
+    /// `error::Unspecified` on internal error.
+    pub fn generate_pkcs8v2(
+        alg: &'static EcdsaSigningAlgorithm,
+        _rng: &dyn SecureRandom,
+    ) -> Result<Document, Unspecified> {
+        let key_pair = Self::generate(alg)?;
+
+        key_pair.to_pkcs8v2()
+    }
+
+    /// Serializes this `EcdsaKeyPair` into a PKCS#8 v2 document. The uncompressed
+    /// public key is included in the document's `publicKey` field.
+    ///
+    /// # Errors
+    /// `error::Unspecified` on internal error.
+    pub fn to_pkcs8v2(&self) -> Result<Document, Unspecified> {
+        self.evp_pkey
+            .marshall_private_key_v2_with_public_key(self.pubkey.as_ref())
+    }
+
     /// Constructs an ECDSA key pair from the private key and public key bytes
     ///
     /// The private key must encoded as a big-endian fixed-length integer. For
@@ -187,14 +309,64 @@ impl EcdsaKeyPair {
         Ok(Self::new(alg, evp_pkey)?)
     }
 
+    /// Returns a new `EcdsaKeyPair` that shares the underlying key with this one.
+    ///
+    /// The key is reference counted rather than copied or re-parsed, so this is cheap. This is
+    /// useful for handing a loaded key to each worker in a pool. The key material is freed once
+    /// every clone has been dropped.
+    ///
+    /// # Errors
+    /// `error::Unspecified` on internal error.
+    pub fn try_clone(&self) -> Result<Self, Unspecified> {
+        Ok(Self {
+            algorithm: self.algorithm,
+            evp_pkey: self.evp_pkey.try_clone()?,
+            pubkey: self.pubkey.clone(),
+        })
+    }
+
+    /// Returns a pointer to the *AWS-LC* `EVP_PKEY` holding this key pair, for use with other
+    /// code that links against *AWS-LC*.
+    ///
+    /// The pointer is valid for as long as this `EcdsaKeyPair` is alive. The caller must not free
+    /// it, must not modify the key through it, and must not use it after the key pair is dropped.
+    /// Callers needing a longer-lived reference may take their own with `EVP_PKEY_up_ref`.
+    #[cfg(feature = "unstable-ffi")]
+    #[must_use]
+    pub fn as_evp_pkey_ptr(&self) -> *mut EVP_PKEY {
+        *self.evp_pkey
+    }
+
     /// Access functions related to the private key.
     #[must_use]
     pub fn private_key(&self) -> PrivateKey<'_> {
         PrivateKey(self)
     }
 
+    /// Returns the public key in the compressed form of the
+    /// Elliptic-Curve-Point-to-Octet-String algorithm in
+    /// [SEC 1: Elliptic Curve Cryptography, Version 2.0]: a `0x02` or `0x03` prefix followed by
+    /// the x-coordinate, e.g. 33 bytes for P-256 and 49 bytes for P-384.
+    ///
+    /// `public_key()` provides the uncompressed form. Verification accepts either form.
+    ///
+    /// [SEC 1: Elliptic Curve Cryptography, Version 2.0]:
+    ///     http://www.secg.org/sec1-v2.pdf
+    ///
+    /// # Errors
+    /// `error::Unspecified` if serialization failed.
+    pub fn public_key_compressed(&self) -> Result<Box<[u8]>, Unspecified> {
+        unsafe { ec::marshal_public_key_compressed(&self.evp_pkey.as_const()) }
+    }
+
     /// Returns the signature of the message using a random nonce.
     ///
+    /// `message` may be empty.
+    ///
+    /// The nonce is hedged: *AWS-LC* draws it from its DRBG with a hash of the private key and
+    /// the message digest as additional input, so a failure of the entropy source does not by
+    /// itself lead to nonce reuse. Callers cannot supply additional entropy of their own.
+    ///
     /// # *ring* Compatibility
     /// Our implementation ignores the `SecureRandom` parameter.
     ///
@@ -207,6 +379,127 @@ impl EcdsaKeyPair {
     // * Digest Algorithms: SHA256, SHA384, SHA512
     #[inline]
     pub fn sign(&self, _rng: &dyn SecureRandom, message: &[u8]) -> Result<Signature, Unspecified> {
+        let mut md_ctx = self.digest_sign_init()?;
+
+        let mut out_sig = vec![0u8; get_signature_length(&mut md_ctx)?];
+
+        let out_sig = compute_ecdsa_signature(&mut md_ctx, message, out_sig.as_mut_slice())?;
+
+        Ok(match self.algorithm.sig_format {
+            EcdsaSignatureFormat::ASN1 => Signature::new(|slice| {
+                slice[..out_sig.len()].copy_from_slice(out_sig);
+                out_sig.len()
+            }),
+            EcdsaSignatureFormat::Fixed => ec::ecdsa_asn1_to_fixed(self.algorithm.id, out_sig)?,
+        })
+    }
+
+    /// Signs the message using a random nonce, writing the signature into `signature`.
+    ///
+    /// Returns the length of the signature written to the front of `signature`.
+    ///
+    /// `signature` must be large enough to hold the largest possible signature for the
+    /// algorithm, even if the signature produced is shorter. For the `*_FIXED_SIGNING`
+    /// algorithms this is twice the size of the curve's scalar (e.g. 64 bytes for P-256). For the
+    /// `*_ASN1_SIGNING` algorithms it is the maximum DER encoding length (e.g. 72 bytes for
+    /// P-256, 104 bytes for P-384 and 141 bytes for P-521). `ECDSA_SIGNATURE_MAX_LEN` is
+    /// sufficient for every algorithm.
+    ///
+    /// # *ring* Compatibility
+    /// Our implementation ignores the `SecureRandom` parameter.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `signature` is too small, or on internal error.
+    //
+    // # FIPS
+    // The following conditions must be met:
+    // * NIST Elliptic Curves: P256, P384, P521
+    // * Digest Algorithms: SHA256, SHA384, SHA512
+    pub fn sign_into(
+        &self,
+        _rng: &dyn SecureRandom,
+        message: &[u8],
+        signature: &mut [u8],
+    ) -> Result<usize, Unspecified> {
+        let mut md_ctx = self.digest_sign_init()?;
+
+        let max_asn1_len = get_signature_length(&mut md_ctx)?;
+
+        match self.algorithm.sig_format {
+            EcdsaSignatureFormat::ASN1 => {
+                if signature.len() < max_asn1_len {
+                    return Err(Unspecified);
+                }
+                let out_sig =
+                    compute_ecdsa_signature(&mut md_ctx, message, &mut signature[..max_asn1_len])?;
+                Ok(out_sig.len())
+            }
+            EcdsaSignatureFormat::Fixed => {
+                let fixed_len = 2 * self.algorithm.id.private_key_size();
+                if signature.len() < fixed_len {
+                    return Err(Unspecified);
+                }
+                let mut asn1_sig = [0u8; MAX_LEN];
+                let asn1_sig = compute_ecdsa_signature(
+                    &mut md_ctx,
+                    message,
+                    asn1_sig.get_mut(..max_asn1_len).ok_or(Unspecified)?,
+                )?;
+                let fixed_sig = ec::ecdsa_asn1_to_fixed(self.algorithm.id, asn1_sig)?;
+                let fixed_sig = fixed_sig.as_ref();
+                signature[..fixed_sig.len()].copy_from_slice(fixed_sig);
+                Ok(fixed_sig.len())
+            }
+        }
+    }
+
+    /// Signs the message using a random nonce, then verifies the signature against the public
+    /// key before returning it.
+    ///
+    /// A fault induced during signing (e.g. by voltage glitching or a hardware error) can produce
+    /// a faulty signature from which the private key may be recovered. Verifying the signature
+    /// before releasing it ensures that only valid signatures leave this function, at the cost of
+    /// a verification per signature.
+    ///
+    /// # *ring* Compatibility
+    /// Our implementation ignores the `SecureRandom` parameter.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the signature does not verify, or on internal error.
+    pub fn sign_checked(
+        &self,
+        rng: &dyn SecureRandom,
+        message: &[u8],
+    ) -> Result<Signature, Unspecified> {
+        let signature = self.sign(rng, message)?;
+        self.algorithm
+            .verify_sig(self.pubkey.as_ref(), message, signature.as_ref())?;
+        Ok(signature)
+    }
+
+    /// Signs `message` under the domain separation `context`, using a random nonce.
+    ///
+    /// The signed message is `0x00 || len(context) || context || message`; see
+    /// `signature::verify_with_context` for the construction and its caveats. **This is not part
+    /// of any ECDSA standard.**
+    ///
+    /// # *ring* Compatibility
+    /// Our implementation ignores the `SecureRandom` parameter.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `context` is longer than `ECDSA_CONTEXT_MAX_LEN`, or on internal
+    /// error.
+    pub fn sign_with_context(
+        &self,
+        rng: &dyn SecureRandom,
+        context: &[u8],
+        message: &[u8],
+    ) -> Result<Signature, Unspecified> {
+        let prefix = ec::context_prefix(context)?;
+        self.sign(rng, &[&prefix, context, message].concat())
+    }
+
+    fn digest_sign_init(&self) -> Result<DigestContext, Unspecified> {
         let mut md_ctx = DigestContext::new_uninit();
 
         let digest = digest::match_digest_type(&self.algorithm.digest.id);
@@ -223,18 +516,62 @@ impl EcdsaKeyPair {
             return Err(Unspecified);
         }
 
-        let mut out_sig = vec![0u8; get_signature_length(&mut md_ctx)?];
+        Ok(md_ctx)
+    }
+}
 
-        let out_sig = compute_ecdsa_signature(&mut md_ctx, message, out_sig.as_mut_slice())?;
+/// Signs the pre-computed `digest` with `key_pair`, using `nonce` as the ECDSA nonce `k`.
+///
+/// **DANGER: For reproducing known-answer tests only.** Anyone who learns the nonce of a
+/// signature can compute the private key from it, and reusing a nonce for two different digests
+/// reveals the private key to anyone who sees both signatures. Never use this with a key that
+/// protects anything.
+///
+/// `digest` is the hash of the message under the key pair's signing algorithm; it is not hashed
+/// again. `nonce` is the big-endian encoding of `k`, which must be in the range `[1, n)` where
+/// `n` is the order of the curve. The signature is encoded according to the key pair's
+/// algorithm, as for `EcdsaKeyPair::sign`.
+///
+/// # Errors
+/// `error::Unspecified` if `nonce` is out of range, or on internal error.
+#[cfg(feature = "danger-test-only")]
+pub fn sign_with_fixed_nonce(
+    key_pair: &EcdsaKeyPair,
+    digest: &[u8],
+    nonce: &[u8],
+) -> Result<Signature, Unspecified> {
+    let ec_key = ConstPointer::new(unsafe { EVP_PKEY_get0_EC_KEY(*key_pair.evp_pkey) })?;
+    let ecdsa_sig = LcPtr::new(unsafe {
+        ECDSA_sign_with_nonce_and_leak_private_key_for_testing(
+            digest.as_ptr(),
+            digest.len(),
+            *ec_key,
+            nonce.as_ptr(),
+            nonce.len(),
+        )
+    })?;
 
-        Ok(match self.algorithm.sig_format {
-            EcdsaSignatureFormat::ASN1 => Signature::new(|slice| {
-                slice[..out_sig.len()].copy_from_slice(out_sig);
-                out_sig.len()
-            }),
-            EcdsaSignatureFormat::Fixed => ec::ecdsa_asn1_to_fixed(self.algorithm.id, out_sig)?,
-        })
+    let mut out_bytes = null_mut::<u8>();
+    let mut out_bytes_len = MaybeUninit::<usize>::uninit();
+    if 1 != unsafe {
+        ECDSA_SIG_to_bytes(
+            &mut out_bytes,
+            out_bytes_len.as_mut_ptr(),
+            *ecdsa_sig.as_const(),
+        )
+    } {
+        return Err(Unspecified);
     }
+    let out_bytes = LcPtr::new(out_bytes)?;
+    let out_sig = unsafe { out_bytes.as_slice(out_bytes_len.assume_init()) };
+
+    Ok(match key_pair.algorithm.sig_format {
+        EcdsaSignatureFormat::ASN1 => Signature::new(|slice| {
+            slice[..out_sig.len()].copy_from_slice(out_sig);
+            out_sig.len()
+        }),
+        EcdsaSignatureFormat::Fixed => ec::ecdsa_asn1_to_fixed(key_pair.algorithm.id, out_sig)?,
+    })
 }
 
 #[inline]
@@ -0,0 +1,289 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Multi-recipient public key encryption.
+//!
+//! Encrypts a payload once, so that any of several ECDH recipients can decrypt it, as is needed
+//! for e.g. group messaging. `seal` generates an ephemeral key pair and a random content
+//! encryption key (CEK), wraps the CEK for each recipient under a key derived from their ECDH
+//! shared secret, and AEAD-seals the payload under the CEK.
+//!
+//! # Construction
+//!
+//! For an ephemeral public key `E`:
+//!
+//! * For each recipient, the key encryption key is `HKDF-SHA256(salt = "", ikm = ECDH(e,
+//!   R_i), info = "aws-lc-rs ecies v1" || E)`. It seals the 32-byte CEK with AES-256-GCM and an
+//!   all-zero nonce.
+//! * The payload is sealed with AES-256-GCM under the CEK, with an all-zero nonce and with
+//!   `header || aad` as the additional authenticated data.
+//!
+//! Both keys are used for exactly one encryption, so a fixed nonce is safe. The output is the
+//! header followed by the sealed payload, where the header is, with lengths as big-endian
+//! integers:
+//!
+//! ```text
+//! version (1 byte, 0x01) || algorithm (1 byte)
+//!     || len(E) (2 bytes) || E
+//!     || recipient count (2 bytes) || wrapped CEK (48 bytes) for each recipient
+//! ```
+//!
+//! The algorithm byte is `1` for X25519, `2` for P-256, `3` for P-384 and `4` for P-521. The
+//! header does not identify the recipients; `open` tries each wrapped CEK in turn.
+//!
+//! This construction is specific to *aws-lc-rs*. For single-recipient encryption to a standard
+//! format, use [`crate::hpke`].
+//!
+//! # Example
+//! ```
+//! use aws_lc_rs::{agreement, ecies};
+//!
+//! let alice = agreement::PrivateKey::generate(&agreement::X25519)?;
+//! let bob = agreement::PrivateKey::generate(&agreement::X25519)?;
+//! let recipients = [
+//!     agreement::UnparsedPublicKey::new(&agreement::X25519, alice.compute_public_key()?),
+//!     agreement::UnparsedPublicKey::new(&agreement::X25519, bob.compute_public_key()?),
+//! ];
+//!
+//! let ciphertext = ecies::seal(&recipients, b"header", b"message")?;
+//!
+//! assert_eq!(b"message", ecies::open(&alice, b"header", &ciphertext)?.as_slice());
+//! assert_eq!(b"message", ecies::open(&bob, b"header", &ciphertext)?.as_slice());
+//! # Ok::<(), aws_lc_rs::error::Unspecified>(())
+//! ```
+
+use crate::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use crate::agreement::{self, PrivateKey, UnparsedPublicKey};
+use crate::error::Unspecified;
+use crate::{hkdf, rand};
+use zeroize::Zeroizing;
+
+const VERSION: u8 = 1;
+
+const KDF_INFO: &[u8] = b"aws-lc-rs ecies v1";
+
+const CEK_LEN: usize = 32;
+
+/// The length of a wrapped content encryption key: the CEK and an AES-256-GCM tag.
+const WRAPPED_CEK_LEN: usize = CEK_LEN + 16;
+
+/// Encrypts `plaintext` so that the holder of the private key for any of `recipients` can
+/// decrypt it with `open`.
+///
+/// `aad` is authenticated but not encrypted; recipients must provide the same value to `open`.
+/// All recipients must use the same key agreement algorithm.
+///
+/// # Errors
+/// `error::Unspecified` if `recipients` is empty or has more than 65535 entries, if the
+/// recipients use different algorithms, if a recipient's public key is invalid, or if the
+/// encryption failed.
+pub fn seal<B: AsRef<[u8]>>(
+    recipients: &[UnparsedPublicKey<B>],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Result<Vec<u8>, Unspecified> {
+    let alg = recipients.first().ok_or(Unspecified)?.algorithm();
+    let recipient_count = u16::try_from(recipients.len()).map_err(|_| Unspecified)?;
+
+    let ephemeral_key = PrivateKey::generate(alg)?;
+    let ephemeral_public_key = ephemeral_key.compute_public_key()?;
+    let ephemeral_public_key = ephemeral_public_key.as_ref();
+
+    let mut cek = Zeroizing::new([0u8; CEK_LEN]);
+    rand::fill(cek.as_mut())?;
+
+    let mut header =
+        Vec::with_capacity(6 + ephemeral_public_key.len() + recipients.len() * WRAPPED_CEK_LEN);
+    header.push(VERSION);
+    header.push(algorithm_id(alg));
+    header.extend_from_slice(
+        &u16::try_from(ephemeral_public_key.len())
+            .map_err(|_| Unspecified)?
+            .to_be_bytes(),
+    );
+    header.extend_from_slice(ephemeral_public_key);
+    header.extend_from_slice(&recipient_count.to_be_bytes());
+    for recipient in recipients {
+        let kek = agreement::agree(&ephemeral_key, recipient, Unspecified, |secret| {
+            key_encryption_key(secret, ephemeral_public_key)
+        })?;
+        header.extend_from_slice(&kek.seal(zero_nonce(), Aad::empty(), cek.as_ref())?);
+    }
+
+    let cek = LessSafeKey::new(UnboundKey::new(&AES_256_GCM, cek.as_ref())?);
+    let ciphertext = cek.seal(
+        zero_nonce(),
+        Aad::from([header.as_slice(), aad].concat()),
+        plaintext,
+    )?;
+    header.extend_from_slice(&ciphertext);
+    Ok(header)
+}
+
+/// Decrypts `ciphertext` produced by `seal`, using the private key of one of its recipients.
+///
+/// # Errors
+/// `error::Unspecified` if `ciphertext` is malformed, was not sealed to `private_key`, or was
+/// sealed with a different `aad`.
+pub fn open(
+    private_key: &PrivateKey,
+    aad: &[u8],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, Unspecified> {
+    let alg = private_key.algorithm();
+    let mut input = ciphertext;
+    if take(&mut input, 2)? != [VERSION, algorithm_id(alg)] {
+        return Err(Unspecified);
+    }
+    let ephemeral_public_key_len = take_u16(&mut input)?;
+    let ephemeral_public_key = take(&mut input, usize::from(ephemeral_public_key_len))?;
+    let recipient_count = take_u16(&mut input)?;
+    let wrapped_ceks = take(&mut input, usize::from(recipient_count) * WRAPPED_CEK_LEN)?;
+    let header = &ciphertext[..ciphertext.len() - input.len()];
+
+    let kek = agreement::agree(
+        private_key,
+        &UnparsedPublicKey::new(alg, ephemeral_public_key),
+        Unspecified,
+        |secret| key_encryption_key(secret, ephemeral_public_key),
+    )?;
+    let cek = wrapped_ceks
+        .chunks(WRAPPED_CEK_LEN)
+        .find_map(|wrapped_cek| {
+            kek.open(zero_nonce(), Aad::empty(), wrapped_cek)
+                .ok()
+                .map(Zeroizing::new)
+        })
+        .ok_or(Unspecified)?;
+
+    let cek = LessSafeKey::new(UnboundKey::new(&AES_256_GCM, &cek)?);
+    cek.open(zero_nonce(), Aad::from([header, aad].concat()), input)
+}
+
+fn key_encryption_key(
+    secret: &[u8],
+    ephemeral_public_key: &[u8],
+) -> Result<LessSafeKey, Unspecified> {
+    let info = [KDF_INFO, ephemeral_public_key];
+    let prk = hkdf::Salt::new(hkdf::HKDF_SHA256, &[]).extract(secret);
+    let okm = prk.expand(&info, &AES_256_GCM)?;
+    Ok(LessSafeKey::new(UnboundKey::from(okm)))
+}
+
+fn algorithm_id(alg: &agreement::Algorithm) -> u8 {
+    if *alg == agreement::X25519 {
+        1
+    } else if *alg == agreement::ECDH_P256 {
+        2
+    } else if *alg == agreement::ECDH_P384 {
+        3
+    } else {
+        4
+    }
+}
+
+fn zero_nonce() -> Nonce {
+    Nonce::assume_unique_for_key([0u8; NONCE_LEN])
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], Unspecified> {
+    if input.len() < len {
+        return Err(Unspecified);
+    }
+    let (taken, rest) = input.split_at(len);
+    *input = rest;
+    Ok(taken)
+}
+
+fn take_u16(input: &mut &[u8]) -> Result<u16, Unspecified> {
+    let bytes = take(input, 2)?;
+    Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::agreement::{self, PrivateKey, UnparsedPublicKey};
+    use crate::ecies::{open, seal, WRAPPED_CEK_LEN};
+
+    #[test]
+    fn test_seal_open_two_recipients() {
+        for alg in [
+            &agreement::X25519,
+            &agreement::ECDH_P256,
+            &agreement::ECDH_P384,
+            &agreement::ECDH_P521,
+        ] {
+            let alice = PrivateKey::generate(alg).unwrap();
+            let bob = PrivateKey::generate(alg).unwrap();
+            let eve = PrivateKey::generate(alg).unwrap();
+            let recipients = [
+                UnparsedPublicKey::new(alg, alice.compute_public_key().unwrap()),
+                UnparsedPublicKey::new(alg, bob.compute_public_key().unwrap()),
+            ];
+
+            let ciphertext = seal(&recipients, b"aad", b"message").unwrap();
+            assert_eq!(
+                b"message",
+                open(&alice, b"aad", &ciphertext).unwrap().as_slice()
+            );
+            assert_eq!(
+                b"message",
+                open(&bob, b"aad", &ciphertext).unwrap().as_slice()
+            );
+            assert!(open(&eve, b"aad", &ciphertext).is_err());
+            assert!(open(&alice, b"other aad", &ciphertext).is_err());
+
+            // Each message uses a new ephemeral key and content encryption key.
+            assert_ne!(ciphertext, seal(&recipients, b"aad", b"message").unwrap());
+
+            // Tampering with any part of the output, or truncating it, is detected.
+            for i in [0, 1, 2, 4, ciphertext.len() - 17, ciphertext.len() - 1] {
+                let mut tampered = ciphertext.clone();
+                tampered[i] ^= 1;
+                assert!(open(&alice, b"aad", &tampered).is_err());
+            }
+            assert!(open(&alice, b"aad", &ciphertext[..ciphertext.len() - 1]).is_err());
+            assert!(open(&alice, b"aad", &[]).is_err());
+
+            // Removing a recipient from the header is detected by the remaining recipients.
+            let ephemeral_public_key_len = u16::from_be_bytes([ciphertext[2], ciphertext[3]]);
+            let count_offset = 4 + usize::from(ephemeral_public_key_len);
+            let mut removed = ciphertext.clone();
+            removed[count_offset + 1] = 1;
+            removed.drain(count_offset + 2..count_offset + 2 + WRAPPED_CEK_LEN);
+            assert!(open(&bob, b"aad", &removed).is_err());
+
+            let ciphertext = seal(&recipients[..1], b"", b"").unwrap();
+            assert!(open(&alice, b"", &ciphertext).unwrap().is_empty());
+            assert!(open(&bob, b"", &ciphertext).is_err());
+        }
+    }
+
+    #[test]
+    fn test_seal_invalid_recipients() {
+        let x25519_key = PrivateKey::generate(&agreement::X25519).unwrap();
+        let p256_key = PrivateKey::generate(&agreement::ECDH_P256).unwrap();
+        let empty: [UnparsedPublicKey<&[u8]>; 0] = [];
+        assert!(seal(&empty, b"", b"message").is_err());
+
+        // Recipients must share an algorithm.
+        let mixed = [
+            UnparsedPublicKey::new(
+                &agreement::X25519,
+                x25519_key.compute_public_key().unwrap().as_ref().to_vec(),
+            ),
+            UnparsedPublicKey::new(
+                &agreement::ECDH_P256,
+                p256_key.compute_public_key().unwrap().as_ref().to_vec(),
+            ),
+        ];
+        assert!(seal(&mixed, b"", b"message").is_err());
+
+        let invalid = [UnparsedPublicKey::new(&agreement::ECDH_P256, [0u8; 65])];
+        assert!(seal(&invalid, b"", b"message").is_err());
+
+        // A message sealed to an X25519 key cannot be opened with a P-256 key.
+        let ciphertext = seal(&mixed[..1], b"", b"message").unwrap();
+        assert!(open(&p256_key, b"", &ciphertext).is_err());
+    }
+}
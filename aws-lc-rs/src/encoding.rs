@@ -4,6 +4,8 @@
 //! Serialization formats
 
 use crate::buffer::Buffer;
+use crate::error::Unspecified;
+use crate::{base64, hex};
 use paste::paste;
 
 macro_rules! generated_encodings {
@@ -80,3 +82,110 @@ pub trait AsBigEndian<T> {
     /// Returns Unspecified if serialization fails.
     fn as_be_bytes(&self) -> Result<T, crate::error::Unspecified>;
 }
+
+/// Encodes `bytes` as a lower-case hex string.
+#[must_use]
+pub fn hex_encode<T: AsRef<[u8]>>(bytes: T) -> String {
+    hex::encode(bytes)
+}
+
+/// Decodes a hex string, ignoring any ASCII whitespace within it.
+///
+/// Both upper-case and lower-case digits are accepted.
+///
+/// # Errors
+/// `error::Unspecified` if `hex_str` contains a character that is neither a hex digit nor
+/// whitespace, or if it contains an odd number of hex digits.
+pub fn hex_decode(hex_str: &str) -> Result<Vec<u8>, Unspecified> {
+    let clean: String = hex_str
+        .chars()
+        .filter(|ch| !ch.is_ascii_whitespace())
+        .collect();
+    if clean.len() % 2 != 0 {
+        return Err(Unspecified);
+    }
+    hex::decode(&clean).map_err(|_| Unspecified)
+}
+
+/// Encodes `bytes` as an unpadded base64url string, as used by JSON Web Keys ([RFC 7515]).
+///
+/// [RFC 7515]: https://www.rfc-editor.org/rfc/rfc7515#section-2
+#[must_use]
+pub fn base64url_encode<T: AsRef<[u8]>>(bytes: T) -> String {
+    base64::encode_url(bytes)
+}
+
+/// Decodes a base64url string, ignoring any ASCII whitespace within it.
+///
+/// Trailing `=` padding is accepted but not required.
+///
+/// # Errors
+/// `error::Unspecified` if `b64_str` contains a character outside of the base64url alphabet, has a
+/// length that no encoding could produce, or has non-zero unused trailing bits.
+pub fn base64url_decode(b64_str: &str) -> Result<Vec<u8>, Unspecified> {
+    base64::decode_url(b64_str).map_err(|_| Unspecified)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoding::{base64url_decode, base64url_encode, hex_decode, hex_encode};
+
+    #[test]
+    fn hex_whitespace() {
+        let bytes = hex_decode(" 00 01 0a\n\tFF ee\r\n").unwrap();
+        assert_eq!(vec![0x00, 0x01, 0x0a, 0xff, 0xee], bytes);
+        assert_eq!("00010affee", hex_encode(&bytes));
+
+        assert_eq!(Vec::<u8>::new(), hex_decode("").unwrap());
+        assert!(hex_decode("0").is_err());
+        assert!(hex_decode("0 1 2").is_err());
+        assert!(hex_decode("0g").is_err());
+        assert!(hex_decode("00:01").is_err());
+    }
+
+    #[test]
+    fn base64url_rfc4648_vectors() {
+        for (decoded, encoded) in [
+            ("", ""),
+            ("f", "Zg"),
+            ("fo", "Zm8"),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg"),
+            ("fooba", "Zm9vYmE"),
+            ("foobar", "Zm9vYmFy"),
+        ] {
+            assert_eq!(encoded, base64url_encode(decoded));
+            assert_eq!(decoded.as_bytes(), base64url_decode(encoded).unwrap());
+        }
+        assert_eq!(b"f", base64url_decode("Zg==").unwrap().as_slice());
+        assert_eq!(b"fo", base64url_decode("Zm8=").unwrap().as_slice());
+    }
+
+    #[test]
+    fn base64url_round_trip() {
+        let bytes: Vec<u8> = (0..=255u8).collect();
+        for len in 0..bytes.len() {
+            let encoded = base64url_encode(&bytes[..len]);
+            assert!(!encoded.contains(['+', '/', '=']));
+            assert_eq!(&bytes[..len], base64url_decode(&encoded).unwrap());
+        }
+
+        // The "x" coordinate of the P-256 JWK in RFC 7517 Appendix A.1, split across lines.
+        let x = base64url_decode("MKBCTNIcKUSDii11ySs3526iDZ8A\n  iTo7Tu6KPAqv7D4").unwrap();
+        assert_eq!(32, x.len());
+        assert_eq!(
+            "MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4",
+            base64url_encode(x)
+        );
+    }
+
+    #[test]
+    fn base64url_invalid() {
+        // Standard base64 alphabet characters are rejected.
+        assert!(base64url_decode("ab+/").is_err());
+        assert!(base64url_decode("Z").is_err());
+        assert!(base64url_decode("Zh").is_err());
+        assert!(base64url_decode("Zm9=v").is_err());
+        assert!(base64url_decode("Zm9v!").is_err());
+    }
+}
@@ -3,15 +3,18 @@
 
 use crate::cbb::LcCBB;
 use crate::cbs;
-use crate::ec::PKCS8_DOCUMENT_MAX_LEN;
+use crate::ec::{PKCS8_DOCUMENT_MAX_LEN, PUBLIC_KEY_MAX_LEN};
 use crate::error::{KeyRejected, Unspecified};
 use crate::pkcs8::{Document, Version};
 use crate::ptr::LcPtr;
 use aws_lc::{
-    EVP_PKEY_bits, EVP_PKEY_get1_EC_KEY, EVP_PKEY_get1_RSA, EVP_PKEY_id, EVP_PKEY_up_ref,
-    EVP_marshal_private_key, EVP_marshal_private_key_v2, EVP_parse_private_key, EC_KEY, EVP_PKEY,
-    RSA,
+    CBB_add_asn1, CBB_add_asn1_uint64, CBB_add_bytes, CBB_add_u8, CBS_data, CBS_get_asn1,
+    CBS_get_asn1_element, CBS_get_asn1_uint64, CBS_len, EVP_PKEY_bits, EVP_PKEY_get1_EC_KEY,
+    EVP_PKEY_get1_RSA, EVP_PKEY_id, EVP_PKEY_up_ref, EVP_marshal_private_key,
+    EVP_marshal_private_key_v2, EVP_parse_private_key, CBB, CBS_ASN1_CONTEXT_SPECIFIC,
+    CBS_ASN1_OCTETSTRING, CBS_ASN1_SEQUENCE, CBS_ASN1_TAG, EC_KEY, EVP_PKEY, RSA,
 };
+use core::mem::MaybeUninit;
 // TODO: Uncomment when MSRV >= 1.64
 // use core::ffi::c_int;
 use std::os::raw::c_int;
@@ -112,15 +115,129 @@ impl LcPtr<EVP_PKEY> {
 
         Ok(Document::new(buffer.into_boxed_slice()))
     }
+
+    // AWS-LC only marshals PKCS#8 v2 documents for key types with a dedicated v2 encoder, such as
+    // Ed25519. For other key types the RFC 5958 `OneAsymmetricKey` is assembled from the v1
+    // `PrivateKeyInfo`, with `public_key` placed in the optional `publicKey` field.
+    pub(crate) fn marshall_private_key_v2_with_public_key(
+        &self,
+        public_key: &[u8],
+    ) -> Result<Document, Unspecified> {
+        const PKCS8_V2_DOCUMENT_MAX_LEN: usize =
+            PKCS8_DOCUMENT_MAX_LEN + 4 /*tag, length and unused bits*/ + PUBLIC_KEY_MAX_LEN;
+
+        if public_key.len() > PUBLIC_KEY_MAX_LEN {
+            return Err(Unspecified);
+        }
+
+        let v1 = self.marshall_private_key(Version::V1)?;
+
+        let mut input = unsafe { cbs::build_CBS(v1.as_ref()) };
+        let mut private_key_info = unsafe { cbs::build_CBS(&[]) };
+        let mut version = 0u64;
+        let mut algorithm = unsafe { cbs::build_CBS(&[]) };
+        let mut private_key = unsafe { cbs::build_CBS(&[]) };
+        if 1 != unsafe {
+            CBS_get_asn1(
+                &mut input,
+                &mut private_key_info,
+                CBS_ASN1_SEQUENCE as CBS_ASN1_TAG,
+            )
+        } || 1 != unsafe { CBS_get_asn1_uint64(&mut private_key_info, &mut version) }
+            || 0 != version
+            || 1 != unsafe {
+                CBS_get_asn1_element(
+                    &mut private_key_info,
+                    &mut algorithm,
+                    CBS_ASN1_SEQUENCE as CBS_ASN1_TAG,
+                )
+            }
+            || 1 != unsafe {
+                CBS_get_asn1_element(
+                    &mut private_key_info,
+                    &mut private_key,
+                    CBS_ASN1_OCTETSTRING as CBS_ASN1_TAG,
+                )
+            }
+        {
+            return Err(Unspecified);
+        }
+
+        let mut buffer = vec![0u8; PKCS8_V2_DOCUMENT_MAX_LEN];
+
+        let out_len = {
+            let mut cbb = LcCBB::new_fixed(<&mut [u8; PKCS8_V2_DOCUMENT_MAX_LEN]>::try_from(
+                buffer.as_mut_slice(),
+            )?);
+            let mut one_asymmetric_key = MaybeUninit::<CBB>::uninit();
+            let mut public_key_bits = MaybeUninit::<CBB>::uninit();
+
+            if 1 != unsafe {
+                CBB_add_asn1(
+                    cbb.as_mut_ptr(),
+                    one_asymmetric_key.as_mut_ptr(),
+                    CBS_ASN1_SEQUENCE as CBS_ASN1_TAG,
+                )
+            } {
+                return Err(Unspecified);
+            }
+            let one_asymmetric_key = one_asymmetric_key.as_mut_ptr();
+            if 1 != unsafe { CBB_add_asn1_uint64(one_asymmetric_key, 1) }
+                || 1 != unsafe {
+                    CBB_add_bytes(
+                        one_asymmetric_key,
+                        CBS_data(&algorithm),
+                        CBS_len(&algorithm),
+                    )
+                }
+                || 1 != unsafe {
+                    CBB_add_bytes(
+                        one_asymmetric_key,
+                        CBS_data(&private_key),
+                        CBS_len(&private_key),
+                    )
+                }
+                // publicKey [1] IMPLICIT BIT STRING
+                || 1 != unsafe {
+                    CBB_add_asn1(
+                        one_asymmetric_key,
+                        public_key_bits.as_mut_ptr(),
+                        CBS_ASN1_CONTEXT_SPECIFIC as CBS_ASN1_TAG | 1,
+                    )
+                }
+                || 1 != unsafe { CBB_add_u8(public_key_bits.as_mut_ptr(), 0) }
+                || 1 != unsafe {
+                    CBB_add_bytes(
+                        public_key_bits.as_mut_ptr(),
+                        public_key.as_ptr(),
+                        public_key.len(),
+                    )
+                }
+            {
+                return Err(Unspecified);
+            }
+            cbb.finish()?
+        };
+
+        buffer.truncate(out_len);
+
+        Ok(Document::new(buffer.into_boxed_slice()))
+    }
+}
+
+impl LcPtr<EVP_PKEY> {
+    // Returns a new pointer to the same `EVP_PKEY`, whose reference count is incremented. The key
+    // is freed once every pointer to it has been dropped.
+    pub(crate) fn try_clone(&self) -> Result<Self, Unspecified> {
+        if 1 != unsafe { EVP_PKEY_up_ref(**self) } {
+            return Err(Unspecified);
+        }
+        Self::new(**self).map_err(|()| Unspecified)
+    }
 }
 
 impl Clone for LcPtr<EVP_PKEY> {
     fn clone(&self) -> Self {
-        assert_eq!(
-            1,
-            unsafe { EVP_PKEY_up_ref(**self) },
-            "infallible AWS-LC function"
-        );
-        Self::new(**self).expect("non-null AWS-LC EVP_PKEY pointer")
+        self.try_clone().expect("infallible AWS-LC function")
     }
 }
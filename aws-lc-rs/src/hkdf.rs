@@ -7,6 +7,8 @@
 //!
 //! HKDF is specified in [RFC 5869].
 //!
+//! HKDF over the SHA-3 digests is not provided, as *AWS-LC* does not support HMAC over them.
+//!
 //! [RFC 5869]: https://tools.ietf.org/html/rfc5869
 //!
 //! # Example
@@ -43,7 +45,7 @@ use crate::{digest, hmac};
 use alloc::sync::Arc;
 use aws_lc::{HKDF_expand, HKDF};
 use core::fmt;
-use zeroize::Zeroize;
+use zeroize::{Zeroize, Zeroizing};
 
 /// An HKDF algorithm.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -372,8 +374,43 @@ impl Prk {
             info_bytes,
             info_len,
             len,
+            len_cached,
         })
     }
+
+    /// The TLS 1.3 [HKDF-Expand-Label] operation, which fills `out` with
+    /// `HKDF-Expand(PRK, HkdfLabel, out.len())`, where `HkdfLabel` encodes the
+    /// length of `out`, `"tls13 "` followed by `label`, and `context`.
+    ///
+    /// [HKDF-Expand-Label]: https://www.rfc-editor.org/rfc/rfc8446#section-7.1
+    ///
+    /// # Errors
+    /// Returns `error::Unspecified` if:
+    ///   * `out.len()` is more than 255 times the digest algorithm's output length.
+    ///   * `label` is longer than 249 bytes, or `context` is longer than 255 bytes.
+    pub fn expand_label(
+        &self,
+        label: &[u8],
+        context: &[u8],
+        out: &mut [u8],
+    ) -> Result<(), Unspecified> {
+        const LABEL_PREFIX: &[u8] = b"tls13 ";
+
+        let out_len = u16::try_from(out.len())?.to_be_bytes();
+        let label_len = [u8::try_from(LABEL_PREFIX.len() + label.len())?];
+        let context_len = [u8::try_from(context.len())?];
+        let info = [
+            &out_len[..],
+            &label_len,
+            LABEL_PREFIX,
+            label,
+            &context_len,
+            context,
+        ];
+        self.expand(&info, OutputLen(out.len()))?.fill(out)?;
+
+        Ok(())
+    }
 }
 
 impl From<Okm<'_, Algorithm>> for Prk {
@@ -399,6 +436,7 @@ pub struct Okm<'a, L: KeyType> {
     info_bytes: Box<[u8]>,
     info_len: usize,
     len: L,
+    len_cached: usize,
 }
 
 impl<'a, L: KeyType> fmt::Debug for Okm<'a, L> {
@@ -439,7 +477,7 @@ impl<L: KeyType> Okm<'_, L> {
     /// `L: KeyType`.
     #[inline]
     pub fn fill(self, out: &mut [u8]) -> Result<(), Unspecified> {
-        if out.len() != self.len.len() {
+        if out.len() != self.len_cached {
             return Err(Unspecified);
         }
 
@@ -449,6 +487,72 @@ impl<L: KeyType> Okm<'_, L> {
 
         Ok(())
     }
+
+    /// Returns the output of the HKDF-Expand operation for the given inputs in a buffer that is
+    /// zeroized when dropped. The length of the output is `self.len().len()`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the HKDF-Expand operation fails.
+    pub fn fill_zeroizing(self) -> Result<Zeroizing<Vec<u8>>, Unspecified> {
+        let mut out = Zeroizing::new(vec![0u8; self.len_cached]);
+        self.fill(out.as_mut_slice())?;
+        Ok(out)
+    }
+}
+
+/// Derives any number of independent keys from a single [`Prk`].
+///
+/// Protocols such as Noise or MLS derive many keys from one secret. The HKDF-Extract step is
+/// performed once, when the `Prk` is created, and each `derive_key` call is a separate
+/// HKDF-Expand using its label as the info.
+///
+/// # Example
+/// ```
+/// use aws_lc_rs::hkdf;
+///
+/// let salt = hkdf::Salt::new(hkdf::HKDF_SHA256, b"salt");
+/// let derivation = hkdf::KeyDerivation::new(salt.extract(b"secret input keying material"));
+///
+/// let encryption_key = derivation.derive_key(b"encryption", 32).unwrap();
+/// let authentication_key = derivation.derive_key(b"authentication", 32).unwrap();
+/// assert_ne!(encryption_key, authentication_key);
+/// ```
+#[derive(Clone, Debug)]
+pub struct KeyDerivation {
+    prk: Prk,
+}
+
+impl KeyDerivation {
+    /// Constructs a `KeyDerivation` that derives keys from `prk`.
+    #[must_use]
+    pub fn new(prk: Prk) -> Self {
+        Self { prk }
+    }
+
+    /// The PRK that keys are derived from.
+    #[must_use]
+    pub fn prk(&self) -> &Prk {
+        &self.prk
+    }
+
+    /// Derives a key of `len` bytes by HKDF-Expand with `label` as the info, in a buffer that is
+    /// zeroized when dropped.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `len` is more than 255 times the digest algorithm's output length,
+    /// or if the HKDF-Expand operation fails.
+    pub fn derive_key(&self, label: &[u8], len: usize) -> Result<Zeroizing<Vec<u8>>, Unspecified> {
+        self.prk.expand(&[label], OutputLen(len))?.fill_zeroizing()
+    }
+}
+
+/// A `KeyType` for an output of an arbitrary length.
+pub(crate) struct OutputLen(pub(crate) usize);
+
+impl KeyType for OutputLen {
+    fn len(&self) -> usize {
+        self.0
+    }
 }
 
 #[cfg(test)]
@@ -5,6 +5,8 @@
 
 //! HMAC is specified in [RFC 2104].
 //!
+//! HMAC over the SHA-3 digests is not provided, as *AWS-LC* does not support it.
+//!
 //! After a `Key` is constructed, it can be used for multiple signing or
 //! verification operations. Separating the construction of the key from the
 //! rest of the HMAC operation allows the per-key precomputation to be done
@@ -107,6 +109,7 @@ use core::ptr::null_mut;
 // TODO: Uncomment when MSRV >= 1.64
 // use core::ffi::c_uint;
 use std::os::raw::c_uint;
+use zeroize::Zeroizing;
 
 /// A deprecated alias for `Tag`.
 #[deprecated]
@@ -260,7 +263,7 @@ impl Key {
     where
         F: FnOnce(&mut [u8]) -> Result<(), Unspecified>,
     {
-        let mut key_bytes = [0; digest::MAX_OUTPUT_LEN];
+        let mut key_bytes = Zeroizing::new([0; digest::MAX_OUTPUT_LEN]);
         let key_bytes = &mut key_bytes[..algorithm.0.output_len];
         fill(key_bytes)?;
         Ok(Self::new(algorithm, key_bytes))
@@ -484,6 +487,46 @@ pub fn verify(key: &Key, data: &[u8], tag: &[u8]) -> Result<(), Unspecified> {
     constant_time::verify_slices_are_equal(sign(key, data).as_ref(), tag)
 }
 
+/// Calculates the HMAC of the concatenation of `segments` using the key `key` in one step,
+/// without copying the segments into a single buffer.
+//
+// # FIPS
+// Use this function with one of the following algorithms:
+// * `HMAC_SHA1_FOR_LEGACY_USE_ONLY`
+// * `HMAC_SHA224`
+// * `HMAC_SHA256`
+// * `HMAC_SHA384`
+// * `HMAC_SHA512`
+#[inline]
+#[must_use]
+pub fn sign_segments(key: &Key, segments: &[&[u8]]) -> Tag {
+    let mut ctx = Context::with_key(key);
+    for segment in segments {
+        ctx.update(segment);
+    }
+    ctx.sign()
+}
+
+/// Calculates the HMAC of the concatenation of `segments` using the key `key`, and verifies
+/// whether the resultant value equals `tag`, in one step.
+///
+/// The verification will be done in constant time to prevent timing attacks.
+///
+/// # Errors
+/// `error::Unspecified` if the inputs are not verified.
+//
+// # FIPS
+// Use this function with one of the following algorithms:
+// * `HMAC_SHA1_FOR_LEGACY_USE_ONLY`
+// * `HMAC_SHA224`
+// * `HMAC_SHA256`
+// * `HMAC_SHA384`
+// * `HMAC_SHA512`
+#[inline]
+pub fn verify_segments(key: &Key, segments: &[&[u8]], tag: &[u8]) -> Result<(), Unspecified> {
+    constant_time::verify_slices_are_equal(sign_segments(key, segments).as_ref(), tag)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{hmac, rand};
@@ -0,0 +1,258 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! HMAC-DRBG deterministic random bit generator.
+//!
+//! An implementation of `HMAC_DRBG` as specified in [NIST SP 800-90A Rev. 1], section 10.1.2,
+//! built on the [`crate::hmac`] module. The output of an `HmacDrbg` is fully determined by the
+//! seed material it is instantiated and reseeded with, which makes it suitable for reproducible
+//! tests and for deterministic nonce generation such as that of [RFC 6979].
+//!
+//! The caller is responsible for providing seed material with sufficient entropy. For general
+//! purpose randomness, use [`crate::rand::SystemRandom`] instead.
+//!
+//! # Example
+//! ```
+//! use aws_lc_rs::{hmac, hmac_drbg::HmacDrbg};
+//!
+//! let seed = b"entropy input || nonce || personalization string";
+//! let mut drbg = HmacDrbg::new(hmac::HMAC_SHA256, seed);
+//! let mut first = [0u8; 32];
+//! drbg.generate(&mut first).unwrap();
+//!
+//! // The same seed always produces the same output.
+//! let mut drbg = HmacDrbg::new(hmac::HMAC_SHA256, seed);
+//! let mut second = [0u8; 32];
+//! drbg.generate(&mut second).unwrap();
+//! assert_eq!(first, second);
+//! ```
+//!
+//! [NIST SP 800-90A Rev. 1]: https://csrc.nist.gov/publications/detail/sp/800-90a/rev-1/final
+//! [RFC 6979]: https://www.rfc-editor.org/rfc/rfc6979#section-3.3
+
+use crate::digest::MAX_OUTPUT_LEN;
+use crate::error::Unspecified;
+use crate::hmac;
+use core::fmt::{self, Debug, Formatter};
+use zeroize::Zeroize;
+
+/// The maximum number of bytes returned by a single call to `generate` (2^19 bits).
+pub const MAX_BYTES_PER_REQUEST: usize = 1 << 16;
+
+/// The number of `generate` calls allowed before a reseed is required (2^48).
+pub const RESEED_INTERVAL: u64 = 1 << 48;
+
+/// An HMAC-DRBG instance.
+pub struct HmacDrbg {
+    algorithm: hmac::Algorithm,
+    key: hmac::Key,
+    value: [u8; MAX_OUTPUT_LEN],
+    reseed_counter: u64,
+}
+
+impl HmacDrbg {
+    /// Instantiates an HMAC-DRBG using `algorithm`, seeded with `seed`.
+    ///
+    /// `seed` is the seed material of the instantiate function, i.e. the concatenation of the
+    /// entropy input, the nonce and the (optional) personalization string.
+    #[must_use]
+    pub fn new(algorithm: hmac::Algorithm, seed: &[u8]) -> Self {
+        let output_len = algorithm.digest_algorithm().output_len();
+        let mut value = [0u8; MAX_OUTPUT_LEN];
+        value[..output_len].fill(0x01);
+        let mut drbg = Self {
+            algorithm,
+            key: hmac::Key::new(algorithm, &[0u8; MAX_OUTPUT_LEN][..output_len]),
+            value,
+            reseed_counter: 1,
+        };
+        drbg.update(seed);
+        drbg
+    }
+
+    /// Reseeds the HMAC-DRBG with `seed`.
+    ///
+    /// `seed` is the concatenation of the new entropy input and the (optional) additional input.
+    pub fn reseed(&mut self, seed: &[u8]) {
+        self.update(seed);
+        self.reseed_counter = 1;
+    }
+
+    /// Fills `out` with pseudorandom bytes.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `out` is longer than `MAX_BYTES_PER_REQUEST`, or if
+    /// `RESEED_INTERVAL` requests have been made since the last (re)seed.
+    pub fn generate(&mut self, out: &mut [u8]) -> Result<(), Unspecified> {
+        self.generate_with_additional_input(&[], out)
+    }
+
+    /// Fills `out` with pseudorandom bytes, after mixing `additional_input` into the state.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if `out` is longer than `MAX_BYTES_PER_REQUEST`, or if
+    /// `RESEED_INTERVAL` requests have been made since the last (re)seed.
+    pub fn generate_with_additional_input(
+        &mut self,
+        additional_input: &[u8],
+        out: &mut [u8],
+    ) -> Result<(), Unspecified> {
+        if out.len() > MAX_BYTES_PER_REQUEST || self.reseed_counter > RESEED_INTERVAL {
+            return Err(Unspecified);
+        }
+
+        if !additional_input.is_empty() {
+            self.update(additional_input);
+        }
+
+        let output_len = self.output_len();
+        for chunk in out.chunks_mut(output_len) {
+            self.hmac_value();
+            chunk.copy_from_slice(&self.value[..chunk.len()]);
+        }
+
+        self.update(additional_input);
+        self.reseed_counter += 1;
+        Ok(())
+    }
+
+    /// The HMAC algorithm used by this HMAC-DRBG.
+    #[must_use]
+    pub fn algorithm(&self) -> hmac::Algorithm {
+        self.algorithm
+    }
+
+    fn output_len(&self) -> usize {
+        self.algorithm.digest_algorithm().output_len()
+    }
+
+    /// `HMAC_DRBG_Update` (SP 800-90A, section 10.1.2.2).
+    fn update(&mut self, data: &[u8]) {
+        self.update_key(0x00, data);
+        self.hmac_value();
+        if !data.is_empty() {
+            self.update_key(0x01, data);
+            self.hmac_value();
+        }
+    }
+
+    /// K = HMAC(K, V || `separator` || `data`)
+    fn update_key(&mut self, separator: u8, data: &[u8]) {
+        let output_len = self.output_len();
+        let mut context = hmac::Context::with_key(&self.key);
+        context.update(&self.value[..output_len]);
+        context.update(&[separator]);
+        context.update(data);
+        self.key = hmac::Key::new(self.algorithm, context.sign().as_ref());
+    }
+
+    /// V = HMAC(K, V)
+    fn hmac_value(&mut self) {
+        let output_len = self.output_len();
+        let tag = hmac::sign(&self.key, &self.value[..output_len]);
+        self.value[..output_len].copy_from_slice(tag.as_ref());
+    }
+}
+
+impl Drop for HmacDrbg {
+    fn drop(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl Debug for HmacDrbg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HmacDrbg")
+            .field("algorithm", &self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hmac;
+    use crate::hmac_drbg::{HmacDrbg, MAX_BYTES_PER_REQUEST};
+    use crate::test::from_hex;
+
+    // NIST CAVP HMAC_DRBG.rsp: [SHA-256], [PredictionResistance = True], COUNT = 0.
+    #[test]
+    fn test_nist_sha256_prediction_resistance() {
+        let entropy_input =
+            from_hex("ca851911349384bffe89de1cbdc46e6831e44d34a4fb935ee285dd14b71a7488").unwrap();
+        let nonce = from_hex("659ba96c601dc69fc902940805ec0ca8").unwrap();
+        let personalization_string =
+            from_hex("e72dd8590d4ed5295515c35ed6199e9d211b8f069b3058caa6670b96ef1208d0").unwrap();
+        let entropy_input_pr = [
+            from_hex("5cacc68165a2e2ee20812f35ec73a79dbf30fd475476ac0c44fc6174cdac2b55").unwrap(),
+            from_hex("8df013b4d103523073917ddf6a869793059e9943fc8654549e7ab22f7c29f122").unwrap(),
+        ];
+        let additional_input = [
+            from_hex("793a7ef8f6f0482beac542bb785c10f8b7b406a4de92667ab168ecc2cf7573c6").unwrap(),
+            from_hex("2238cdb4e23d629fe0c2a83dd8d5144ce1a6229ef41dabe2a99ff722e510b530").unwrap(),
+        ];
+        let returned_bits = from_hex(
+            "b1d17c002a7febd28412d8e58a7f32318e4ee3605a99b05b05d59356d5f0c6b4\
+             960a4b8f963b7efa55bb6872fbeac7b99b78dea8f3531973637c946a9cab3349\
+             744b24a0851dd47f2b3b460c2c61846e91181d62d42c60a4efda5ed57902bfd7\
+             02b349c54952c7f644769d8ef4015ecc5f5bbd4af06134688e30050e0497fb0a",
+        )
+        .unwrap();
+
+        let seed = [entropy_input.as_slice(), &nonce, &personalization_string].concat();
+        let mut drbg = HmacDrbg::new(hmac::HMAC_SHA256, &seed);
+
+        // With prediction resistance, each generate request reseeds with fresh entropy and the
+        // additional input first.
+        let mut out = vec![0u8; returned_bits.len()];
+        for (entropy, additional) in entropy_input_pr.iter().zip(additional_input.iter()) {
+            drbg.reseed(&[entropy.as_slice(), additional].concat());
+            drbg.generate(&mut out).unwrap();
+        }
+        assert_eq!(returned_bits, out);
+    }
+
+    #[test]
+    fn test_reproducible() {
+        for alg in [hmac::HMAC_SHA256, hmac::HMAC_SHA384, hmac::HMAC_SHA512] {
+            let mut a = HmacDrbg::new(alg, b"fixed seed");
+            let mut b = HmacDrbg::new(alg, b"fixed seed");
+            let mut c = HmacDrbg::new(alg, b"other seed");
+            let (mut out_a, mut out_b, mut out_c) = ([0u8; 100], [0u8; 100], [0u8; 100]);
+
+            a.generate(&mut out_a).unwrap();
+            b.generate(&mut out_b).unwrap();
+            c.generate(&mut out_c).unwrap();
+            assert_eq!(out_a, out_b);
+            assert_ne!(out_a, out_c);
+
+            // Successive outputs differ, and additional input changes the output.
+            a.generate(&mut out_a).unwrap();
+            assert_ne!(out_a, out_b);
+            b.generate_with_additional_input(b"additional", &mut out_b)
+                .unwrap();
+            assert_ne!(out_a, out_b);
+
+            // Reseeding with the same material keeps instances in sync.
+            let mut a = HmacDrbg::new(alg, b"fixed seed");
+            let mut b = HmacDrbg::new(alg, b"fixed seed");
+            a.reseed(b"reseed");
+            b.reseed(b"reseed");
+            c.reseed(b"reseed");
+            a.generate(&mut out_a).unwrap();
+            b.generate(&mut out_b).unwrap();
+            c.generate(&mut out_c).unwrap();
+            assert_eq!(out_a, out_b);
+            assert_ne!(out_a, out_c);
+            assert_eq!(alg, a.algorithm());
+        }
+    }
+
+    #[test]
+    fn test_max_request_len() {
+        let mut drbg = HmacDrbg::new(hmac::HMAC_SHA256, b"fixed seed");
+        let mut out = vec![0u8; MAX_BYTES_PER_REQUEST + 1];
+        assert!(drbg.generate(&mut out).is_err());
+        drbg.generate(&mut out[..MAX_BYTES_PER_REQUEST]).unwrap();
+        assert!(drbg.generate(&mut []).is_ok());
+    }
+}
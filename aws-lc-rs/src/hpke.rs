@@ -0,0 +1,390 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Hybrid Public Key Encryption (HPKE).
+//!
+//! Single-shot encryption to a recipient's public key in the base mode of [RFC 9180]. Each call
+//! to `seal` generates a new ephemeral key, and returns the encapsulated key (`enc`) that must be
+//! sent to the recipient along with the ciphertext.
+//!
+//! # Example
+//! ```
+//! use aws_lc_rs::hpke;
+//!
+//! let suite = &hpke::DHKEM_X25519_HKDF_SHA256_AES_128_GCM;
+//!
+//! // The recipient generates a key pair and publishes the public key.
+//! let recipient_key = hpke::PrivateKey::generate(suite)?;
+//! let public_key = recipient_key.public_key();
+//!
+//! // The sender encrypts a message to the recipient's public key.
+//! let info = b"example application";
+//! let (enc, ciphertext) = hpke::seal(suite, public_key, info, b"header", b"message")?;
+//!
+//! // The recipient decrypts it using the encapsulated key.
+//! let plaintext = hpke::open(&recipient_key, &enc, info, b"header", &ciphertext)?;
+//! assert_eq!(b"message", plaintext.as_slice());
+//! # Ok::<(), aws_lc_rs::error::Unspecified>(())
+//! ```
+//!
+//! [RFC 9180]: https://www.rfc-editor.org/rfc/rfc9180
+
+use crate::debug::derive_debug_via_id;
+use crate::error::{KeyRejected, Unspecified};
+use crate::ptr::LcPtr;
+use aws_lc::{
+    EVP_HPKE_CTX_max_overhead, EVP_HPKE_CTX_new, EVP_HPKE_CTX_open, EVP_HPKE_CTX_seal,
+    EVP_HPKE_CTX_setup_recipient, EVP_HPKE_CTX_setup_sender,
+    EVP_HPKE_CTX_setup_sender_with_seed_for_testing, EVP_HPKE_KEM_enc_len,
+    EVP_HPKE_KEM_public_key_len, EVP_HPKE_KEY_generate, EVP_HPKE_KEY_init, EVP_HPKE_KEY_new,
+    EVP_HPKE_KEY_public_key, EVP_hpke_aes_128_gcm, EVP_hpke_hkdf_sha256,
+    EVP_hpke_x25519_hkdf_sha256, EVP_HPKE_AEAD, EVP_HPKE_KDF, EVP_HPKE_KEM, EVP_HPKE_KEY,
+};
+use core::fmt::{self, Debug, Formatter};
+
+/// An HPKE ciphersuite: a KEM, a KDF and an AEAD.
+pub struct Suite {
+    id: SuiteId,
+    kem: fn() -> *const EVP_HPKE_KEM,
+    kdf: fn() -> *const EVP_HPKE_KDF,
+    aead: fn() -> *const EVP_HPKE_AEAD,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SuiteId {
+    DHKEM_X25519_HKDF_SHA256_AES_128_GCM,
+}
+
+derive_debug_via_id!(Suite);
+
+impl PartialEq for Suite {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Suite {}
+
+/// DHKEM(X25519, HKDF-SHA256) with HKDF-SHA256 and AES-128-GCM.
+pub static DHKEM_X25519_HKDF_SHA256_AES_128_GCM: Suite = Suite {
+    id: SuiteId::DHKEM_X25519_HKDF_SHA256_AES_128_GCM,
+    kem: || unsafe { EVP_hpke_x25519_hkdf_sha256() },
+    kdf: || unsafe { EVP_hpke_hkdf_sha256() },
+    aead: || unsafe { EVP_hpke_aes_128_gcm() },
+};
+
+/// A recipient's private key.
+pub struct PrivateKey {
+    suite: &'static Suite,
+    key: LcPtr<EVP_HPKE_KEY>,
+    public_key: Box<[u8]>,
+}
+
+impl PrivateKey {
+    /// Generates a new private key for `suite`.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the key could not be generated.
+    pub fn generate(suite: &'static Suite) -> Result<Self, Unspecified> {
+        let key = LcPtr::new(unsafe { EVP_HPKE_KEY_new() })?;
+        if 1 != unsafe { EVP_HPKE_KEY_generate(*key, (suite.kem)()) } {
+            return Err(Unspecified);
+        }
+        Self::new(suite, key)
+    }
+
+    /// Constructs a private key for `suite` from its serialized form, e.g. the 32-byte X25519
+    /// private key.
+    ///
+    /// # Errors
+    /// `error::KeyRejected` if `bytes` is not a valid private key for the KEM of `suite`.
+    pub fn from_bytes(suite: &'static Suite, bytes: &[u8]) -> Result<Self, KeyRejected> {
+        let key = LcPtr::new(unsafe { EVP_HPKE_KEY_new() })?;
+        if 1 != unsafe { EVP_HPKE_KEY_init(*key, (suite.kem)(), bytes.as_ptr(), bytes.len()) } {
+            return Err(KeyRejected::invalid_encoding());
+        }
+        Ok(Self::new(suite, key)?)
+    }
+
+    fn new(suite: &'static Suite, key: LcPtr<EVP_HPKE_KEY>) -> Result<Self, Unspecified> {
+        let mut public_key = vec![0u8; unsafe { EVP_HPKE_KEM_public_key_len((suite.kem)()) }];
+        let mut public_key_len = 0;
+        if 1 != unsafe {
+            EVP_HPKE_KEY_public_key(
+                *key,
+                public_key.as_mut_ptr(),
+                &mut public_key_len,
+                public_key.len(),
+            )
+        } {
+            return Err(Unspecified);
+        }
+        public_key.truncate(public_key_len);
+        Ok(Self {
+            suite,
+            key,
+            public_key: public_key.into_boxed_slice(),
+        })
+    }
+
+    /// The serialized public key to which senders encrypt.
+    #[must_use]
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    /// The ciphersuite of this key.
+    #[must_use]
+    pub fn suite(&self) -> &'static Suite {
+        self.suite
+    }
+}
+
+// `EVP_HPKE_KEY` is not modified after construction.
+unsafe impl Send for PrivateKey {}
+unsafe impl Sync for PrivateKey {}
+
+impl Debug for PrivateKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrivateKey")
+            .field("suite", &self.suite)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Encrypts `plaintext` to `recipient_public_key` in the HPKE base mode, returning the
+/// encapsulated key and the ciphertext (with the tag appended).
+///
+/// `info` binds the encryption to an application context and `aad` is authenticated but not
+/// encrypted; the recipient must provide the same values to `open`.
+///
+/// # Errors
+/// `error::Unspecified` if `recipient_public_key` is not a valid public key for the KEM of
+/// `suite`, or if the encryption failed.
+pub fn seal(
+    suite: &'static Suite,
+    recipient_public_key: &[u8],
+    info: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Result<(Vec<u8>, Vec<u8>), Unspecified> {
+    seal_with_seed(suite, recipient_public_key, info, aad, plaintext, None)
+}
+
+/// Decrypts `ciphertext` in the HPKE base mode, using the encapsulated key `enc` produced by
+/// `seal`.
+///
+/// # Errors
+/// `error::Unspecified` if `enc`, `info`, `aad` or `ciphertext` do not match what was sealed,
+/// or if the ciphertext was not sealed to `private_key`.
+pub fn open(
+    private_key: &PrivateKey,
+    enc: &[u8],
+    info: &[u8],
+    aad: &[u8],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, Unspecified> {
+    let suite = private_key.suite;
+    let ctx = LcPtr::new(unsafe { EVP_HPKE_CTX_new() })?;
+    if 1 != unsafe {
+        EVP_HPKE_CTX_setup_recipient(
+            *ctx,
+            *private_key.key.as_const(),
+            (suite.kdf)(),
+            (suite.aead)(),
+            enc.as_ptr(),
+            enc.len(),
+            info.as_ptr(),
+            info.len(),
+        )
+    } {
+        return Err(Unspecified);
+    }
+
+    let mut plaintext = vec![0u8; ciphertext.len()];
+    let mut plaintext_len = 0;
+    if 1 != unsafe {
+        EVP_HPKE_CTX_open(
+            *ctx,
+            plaintext.as_mut_ptr(),
+            &mut plaintext_len,
+            plaintext.len(),
+            ciphertext.as_ptr(),
+            ciphertext.len(),
+            aad.as_ptr(),
+            aad.len(),
+        )
+    } {
+        return Err(Unspecified);
+    }
+    plaintext.truncate(plaintext_len);
+    Ok(plaintext)
+}
+
+/// `seal`, with the ephemeral private key taken from `seed` when it is provided.
+fn seal_with_seed(
+    suite: &'static Suite,
+    recipient_public_key: &[u8],
+    info: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+    seed: Option<&[u8]>,
+) -> Result<(Vec<u8>, Vec<u8>), Unspecified> {
+    let ctx = LcPtr::new(unsafe { EVP_HPKE_CTX_new() })?;
+    let kem = (suite.kem)();
+    let mut enc = vec![0u8; unsafe { EVP_HPKE_KEM_enc_len(kem) }];
+    let mut enc_len = 0;
+    if 1 != unsafe {
+        match seed {
+            None => EVP_HPKE_CTX_setup_sender(
+                *ctx,
+                enc.as_mut_ptr(),
+                &mut enc_len,
+                enc.len(),
+                kem,
+                (suite.kdf)(),
+                (suite.aead)(),
+                recipient_public_key.as_ptr(),
+                recipient_public_key.len(),
+                info.as_ptr(),
+                info.len(),
+            ),
+            Some(seed) => EVP_HPKE_CTX_setup_sender_with_seed_for_testing(
+                *ctx,
+                enc.as_mut_ptr(),
+                &mut enc_len,
+                enc.len(),
+                kem,
+                (suite.kdf)(),
+                (suite.aead)(),
+                recipient_public_key.as_ptr(),
+                recipient_public_key.len(),
+                info.as_ptr(),
+                info.len(),
+                seed.as_ptr(),
+                seed.len(),
+            ),
+        }
+    } {
+        return Err(Unspecified);
+    }
+    enc.truncate(enc_len);
+
+    let max_ciphertext_len = plaintext
+        .len()
+        .checked_add(unsafe { EVP_HPKE_CTX_max_overhead(*ctx.as_const()) })
+        .ok_or(Unspecified)?;
+    let mut ciphertext = vec![0u8; max_ciphertext_len];
+    let mut ciphertext_len = 0;
+    if 1 != unsafe {
+        EVP_HPKE_CTX_seal(
+            *ctx,
+            ciphertext.as_mut_ptr(),
+            &mut ciphertext_len,
+            ciphertext.len(),
+            plaintext.as_ptr(),
+            plaintext.len(),
+            aad.as_ptr(),
+            aad.len(),
+        )
+    } {
+        return Err(Unspecified);
+    }
+    ciphertext.truncate(ciphertext_len);
+    Ok((enc, ciphertext))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hpke::{
+        open, seal, seal_with_seed, PrivateKey, DHKEM_X25519_HKDF_SHA256_AES_128_GCM,
+    };
+    use crate::test::{self, from_dirty_hex};
+
+    // RFC 9180, Appendix A.1.1: DHKEM(X25519, HKDF-SHA256), HKDF-SHA256, AES-128-GCM, base
+    // mode, sequence number 0.
+    const SK_EM: &str = "52c4a758a802cd8b936eceea314432798d5baf2d7e9235dc084ab1b9cfa2f736";
+    const PK_RM: &str = "3948cfe0ad1ddb695d780e59077195da6c56506b027329794ab02bca80815c4d";
+    const SK_RM: &str = "4612c550263fc8ad58375df3f557aac531d26850903e55a9f23f21d8534e8ac8";
+    const ENC: &str = "37fda3567bdbd628e88668c3c8d7e97d1d1253b6d4ea6d44c150f741f1bf4431";
+    const INFO: &str = "4f6465206f6e2061204772656369616e2055726e";
+    const AAD: &str = "436f756e742d30";
+    const PT: &str = "4265617574792069732074727574682c20747275746820626561757479";
+    const CT: &str = "f938558b5d72f1a23810b4be2ab4f84331acc02fc97babc53a52ae8218a355a9\
+                      6d8770ac83d07bea87e13c512a";
+
+    #[test]
+    fn test_rfc9180_base_seal() {
+        let suite = &DHKEM_X25519_HKDF_SHA256_AES_128_GCM;
+        let (enc, ciphertext) = seal_with_seed(
+            suite,
+            &from_dirty_hex(PK_RM),
+            &from_dirty_hex(INFO),
+            &from_dirty_hex(AAD),
+            &from_dirty_hex(PT),
+            Some(&from_dirty_hex(SK_EM)),
+        )
+        .unwrap();
+        assert_eq!(from_dirty_hex(ENC), enc);
+        assert_eq!(from_dirty_hex(CT), ciphertext);
+    }
+
+    #[test]
+    fn test_rfc9180_base_open() {
+        let suite = &DHKEM_X25519_HKDF_SHA256_AES_128_GCM;
+        let private_key = PrivateKey::from_bytes(suite, &from_dirty_hex(SK_RM)).unwrap();
+        assert_eq!(from_dirty_hex(PK_RM), private_key.public_key());
+
+        let enc = from_dirty_hex(ENC);
+        let info = from_dirty_hex(INFO);
+        let aad = from_dirty_hex(AAD);
+        let ciphertext = from_dirty_hex(CT);
+        let plaintext = open(&private_key, &enc, &info, &aad, &ciphertext).unwrap();
+        assert_eq!(from_dirty_hex(PT), plaintext);
+
+        assert!(open(&private_key, &enc, b"other info", &aad, &ciphertext).is_err());
+        assert!(open(&private_key, &enc, &info, b"other aad", &ciphertext).is_err());
+        assert!(open(&private_key, &enc[1..], &info, &aad, &ciphertext).is_err());
+        let mut tampered = ciphertext.clone();
+        tampered[0] ^= 1;
+        assert!(open(&private_key, &enc, &info, &aad, &tampered).is_err());
+        assert!(open(&private_key, &enc, &info, &aad, &ciphertext[..15]).is_err());
+    }
+
+    #[test]
+    fn test_traits() {
+        test::compile_time_assert_send::<PrivateKey>();
+        test::compile_time_assert_sync::<PrivateKey>();
+    }
+
+    #[test]
+    fn test_seal_open() {
+        let suite = &DHKEM_X25519_HKDF_SHA256_AES_128_GCM;
+        let private_key = PrivateKey::generate(suite).unwrap();
+        assert_eq!(suite, private_key.suite());
+        assert_eq!(32, private_key.public_key().len());
+
+        let (enc, ciphertext) =
+            seal(suite, private_key.public_key(), b"info", b"aad", b"").unwrap();
+        assert_eq!(32, enc.len());
+        assert_eq!(16, ciphertext.len());
+        assert!(open(&private_key, &enc, b"info", b"aad", &ciphertext)
+            .unwrap()
+            .is_empty());
+
+        // Each message uses a new ephemeral key.
+        let (other_enc, _) = seal(suite, private_key.public_key(), b"info", b"aad", b"").unwrap();
+        assert_ne!(enc, other_enc);
+
+        let other_key = PrivateKey::generate(suite).unwrap();
+        assert!(open(&other_key, &enc, b"info", b"aad", &ciphertext).is_err());
+
+        assert!(PrivateKey::from_bytes(suite, &[0u8; 31]).is_err());
+        assert!(seal(suite, &[0u8; 31], b"info", b"aad", b"").is_err());
+        assert_eq!(
+            "PrivateKey { suite: DHKEM_X25519_HKDF_SHA256_AES_128_GCM, .. }",
+            format!("{private_key:?}")
+        );
+    }
+}
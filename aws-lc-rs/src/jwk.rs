@@ -0,0 +1,318 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! JSON Web Key (JWK) support for elliptic curve keys.
+//!
+//! Parses and emits EC keys as described in [RFC 7518, Section 6.2]: the `kty` member must be
+//! `"EC"`, the `crv` member names the curve and the `x`, `y` and (for private keys) `d` members
+//! hold the base64url-encoded coordinates and private scalar. When parsing, other members, such
+//! as `kid`, `use` or `alg`, are ignored. `EcdsaPublicKey::to_jwk` and
+//! `EcdsaKeyPair::to_jwk_private` emit only the key members.
+//!
+//! # Example
+//! ```
+//! use aws_lc_rs::{jwk, signature};
+//! use aws_lc_rs::signature::KeyPair;
+//!
+//! let key = r#"{
+//!     "kty": "EC",
+//!     "crv": "P-256",
+//!     "x": "MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4",
+//!     "y": "4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM",
+//!     "d": "870MB6gfuTJ4HtUnUvYMyJpr5eUZNP4Bk43bVdj3eAE"
+//! }"#;
+//!
+//! let alg = &signature::ECDSA_P256_SHA256_FIXED_SIGNING;
+//! let key_pair = jwk::ecdsa_key_pair_from_jwk(alg, key)?;
+//! let public_key = jwk::ecdsa_public_key_from_jwk(alg, key)?;
+//! assert_eq!(key_pair.public_key().as_ref(), public_key.as_ref());
+//!
+//! let exported = public_key.to_jwk()?;
+//! let reimported = jwk::ecdsa_public_key_from_jwk(alg, &exported)?;
+//! assert_eq!(public_key.as_ref(), reimported.as_ref());
+//! # Ok::<(), aws_lc_rs::error::KeyRejected>(())
+//! ```
+//!
+//! [RFC 7518, Section 6.2]: https://www.rfc-editor.org/rfc/rfc7518#section-6.2
+
+use crate::ec::{CurveId, Endianness, PublicKey};
+use crate::encoding::{base64url_decode, base64url_encode, AsBigEndian, EcPrivateKeyBin};
+use crate::error::{KeyRejected, Unspecified};
+use crate::signature::{EcdsaKeyPair, EcdsaSigningAlgorithm, KeyPair};
+use serde_json::{Map, Value};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Parses the EC JWK `jwk` as a public key for `alg`.
+///
+/// A `d` member, if present, is ignored.
+///
+/// # Errors
+/// `error::KeyRejected` if `jwk` is not a valid EC JWK, if its `crv` does not match the curve of
+/// `alg`, or if the point is not on the curve.
+pub fn ecdsa_public_key_from_jwk(
+    alg: &'static EcdsaSigningAlgorithm,
+    jwk: &str,
+) -> Result<PublicKey, KeyRejected> {
+    let jwk = parse_ec_jwk(alg, jwk)?;
+    let x = coordinate(alg, &jwk, "x")?;
+    let y = coordinate(alg, &jwk, "y")?;
+    PublicKey::from_coordinates(alg, &x, &y, Endianness::Big)
+}
+
+/// Parses the private EC JWK `jwk` as a key pair for `alg`.
+///
+/// # Errors
+/// `error::KeyRejected` if `jwk` is not a valid EC JWK, if it has no `d` member, if its `crv`
+/// does not match the curve of `alg`, or if the private key does not match the public key.
+pub fn ecdsa_key_pair_from_jwk(
+    alg: &'static EcdsaSigningAlgorithm,
+    jwk: &str,
+) -> Result<EcdsaKeyPair, KeyRejected> {
+    let jwk = parse_ec_jwk(alg, jwk)?;
+    let public_key = uncompressed_point(alg, &jwk)?;
+    let private_key = coordinate(alg, &jwk, "d")?;
+    EcdsaKeyPair::from_private_key_and_public_key(alg, &private_key, &public_key)
+}
+
+impl PublicKey {
+    /// Serializes the public key as an EC JWK with the `kty`, `crv`, `x` and `y` members.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if a compressed public key could not be decompressed.
+    pub fn to_jwk(&self) -> Result<String, Unspecified> {
+        Ok(Value::Object(ec_jwk_members(&self.normalize()?)).to_string())
+    }
+}
+
+impl EcdsaKeyPair {
+    /// Serializes the key pair as a private EC JWK with the `kty`, `crv`, `x`, `y` and `d`
+    /// members.
+    ///
+    /// The returned string contains the private key and is zeroized when dropped.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if the private key could not be serialized.
+    pub fn to_jwk_private(&self) -> Result<Zeroizing<String>, Unspecified> {
+        let private_key: EcPrivateKeyBin<'static> = self.private_key().as_be_bytes()?;
+        let mut jwk = ec_jwk_members(self.public_key());
+        jwk.insert(
+            "d".to_string(),
+            Value::String(base64url_encode(private_key.as_ref())),
+        );
+        let mut jwk = Value::Object(jwk);
+        let serialized = Zeroizing::new(jwk.to_string());
+        if let Some(Value::String(d)) = jwk.get_mut("d") {
+            d.zeroize();
+        }
+        Ok(serialized)
+    }
+}
+
+fn ec_jwk_members(public_key: &PublicKey) -> Map<String, Value> {
+    let point = public_key.as_ref();
+    let (x, y) = point[1..].split_at((point.len() - 1) / 2);
+    let mut jwk = Map::new();
+    jwk.insert("kty".to_string(), Value::from("EC"));
+    jwk.insert(
+        "crv".to_string(),
+        Value::from(curve_name(public_key.algorithm().curve_id())),
+    );
+    jwk.insert("x".to_string(), Value::String(base64url_encode(x)));
+    jwk.insert("y".to_string(), Value::String(base64url_encode(y)));
+    jwk
+}
+
+/// The JWK `crv` name of `curve` (RFC 7518, Section 6.2.1.1 and RFC 8812, Section 3.1).
+pub(crate) fn curve_name(curve: CurveId) -> &'static str {
+    match curve {
+        CurveId::P256 => "P-256",
+        CurveId::P384 => "P-384",
+        CurveId::P521 => "P-521",
+        CurveId::P256K1 => "secp256k1",
+        CurveId::BrainpoolP256R1 => "brainpoolP256r1",
+        CurveId::BrainpoolP384R1 => "brainpoolP384r1",
+        CurveId::BrainpoolP512R1 => "brainpoolP512r1",
+    }
+}
+
+fn parse_ec_jwk(
+    alg: &'static EcdsaSigningAlgorithm,
+    jwk: &str,
+) -> Result<Map<String, Value>, KeyRejected> {
+    let jwk = match serde_json::from_str(jwk) {
+        Ok(Value::Object(jwk)) => jwk,
+        _ => return Err(KeyRejected::invalid_encoding()),
+    };
+    if member(&jwk, "kty")? != Some("EC") {
+        return Err(KeyRejected::wrong_algorithm());
+    }
+    if member(&jwk, "crv")?.ok_or_else(KeyRejected::invalid_encoding)? != curve_name(alg.curve_id())
+    {
+        return Err(KeyRejected::wrong_algorithm());
+    }
+    Ok(jwk)
+}
+
+/// The string value of the member `name`, if present.
+fn member<'a>(jwk: &'a Map<String, Value>, name: &str) -> Result<Option<&'a str>, KeyRejected> {
+    match jwk.get(name) {
+        None => Ok(None),
+        Some(Value::String(value)) => Ok(Some(value)),
+        Some(_) => Err(KeyRejected::invalid_encoding()),
+    }
+}
+
+/// Decodes the member `name`, which must be a field element or scalar of the curve of `alg`.
+fn coordinate(
+    alg: &'static EcdsaSigningAlgorithm,
+    jwk: &Map<String, Value>,
+    name: &str,
+) -> Result<Zeroizing<Vec<u8>>, KeyRejected> {
+    let value = member(jwk, name)?.ok_or_else(KeyRejected::invalid_encoding)?;
+    let bytes =
+        Zeroizing::new(base64url_decode(value).map_err(|_| KeyRejected::invalid_encoding())?);
+    if bytes.len() != alg.id.private_key_size() {
+        return Err(KeyRejected::invalid_encoding());
+    }
+    Ok(bytes)
+}
+
+fn uncompressed_point(
+    alg: &'static EcdsaSigningAlgorithm,
+    jwk: &Map<String, Value>,
+) -> Result<Vec<u8>, KeyRejected> {
+    let x = coordinate(alg, jwk, "x")?;
+    let y = coordinate(alg, jwk, "y")?;
+    Ok([&[0x04], x.as_slice(), y.as_slice()].concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoding::AsBigEndian;
+    use crate::jwk::{ecdsa_key_pair_from_jwk, ecdsa_public_key_from_jwk};
+    use crate::rand::SystemRandom;
+    use crate::signature::{
+        EcdsaKeyPair, KeyPair, UnparsedPublicKey, ECDSA_P256_SHA256_ASN1,
+        ECDSA_P256_SHA256_ASN1_SIGNING, ECDSA_P384_SHA384_ASN1_SIGNING,
+        ECDSA_P521_SHA512_ASN1_SIGNING,
+    };
+
+    // RFC 7517, Appendix A.2.
+    const PRIVATE_JWK: &str = r#"{"kty":"EC",
+          "crv":"P-256",
+          "x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4",
+          "y":"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM",
+          "d":"870MB6gfuTJ4HtUnUvYMyJpr5eUZNP4Bk43bVdj3eAE",
+          "use":"enc",
+          "kid":"1"}"#;
+
+    // RFC 7517, Appendix A.1.
+    const PUBLIC_JWK: &str = r#"{"kty":"EC",
+          "crv":"P-256",
+          "x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4",
+          "y":"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM",
+          "use":"enc",
+          "kid":"1"}"#;
+
+    #[test]
+    fn test_public_jwk() {
+        let public_key =
+            ecdsa_public_key_from_jwk(&ECDSA_P256_SHA256_ASN1_SIGNING, PUBLIC_JWK).unwrap();
+        let public_key_bytes = public_key.as_ref();
+        assert_eq!(65, public_key_bytes.len());
+        assert_eq!(0x04, public_key_bytes[0]);
+        assert_eq!(0x30, public_key_bytes[1]);
+        assert_eq!(0x23, public_key_bytes[64]);
+
+        assert!(ecdsa_key_pair_from_jwk(&ECDSA_P256_SHA256_ASN1_SIGNING, PUBLIC_JWK).is_err());
+    }
+
+    #[test]
+    fn test_private_jwk() {
+        let key_pair =
+            ecdsa_key_pair_from_jwk(&ECDSA_P256_SHA256_ASN1_SIGNING, PRIVATE_JWK).unwrap();
+        let public_key =
+            ecdsa_public_key_from_jwk(&ECDSA_P256_SHA256_ASN1_SIGNING, PRIVATE_JWK).unwrap();
+        assert_eq!(key_pair.public_key().as_ref(), public_key.as_ref());
+
+        let message = b"signed with a JWK";
+        let signature = key_pair.sign(&SystemRandom::new(), message).unwrap();
+        UnparsedPublicKey::new(&ECDSA_P256_SHA256_ASN1, public_key.as_ref())
+            .verify(message, signature.as_ref())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_to_jwk_round_trip() {
+        for alg in [
+            &ECDSA_P256_SHA256_ASN1_SIGNING,
+            &ECDSA_P384_SHA384_ASN1_SIGNING,
+            &ECDSA_P521_SHA512_ASN1_SIGNING,
+        ] {
+            let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+
+            let public_jwk = key_pair.public_key().to_jwk().unwrap();
+            let public_key = ecdsa_public_key_from_jwk(alg, &public_jwk).unwrap();
+            assert_eq!(key_pair.public_key().as_ref(), public_key.as_ref());
+            assert_eq!(public_jwk, public_key.to_jwk().unwrap());
+            assert!(ecdsa_key_pair_from_jwk(alg, &public_jwk).is_err());
+
+            let private_jwk = key_pair.to_jwk_private().unwrap();
+            let reimported = ecdsa_key_pair_from_jwk(alg, &private_jwk).unwrap();
+            assert_eq!(
+                key_pair.private_key().as_be_bytes().unwrap().as_ref(),
+                reimported.private_key().as_be_bytes().unwrap().as_ref()
+            );
+            assert_eq!(private_jwk, reimported.to_jwk_private().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_to_jwk() {
+        let key_pair =
+            ecdsa_key_pair_from_jwk(&ECDSA_P256_SHA256_ASN1_SIGNING, PRIVATE_JWK).unwrap();
+        assert_eq!(
+            r#"{"crv":"P-256","kty":"EC","x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4","y":"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM"}"#,
+            key_pair.public_key().to_jwk().unwrap()
+        );
+        assert_eq!(
+            r#"{"crv":"P-256","d":"870MB6gfuTJ4HtUnUvYMyJpr5eUZNP4Bk43bVdj3eAE","kty":"EC","x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4","y":"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM"}"#,
+            key_pair.to_jwk_private().unwrap().as_str()
+        );
+    }
+
+    #[test]
+    fn test_invalid_jwk() {
+        let alg = &ECDSA_P256_SHA256_ASN1_SIGNING;
+
+        // Wrong curve for the algorithm.
+        assert!(ecdsa_public_key_from_jwk(&ECDSA_P384_SHA384_ASN1_SIGNING, PUBLIC_JWK).is_err());
+
+        for invalid in [
+            "",
+            "[]",
+            "{}",
+            &PUBLIC_JWK.replace("\"EC\"", "\"RSA\""),
+            &PUBLIC_JWK.replace("\"kty\":\"EC\",", ""),
+            &PUBLIC_JWK.replace("P-256", "P-384"),
+            &PUBLIC_JWK.replace("P-256", "secp256k1"),
+            &PUBLIC_JWK.replace("\"P-256\"", "256"),
+            // Truncated and padded coordinates.
+            &PUBLIC_JWK.replace("7D4", ""),
+            &PUBLIC_JWK.replace("7D4", "7D4AA"),
+            // Not base64url.
+            &PUBLIC_JWK.replace("MKBC", "MK+C"),
+            // Not on the curve.
+            &PUBLIC_JWK.replace("MKBC", "MKBD"),
+        ] {
+            assert!(
+                ecdsa_public_key_from_jwk(alg, invalid).is_err(),
+                "{invalid}"
+            );
+        }
+
+        // The private key does not match the public key.
+        let mismatched = PRIVATE_JWK.replace("870M", "870N");
+        assert!(ecdsa_key_pair_from_jwk(alg, &mismatched).is_err());
+    }
+}
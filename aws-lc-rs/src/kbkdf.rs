@@ -0,0 +1,323 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Key-based key derivation in counter mode.
+//!
+//! An implementation of the counter mode KDF of [NIST SP 800-108r1], section 4.1, using either
+//! HMAC (built on the [`crate::hmac`] module) or AES-CMAC ([NIST SP 800-38B]) as the PRF. Each
+//! block of output is
+//!
+//! ```text
+//! K(i) = PRF(key, [i]_32 || label || 0x00 || context || [L]_32)
+//! ```
+//!
+//! where `i` is the block counter starting at 1, `L` is the length of the output in bits, and
+//! `[x]_32` is the 32-bit big-endian encoding of `x`. Because `L` is part of every block, outputs
+//! of different lengths are unrelated; a shorter output is not a prefix of a longer one.
+//!
+//! New protocols should generally prefer [`crate::hkdf`]. This module is intended for
+//! interoperability with key hierarchies that specify SP 800-108.
+//!
+//! # Example
+//! ```
+//! use aws_lc_rs::{hmac, kbkdf};
+//!
+//! let key = [0x0b; 32];
+//! let mut encryption_key = [0u8; 32];
+//! kbkdf::derive(
+//!     kbkdf::Prf::Hmac(hmac::HMAC_SHA256),
+//!     &key,
+//!     b"encryption",
+//!     b"session 1",
+//!     &mut encryption_key,
+//! )?;
+//! # Ok::<(), aws_lc_rs::error::Unspecified>(())
+//! ```
+//!
+//! [NIST SP 800-108r1]: https://csrc.nist.gov/pubs/sp/800/108/r1/final
+//! [NIST SP 800-38B]: https://csrc.nist.gov/pubs/sp/800/38/b/upd1/final
+
+use crate::digest::MAX_OUTPUT_LEN;
+use crate::error::Unspecified;
+use crate::hmac;
+use crate::ptr::LcPtr;
+use aws_lc::{
+    CMAC_CTX_new, CMAC_Final, CMAC_Init, CMAC_Reset, CMAC_Update, EVP_aes_128_cbc, EVP_aes_256_cbc,
+    CMAC_CTX,
+};
+use core::ptr::null_mut;
+use zeroize::Zeroize;
+
+/// The length of an AES-CMAC output block.
+const AES_CMAC_LEN: usize = 16;
+
+/// The pseudorandom function used to derive each block of output.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Prf {
+    /// HMAC with the given algorithm.
+    Hmac(hmac::Algorithm),
+
+    /// AES-CMAC. The key must be 16 or 32 bytes long, for AES-128 or AES-256 respectively.
+    AesCmac,
+}
+
+/// Fills `out` with key material derived from `key`, `label` and `context` using the SP 800-108
+/// counter mode KDF with `prf` as the PRF.
+///
+/// # Errors
+/// `error::Unspecified` if `out` is empty, if its length in bits does not fit in 32 bits, or if
+/// `key` has an invalid length for `prf`.
+pub fn derive(
+    prf: Prf,
+    key: &[u8],
+    label: &[u8],
+    context: &[u8],
+    out: &mut [u8],
+) -> Result<(), Unspecified> {
+    let output_bits = out
+        .len()
+        .checked_mul(8)
+        .and_then(|bits| u32::try_from(bits).ok())
+        .ok_or(Unspecified)?;
+    derive_with_fixed_input(
+        prf,
+        key,
+        &[label, &[0x00], context, &output_bits.to_be_bytes()],
+        out,
+    )
+}
+
+/// Counter mode with the counter before the fixed input data, which is the concatenation of
+/// `fixed_input`.
+fn derive_with_fixed_input(
+    prf: Prf,
+    key: &[u8],
+    fixed_input: &[&[u8]],
+    out: &mut [u8],
+) -> Result<(), Unspecified> {
+    if out.is_empty() {
+        return Err(Unspecified);
+    }
+
+    match prf {
+        Prf::Hmac(algorithm) => {
+            let key = hmac::Key::new(algorithm, key);
+            let block_len = algorithm.digest_algorithm().output_len();
+            counter_mode(block_len, out, |counter, block| {
+                let mut context = hmac::Context::with_key(&key);
+                context.update(counter);
+                for input in fixed_input {
+                    context.update(input);
+                }
+                block[..block_len].copy_from_slice(context.sign().as_ref());
+                Ok(())
+            })
+        }
+        Prf::AesCmac => {
+            let ctx = aes_cmac_ctx(key)?;
+            counter_mode(AES_CMAC_LEN, out, |counter, block| {
+                if 1 != unsafe { CMAC_Reset(*ctx) } {
+                    return Err(Unspecified);
+                }
+                for input in core::iter::once(&counter).chain(fixed_input) {
+                    if 1 != unsafe { CMAC_Update(*ctx, input.as_ptr(), input.len()) } {
+                        return Err(Unspecified);
+                    }
+                }
+                let mut block_len = AES_CMAC_LEN;
+                if 1 != unsafe { CMAC_Final(*ctx, block.as_mut_ptr(), &mut block_len) } {
+                    return Err(Unspecified);
+                }
+                Ok(())
+            })
+        }
+    }
+}
+
+/// Fills `out` with successive `block_len`-byte blocks computed by `prf` from the 32-bit
+/// big-endian counter, truncating the last block as needed.
+fn counter_mode<F>(block_len: usize, out: &mut [u8], mut prf: F) -> Result<(), Unspecified>
+where
+    F: FnMut(&[u8], &mut [u8; MAX_OUTPUT_LEN]) -> Result<(), Unspecified>,
+{
+    let mut block = [0u8; MAX_OUTPUT_LEN];
+    let result = out
+        .chunks_mut(block_len)
+        .enumerate()
+        .try_for_each(|(i, chunk)| {
+            let counter = u32::try_from(i + 1).map_err(|_| Unspecified)?;
+            prf(&counter.to_be_bytes(), &mut block)?;
+            chunk.copy_from_slice(&block[..chunk.len()]);
+            Ok(())
+        });
+    block.zeroize();
+    result
+}
+
+fn aes_cmac_ctx(key: &[u8]) -> Result<LcPtr<CMAC_CTX>, Unspecified> {
+    let cipher = match key.len() {
+        16 => unsafe { EVP_aes_128_cbc() },
+        32 => unsafe { EVP_aes_256_cbc() },
+        _ => return Err(Unspecified),
+    };
+    let ctx = LcPtr::new(unsafe { CMAC_CTX_new() })?;
+    if 1 != unsafe { CMAC_Init(*ctx, key.as_ptr().cast(), key.len(), cipher, null_mut()) } {
+        return Err(Unspecified);
+    }
+    Ok(ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hmac;
+    use crate::kbkdf::{derive, derive_with_fixed_input, Prf};
+    use crate::test::from_hex;
+
+    // NIST CAVP KBKDF_CTR.rsp: [CTRLOCATION=BEFORE_FIXED], [RLEN=32_BITS], COUNT=0 for each PRF.
+    #[test]
+    fn test_nist_counter_before_fixed() {
+        for (algorithm, key, fixed_input, expected) in [
+            (
+                hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY,
+                "f7591733c856593565130975351954d0155abf3c",
+                "8e347ef55d5f5e99eab6de706b51de7ce004f3882889e259ff4e5cff102167a5\
+                 a4bd711578d4ce17dd9abe56e51c1f2df950e2fc812ec1b217ca08d6",
+                "34fe44b0d8c41b93f5fa64fb96f00e5b",
+            ),
+            (
+                hmac::HMAC_SHA256,
+                "dd1d91b7d90b2bd3138533ce92b272fbf8a369316aefe242e659cc0ae238afe0",
+                "01322b96b30acd197979444e468e1c5c6859bf1b1cf951b7e725303e237e46b8\
+                 64a145fab25e517b08f8683d0315bb2911d80a0e8aba17f3b413faac",
+                "10621342bfb0fd40046c0e29f2cfdbf0",
+            ),
+            (
+                hmac::HMAC_SHA384,
+                "216ed044769c4c3908188ece61601af8819c30f501d12995df608e06f5e0e607\
+                 ab54f542ee2da41906dfdb4971f20f9d",
+                "638e9506a2c7be69ea346b84629a010c0e225b7548f508162c89f29c1ddbfd70\
+                 472c2b58e7dc8aa6a5b06602f1c8ed4948cda79c62708218e26ac0e2",
+                "d4b144bb40c7cabed13963d7d4318e72",
+            ),
+            (
+                hmac::HMAC_SHA512,
+                "dd5dbd45593ee2ac139748e7645b450f223d2ff297b73fd71cbcebe71d41653c\
+                 950b88500de5322d99ef18dfdd30428294c4b3094f4c954334e593bd982ec614",
+                "b50b0c963c6b3034b8cf19cd3f5c4ebe4f4985af0c03e575db62e6fdf1ecfe4f\
+                 28b95d7ce16df85843246e1557ce95bb26cc9a21974bbd2eb69e8355",
+                "e5993bf9bd2aa1c45746042e12598155",
+            ),
+        ] {
+            let key = from_hex(key).unwrap();
+            let fixed_input = from_hex(fixed_input).unwrap();
+            let expected = from_hex(expected).unwrap();
+            let mut out = vec![0u8; expected.len()];
+            derive_with_fixed_input(Prf::Hmac(algorithm), &key, &[&fixed_input], &mut out).unwrap();
+            assert_eq!(expected, out);
+        }
+    }
+
+    // NIST CAVP KBKDF_CTR.rsp: [PRF=CMAC_AES128], [CTRLOCATION=BEFORE_FIXED], [RLEN=32_BITS],
+    // COUNT=0.
+    #[test]
+    fn test_nist_cmac_counter_before_fixed() {
+        let key = from_hex("c10b152e8c97b77e18704e0f0bd38305").unwrap();
+        let fixed_input = from_hex(
+            "98cd4cbbbebe15d17dc86e6dbad800a2dcbd64f7c7ad0e78e9cf94ffdba89d03\
+             e97eadf6c4f7b806caf52aa38f09d0eb71d71f497bcc6906b48d36c4",
+        )
+        .unwrap();
+        let expected = from_hex("26faf61908ad9ee881b8305c221db53f").unwrap();
+        let mut out = [0u8; 16];
+        derive_with_fixed_input(Prf::AesCmac, &key, &[&fixed_input], &mut out).unwrap();
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn test_derive_aes_cmac() {
+        // Three blocks, the last truncated; cross-checked against the `cryptography` Python
+        // package's `KBKDFCMAC`.
+        for (key, expected) in [
+            (
+                [0x0bu8; 16].as_slice(),
+                "b1689de3951e38f1a74b198d6ddf655f05a641187ea2ecd678bac0267d8ac4bf\
+                 529d59db40c5d12fbfe1",
+            ),
+            (
+                [0x0bu8; 32].as_slice(),
+                "47c760c6379b2838b730ebd1b370a7c9aadc546cac17e93eea782454e13acf1d\
+                 f3947f7005f94a6288c3",
+            ),
+        ] {
+            let mut out = [0u8; 42];
+            derive(Prf::AesCmac, key, b"label", b"context", &mut out).unwrap();
+            assert_eq!(from_hex(expected).unwrap(), out);
+        }
+
+        // Only AES-128 and AES-256 keys are accepted.
+        let mut out = [0u8; 16];
+        for len in [0, 15, 24, 33] {
+            assert!(derive(Prf::AesCmac, &vec![0x0b; len], b"", b"", &mut out).is_err());
+        }
+    }
+
+    #[test]
+    fn test_derive() {
+        let key = [0x0bu8; 32];
+
+        // Two blocks, the second truncated; cross-checked against the `cryptography` Python
+        // package's `KBKDFHMAC`.
+        let expected = from_hex(
+            "c0a73a79f0cb6d9586cc199e07fb5f366d2c2711b4bbed26e63154edfa8b3c8a\
+             1373835a7bcac1a90860",
+        )
+        .unwrap();
+        let mut out = [0u8; 42];
+        derive(
+            Prf::Hmac(hmac::HMAC_SHA256),
+            &key,
+            b"label",
+            b"context",
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(expected, out);
+
+        // The output length is bound into every block.
+        let mut shorter = [0u8; 32];
+        derive(
+            Prf::Hmac(hmac::HMAC_SHA256),
+            &key,
+            b"label",
+            b"context",
+            &mut shorter,
+        )
+        .unwrap();
+        assert_ne!(shorter, out[..32]);
+
+        // The separator keeps the label and context apart.
+        let mut other = [0u8; 42];
+        derive(
+            Prf::Hmac(hmac::HMAC_SHA256),
+            &key,
+            b"labelc",
+            b"ontext",
+            &mut other,
+        )
+        .unwrap();
+        assert_ne!(other, out);
+    }
+
+    #[test]
+    fn test_output_len() {
+        let key = [0x0bu8; 32];
+        assert!(derive(Prf::Hmac(hmac::HMAC_SHA256), &key, b"", b"", &mut []).is_err());
+
+        for len in [1, 31, 32, 33, 64, 65, 1000] {
+            let mut out = vec![0u8; len];
+            derive(Prf::Hmac(hmac::HMAC_SHA384), &key, b"", b"", &mut out).unwrap();
+            assert!(out.iter().any(|b| *b != 0));
+        }
+    }
+}
@@ -64,6 +64,41 @@
 //! ["Address Sanitizer" section](https://doc.rust-lang.org/beta/unstable-book/compiler-flags/sanitizer.html#addresssanitizer)
 //! of the [Rust Unstable Book](https://doc.rust-lang.org/beta/unstable-book/).
 //!
+//! #### low-level
+//!
+//! Enables the `aead::evp` module, which allows an AEAD context to be constructed from any
+//! *AWS-LC* `EVP_AEAD`, and the `low_level` module, which exposes elliptic curve point
+//! multiplication and ECDSA verification on curves given by explicit domain parameters. These
+//! bypass the safeguards of the higher-level APIs and are intended for experimentation only.
+//! Their APIs are not covered by semantic versioning guarantees.
+//!
+//! #### rustls
+//!
+//! Enables the `rustls` module, which provides a [rustls](https://docs.rs/rustls) `CryptoProvider`
+//! implemented with this crate. This feature requires a newer Rust compiler than the crate's MSRV.
+//!
+//! #### jwk
+//!
+//! Enables the `jwk` module, which converts elliptic curve keys to and from JSON Web Keys. This
+//! adds a requirement on `serde_json`.
+//!
+//! #### rayon
+//!
+//! Enables `signature::verify_batch_parallel`, which verifies a batch of signatures in parallel
+//! using [rayon](https://docs.rs/rayon).
+//!
+//! #### unstable-ffi
+//!
+//! Exposes raw *AWS-LC* pointers from some types, such as `EcdsaKeyPair::as_evp_pkey_ptr`, for
+//! interoperability with other code linking against *AWS-LC*. These APIs are not covered by
+//! semantic versioning guarantees.
+//!
+//! #### danger-test-only
+//!
+//! Exposes `signature::sign_with_fixed_nonce`, which signs with a caller-provided ECDSA nonce so
+//! that published test vectors can be reproduced. **Do not enable this feature in production
+//! builds**: a signature made with a known nonce reveals the private key.
+//!
 //! #### bindgen
 //!
 //! Causes `aws-lc-sys` or `aws-lc-fips-sys` to generates fresh bindings for AWS-LC instead of using
@@ -77,7 +112,9 @@
 //! behavior is observably different.
 //!
 //! * Our implementation requires the `std` library. We currently do not support a
-//!   [`#![no_std]`](https://docs.rust-embedded.org/book/intro/no-std.html) build.
+//!   [`#![no_std]`](https://docs.rust-embedded.org/book/intro/no-std.html) build: the
+//!   `aws-lc-sys` and `aws-lc-fips-sys` bindings depend on `std`, and the C types in `core::ffi`
+//!   require a newer Rust compiler than the crate's MSRV.
 //! * We can only support a subset of the platforms supported by `aws-lc-sys`. See the list of
 //!   supported platforms above.
 //! * `Ed25519KeyPair::from_pkcs8` and `Ed25519KeyPair::from_pkcs8_maybe_unchecked` both support
@@ -111,11 +148,15 @@ pub mod aead;
 pub mod agreement;
 pub mod constant_time;
 pub mod digest;
+pub mod ecies;
 pub mod error;
 pub mod hkdf;
 pub mod hmac;
+pub mod hmac_drbg;
+pub mod hpke;
 #[cfg(feature = "ring-io")]
 pub mod io;
+pub mod kbkdf;
 pub mod key_wrap;
 pub mod pbkdf2;
 pub mod pkcs8;
@@ -123,6 +164,7 @@ pub mod rand;
 pub mod signature;
 pub mod test;
 
+mod base64;
 mod bn;
 mod buffer;
 mod cbb;
@@ -137,21 +179,30 @@ mod evp_pkey;
 mod fips;
 mod hex;
 pub mod iv;
+#[cfg(feature = "jwk")]
+pub mod jwk;
 #[allow(clippy::module_name_repetitions)]
 pub mod kem;
+pub mod low_level;
 mod ptr;
 pub mod rsa;
+#[cfg(feature = "rustls")]
+pub mod rustls;
+mod supported;
 pub mod tls_prf;
 pub mod unstable;
 
 pub(crate) use debug::derive_debug_via_id;
+pub use supported::{
+    supported_aead_algorithms, supported_digest_algorithms, supported_signature_algorithms,
+};
 // TODO: Uncomment when MSRV >= 1.64
 // use core::ffi::CStr;
 use std::ffi::CStr;
 
 use aws_lc::{
-    CRYPTO_library_init, ERR_error_string, ERR_get_error, FIPS_mode, ERR_GET_FUNC, ERR_GET_LIB,
-    ERR_GET_REASON,
+    CRYPTO_library_init, ERR_error_string, ERR_get_error, EVP_has_aes_hardware, FIPS_mode,
+    ERR_GET_FUNC, ERR_GET_LIB, ERR_GET_REASON,
 };
 use std::sync::Once;
 
@@ -188,6 +239,17 @@ pub fn try_fips_mode() -> Result<(), &'static str> {
     }
 }
 
+/// Indicates whether the CPU has hardware support for AES (e.g. AES-NI on x86-64, or the
+/// Cryptography Extensions on AArch64) that the underlying implementation uses.
+///
+/// Without it, AES-GCM is typically slower than ChaCha20-Poly1305, so protocols that negotiate
+/// cipher suites at runtime may prefer ChaCha20-Poly1305 when this returns `false`.
+#[must_use]
+pub fn has_aes_hardware() -> bool {
+    init();
+    1 == unsafe { EVP_has_aes_hardware() }
+}
+
 #[allow(dead_code)]
 unsafe fn dump_error() {
     let err = ERR_get_error();
@@ -218,13 +280,19 @@ mod sealed {
 
 #[cfg(test)]
 mod tests {
-    use crate::{dump_error, init};
+    use crate::{dump_error, has_aes_hardware, init};
 
     #[test]
     fn test_init() {
         init();
     }
 
+    #[test]
+    fn test_has_aes_hardware() {
+        // The result depends on the CPU; it must be stable across calls.
+        assert_eq!(has_aes_hardware(), has_aes_hardware());
+    }
+
     #[test]
     fn test_dump() {
         unsafe {
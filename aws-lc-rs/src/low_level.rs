@@ -0,0 +1,16 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+#![cfg(feature = "low-level")]
+
+//! Low-level elliptic curve operations.
+//!
+//! # ⚠️ Warning
+//! These operations bypass the safeguards of the `signature` and `agreement` modules. They exist
+//! for protocols built directly on the curve's group operation, and for verifying signatures on
+//! curves without built-in support.
+//!
+//! This module is only available with the `low-level` feature, and its API is not covered by
+//! semantic versioning guarantees.
+
+pub use crate::ec::{point_mul, CustomCurve};
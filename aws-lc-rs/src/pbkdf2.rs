@@ -8,6 +8,10 @@
 //! Use `derive` to derive PBKDF2 outputs. Use `verify` to verify secret
 //! against previously-derived outputs.
 //!
+//! For password storage, `hash_password` and `verify_password` produce and
+//! check self-contained password hash strings that embed the algorithm, the
+//! iteration count and the salt.
+//!
 //! PBKDF2 is specified in [RFC 2898 Section 5.2] with test vectors given in
 //! [RFC 6070]. See also [NIST Special Publication 800-132].
 //!
@@ -105,7 +109,7 @@
 
 use crate::error::Unspecified;
 use crate::fips::indicator_check;
-use crate::{constant_time, digest, hmac};
+use crate::{base64, constant_time, digest, hmac, rand};
 use aws_lc::PKCS5_PBKDF2_HMAC;
 use core::num::NonZeroU32;
 use zeroize::Zeroize;
@@ -278,6 +282,103 @@ pub fn verify(
     result
 }
 
+/// The length of the random salt generated by `hash_password`.
+const PASSWORD_SALT_LEN: usize = 16;
+
+impl Algorithm {
+    /// The identifier of this algorithm in password hash strings.
+    fn password_hash_id(&self) -> &'static str {
+        match self.algorithm.digest_algorithm().id {
+            digest::AlgorithmID::SHA1 => "pbkdf2-sha1",
+            digest::AlgorithmID::SHA384 => "pbkdf2-sha384",
+            digest::AlgorithmID::SHA512 => "pbkdf2-sha512",
+            _ => "pbkdf2-sha256",
+        }
+    }
+
+    fn from_password_hash_id(id: &str) -> Option<Self> {
+        [
+            PBKDF2_HMAC_SHA1,
+            PBKDF2_HMAC_SHA256,
+            PBKDF2_HMAC_SHA384,
+            PBKDF2_HMAC_SHA512,
+        ]
+        .into_iter()
+        .find(|algorithm| algorithm.password_hash_id() == id)
+    }
+}
+
+/// Hashes `password` with a random salt, returning a self-contained password hash string.
+///
+/// The string is in the [PHC string format], e.g.
+/// `$pbkdf2-sha256$i=600000$<salt>$<hash>`, where the 16-byte salt and the hash are encoded as
+/// unpadded base64. The hash is as long as the output of the digest algorithm. The string can be
+/// stored as is and later checked with `verify_password`.
+///
+/// [PHC string format]: https://github.com/P-H-C/phc-string-format/blob/master/phc-sf-spec.md
+///
+/// # Errors
+/// `error::Unspecified` if the salt could not be generated or the derivation failed.
+//
+// # FIPS
+// The following conditions must be met:
+// * `password.len()` >= 14
+// * `iterations` >= 1000
+pub fn hash_password(
+    algorithm: Algorithm,
+    iterations: NonZeroU32,
+    password: &[u8],
+) -> Result<String, Unspecified> {
+    let salt: [u8; PASSWORD_SALT_LEN] = rand::generate(&rand::SystemRandom::new())?.expose();
+    let mut hash = [0u8; digest::MAX_OUTPUT_LEN];
+    let hash = &mut hash[..algorithm.algorithm.digest_algorithm().output_len()];
+    try_derive(algorithm, iterations, &salt, password, hash)?;
+    Ok(format!(
+        "${}$i={}${}${}",
+        algorithm.password_hash_id(),
+        iterations,
+        base64::encode(salt),
+        base64::encode(hash)
+    ))
+}
+
+/// Verifies `password` against a password hash string produced by `hash_password`.
+///
+/// The algorithm, iteration count and salt are taken from `encoded`, and the comparison is done
+/// in constant time.
+///
+/// # Errors
+/// `error::Unspecified` if `encoded` is malformed, or if `password` does not match.
+pub fn verify_password(password: &[u8], encoded: &str) -> Result<(), Unspecified> {
+    let mut fields = encoded.split('$');
+    if fields.next() != Some("") {
+        return Err(Unspecified);
+    }
+    let algorithm = fields
+        .next()
+        .and_then(Algorithm::from_password_hash_id)
+        .ok_or(Unspecified)?;
+    let iterations = fields
+        .next()
+        .and_then(|param| param.strip_prefix("i="))
+        .and_then(parse_iterations)
+        .ok_or(Unspecified)?;
+    let salt = base64::decode(fields.next().ok_or(Unspecified)?).map_err(|_| Unspecified)?;
+    let hash = base64::decode(fields.next().ok_or(Unspecified)?).map_err(|_| Unspecified)?;
+    if fields.next().is_some() || hash.len() > digest::MAX_OUTPUT_LEN {
+        return Err(Unspecified);
+    }
+    verify(algorithm, iterations, &salt, password, &hash)
+}
+
+/// Parses a decimal iteration count without a sign or leading zeros.
+fn parse_iterations(value: &str) -> Option<NonZeroU32> {
+    if value.starts_with('0') || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::pbkdf2;
@@ -286,6 +387,55 @@ mod tests {
     #[cfg(feature = "fips")]
     mod fips;
 
+    #[test]
+    fn test_password_hash() {
+        let iterations = NonZeroU32::new(1000).unwrap();
+        for &alg in &[
+            pbkdf2::PBKDF2_HMAC_SHA1,
+            pbkdf2::PBKDF2_HMAC_SHA256,
+            pbkdf2::PBKDF2_HMAC_SHA384,
+            pbkdf2::PBKDF2_HMAC_SHA512,
+        ] {
+            let encoded = pbkdf2::hash_password(alg, iterations, b"correct horse").unwrap();
+            assert!(encoded.starts_with(&format!("${}$i=1000$", alg.password_hash_id())));
+            pbkdf2::verify_password(b"correct horse", &encoded).unwrap();
+            assert!(pbkdf2::verify_password(b"wrong horse", &encoded).is_err());
+            assert!(pbkdf2::verify_password(b"", &encoded).is_err());
+
+            // Each hash uses a new salt.
+            let other = pbkdf2::hash_password(alg, iterations, b"correct horse").unwrap();
+            assert_ne!(encoded, other);
+        }
+    }
+
+    #[test]
+    fn test_verify_password_known_answer() {
+        // Generated with Python's `hashlib.pbkdf2_hmac`.
+        let encoded = "$pbkdf2-sha256$i=1000$c2FsdHNhbHRzYWx0c2FsdA$\
+                       8nX7hwFEzIB8aPajJTYK8weHQc5Ngz0pFVAKvSu4jQA";
+        pbkdf2::verify_password(b"password", encoded).unwrap();
+        assert!(pbkdf2::verify_password(b"Password", encoded).is_err());
+        let encoded = "$pbkdf2-sha512$i=1000$c2FsdHNhbHRzYWx0c2FsdA$\
+                       715rqIr5dXOVPpBhqqsugl037zT5bWJTWYmZtIcK8hBnisKpwfY7kokvwjDrNHqHhF50Pb7MD6HvkJwiDQw4ww";
+        pbkdf2::verify_password(b"password", encoded).unwrap();
+
+        for malformed in [
+            "",
+            "$pbkdf2-sha256$i=1000$c2FsdHNhbHRzYWx0c2FsdA",
+            "pbkdf2-sha256$i=1000$c2FsdHNhbHRzYWx0c2FsdA$8nX7hwFEzIB8aPajJTYK8weHQc5Ngz0pFVAKvSu4jQA",
+            "$pbkdf2-md5$i=1000$c2FsdHNhbHRzYWx0c2FsdA$8nX7hwFEzIB8aPajJTYK8weHQc5Ngz0pFVAKvSu4jQA",
+            "$pbkdf2-sha256$i=0$c2FsdHNhbHRzYWx0c2FsdA$8nX7hwFEzIB8aPajJTYK8weHQc5Ngz0pFVAKvSu4jQA",
+            "$pbkdf2-sha256$i=01000$c2FsdHNhbHRzYWx0c2FsdA$8nX7hwFEzIB8aPajJTYK8weHQc5Ngz0pFVAKvSu4jQA",
+            "$pbkdf2-sha256$i=+1000$c2FsdHNhbHRzYWx0c2FsdA$8nX7hwFEzIB8aPajJTYK8weHQc5Ngz0pFVAKvSu4jQA",
+            "$pbkdf2-sha256$n=1000$c2FsdHNhbHRzYWx0c2FsdA$8nX7hwFEzIB8aPajJTYK8weHQc5Ngz0pFVAKvSu4jQA",
+            "$pbkdf2-sha256$i=1000$c2FsdHNhbHRzYWx0c2FsdA$8nX7hwFEzIB8aPajJTYK8weHQc5Ngz0pFVAKvSu4jQA$",
+            "$pbkdf2-sha256$i=1000$c2FsdHNhbHRzYWx0c2FsdA$",
+            "$pbkdf2-sha256$i=1000$c2FsdHNhbHRzYWx0c2FsdA$8nX7hwFEzIB8aPajJTYK8weHQc5Ngz0pFVAKvSu4jQ!",
+        ] {
+            assert!(pbkdf2::verify_password(b"password", malformed).is_err());
+        }
+    }
+
     #[test]
     fn pbkdf2_coverage() {
         // Coverage sanity check.
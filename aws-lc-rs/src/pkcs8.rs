@@ -18,6 +18,14 @@ impl Document {
     pub(crate) fn new(bytes: Box<[u8]>) -> Self {
         Self { bytes }
     }
+
+    /// Overwrites the contents of the document with zeros.
+    ///
+    /// The document is zeroized when it is dropped; this allows it to be scrubbed earlier, e.g.
+    /// once it has been written out. The length of the document is unchanged.
+    pub fn zeroize(&mut self) {
+        self.bytes.zeroize();
+    }
 }
 
 impl AsRef<[u8]> for Document {
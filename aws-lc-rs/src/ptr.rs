@@ -4,9 +4,10 @@
 use core::ops::Deref;
 
 use aws_lc::{
-    BN_free, ECDSA_SIG_free, EC_GROUP_free, EC_KEY_free, EC_POINT_free, EVP_AEAD_CTX_free,
-    EVP_PKEY_CTX_free, EVP_PKEY_free, OPENSSL_free, RSA_free, BIGNUM, ECDSA_SIG, EC_GROUP, EC_KEY,
-    EC_POINT, EVP_AEAD_CTX, EVP_PKEY, EVP_PKEY_CTX, RSA,
+    BN_CTX_free, BN_free, CMAC_CTX_free, ECDSA_SIG_free, EC_GROUP_free, EC_KEY_free, EC_POINT_free,
+    EVP_AEAD_CTX_free, EVP_HPKE_CTX_free, EVP_HPKE_KEY_free, EVP_PKEY_CTX_free, EVP_PKEY_free,
+    OPENSSL_free, RSA_free, BIGNUM, BN_CTX, CMAC_CTX, ECDSA_SIG, EC_GROUP, EC_KEY, EC_POINT,
+    EVP_AEAD_CTX, EVP_HPKE_CTX, EVP_HPKE_KEY, EVP_PKEY, EVP_PKEY_CTX, RSA,
 };
 
 use mirai_annotations::verify_unreachable;
@@ -203,10 +204,14 @@ create_pointer!(EC_POINT, EC_POINT_free);
 create_pointer!(EC_KEY, EC_KEY_free);
 create_pointer!(ECDSA_SIG, ECDSA_SIG_free);
 create_pointer!(BIGNUM, BN_free);
+create_pointer!(BN_CTX, BN_CTX_free);
 create_pointer!(EVP_PKEY, EVP_PKEY_free);
 create_pointer!(EVP_PKEY_CTX, EVP_PKEY_CTX_free);
 create_pointer!(RSA, RSA_free);
 create_pointer!(EVP_AEAD_CTX, EVP_AEAD_CTX_free);
+create_pointer!(EVP_HPKE_CTX, EVP_HPKE_CTX_free);
+create_pointer!(EVP_HPKE_KEY, EVP_HPKE_KEY_free);
+create_pointer!(CMAC_CTX, CMAC_CTX_free);
 
 #[cfg(test)]
 mod tests {
@@ -119,6 +119,11 @@ impl<T> RandomlyConstructable for T where T: sealed::RandomlyConstructable {}
 /// underlying *AWS-LC* libcrypto.
 ///
 /// A single `SystemRandom` may be shared across multiple threads safely.
+///
+/// `SystemRandom` is fork-safe: *AWS-LC* detects when the process has been forked and reseeds
+/// its generator from the operating system before producing any more output, or, on platforms
+/// where fork detection is unavailable, mixes fresh operating system entropy into every request.
+/// A child process therefore never repeats the output of its parent.
 //
 // # FIPS
 // Use this implementation for retrieving random bytes.
@@ -58,7 +58,7 @@ pub(in crate::rsa) mod rfc8017 {
         EVP_PKEY_assign_RSA, EVP_PKEY_new, RSA_parse_private_key, RSA_public_key_from_bytes,
         RSA_public_key_to_bytes, EVP_PKEY,
     };
-    use std::ptr::null_mut;
+    use core::ptr::null_mut;
 
     /// DER encode a RSA public key to `RSAPublicKey` structure.
     pub(in crate::rsa) unsafe fn encode_public_key_der(
@@ -268,8 +268,8 @@ impl KeyPair {
 impl Debug for KeyPair {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
         f.write_str(&format!(
-            "RsaKeyPair {{ public_key: {:?} }}",
-            self.serialized_public_key
+            "RsaKeyPair {{ bits: {} }}",
+            self.public_modulus_len() * 8
         ))
     }
 }
@@ -447,7 +447,7 @@ where
                 params.digest_algorithm(),
                 params.padding(),
                 &rsa,
-                message,
+                &[message],
                 signature,
                 params.bit_size_range(),
             )
@@ -1,7 +1,7 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
-use std::{
+use core::{
     fmt::{self, Debug, Formatter},
     mem::MaybeUninit,
     ops::RangeInclusive,
@@ -9,7 +9,7 @@ use std::{
 };
 
 use aws_lc::{
-    EVP_DigestSign, EVP_DigestVerify, EVP_DigestVerifyInit, EVP_PKEY_CTX_set_rsa_padding,
+    EVP_DigestSign, EVP_DigestVerifyInit, EVP_PKEY_CTX_set_rsa_padding,
     EVP_PKEY_CTX_set_rsa_pss_saltlen, EVP_PKEY_get0_RSA, RSA_bits, RSA_get0_n, EVP_PKEY,
     EVP_PKEY_CTX, RSA_PKCS1_PSS_PADDING, RSA_PSS_SALTLEN_DIGEST,
 };
@@ -81,13 +81,22 @@ impl VerificationAlgorithm for RsaParameters {
         public_key: &[u8],
         msg: &[u8],
         signature: &[u8],
+    ) -> Result<(), Unspecified> {
+        self.verify_sig_segments(public_key, &[msg], signature)
+    }
+
+    fn verify_sig_segments(
+        &self,
+        public_key: &[u8],
+        segments: &[&[u8]],
+        signature: &[u8],
     ) -> Result<(), Unspecified> {
         let evp_pkey = encoding::rfc8017::decode_public_key_der(public_key)?;
         verify_rsa_signature(
             self.digest_algorithm(),
             self.padding(),
             &evp_pkey,
-            msg,
+            segments,
             signature,
             self.bit_size_range(),
         )
@@ -250,7 +259,7 @@ pub(crate) fn verify_rsa_signature(
     algorithm: &'static digest::Algorithm,
     padding: &'static RsaPadding,
     public_key: &LcPtr<EVP_PKEY>,
-    msg: &[u8],
+    msg: &[&[u8]],
     signature: &[u8],
     allowed_bit_size: &RangeInclusive<u32>,
 ) -> Result<(), Unspecified> {
@@ -284,19 +293,7 @@ pub(crate) fn verify_rsa_signature(
         configure_rsa_pkcs1_pss_padding(pctx)?;
     }
 
-    if 1 != indicator_check!(unsafe {
-        EVP_DigestVerify(
-            md_ctx.as_mut_ptr(),
-            signature.as_ptr(),
-            signature.len(),
-            msg.as_ptr(),
-            msg.len(),
-        )
-    }) {
-        return Err(Unspecified);
-    }
-
-    Ok(())
+    md_ctx.verify(msg, signature)
 }
 
 #[inline]
@@ -0,0 +1,106 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! A [rustls](https://docs.rs/rustls) `CryptoProvider` backed by *aws-lc-rs*.
+//!
+//! The provider supports TLS 1.3 with the following cipher suites, key exchange groups and
+//! signature schemes:
+//!
+//! * Cipher suites: `TLS13_AES_128_GCM_SHA256`, `TLS13_AES_256_GCM_SHA384` and
+//!   `TLS13_CHACHA20_POLY1305_SHA256`.
+//! * Key exchange groups: `X25519`, `secp256r1` and `secp384r1`.
+//! * Signature schemes: ECDSA with P-256, P-384 and P-521, RSA-PSS, RSA PKCS#1 v1.5 (for
+//!   certificates) and Ed25519.
+//!
+//! QUIC and TLS 1.2 are not supported.
+//!
+//! This module is only available with the `rustls` feature.
+//!
+//! # Example
+//! ```
+//! use std::sync::Arc;
+//!
+//! let provider = Arc::new(aws_lc_rs::rustls::default_provider());
+//! let config = rustls::ClientConfig::builder_with_provider(provider)
+//!     .with_protocol_versions(&[&rustls::version::TLS13])
+//!     .unwrap()
+//!     .with_root_certificates(rustls::RootCertStore::empty())
+//!     .with_no_client_auth();
+//! # let _ = config;
+//! ```
+
+mod hash;
+mod kx;
+pub mod sign;
+mod tls13;
+mod verify;
+
+use crate::rand::SecureRandom as _;
+use ::rustls::crypto::{
+    CryptoProvider, GetRandomFailed, KeyProvider, SecureRandom, SupportedKxGroup,
+};
+use ::rustls::pki_types::PrivateKeyDer;
+use ::rustls::sign::SigningKey;
+use ::rustls::{Error, SupportedCipherSuite};
+use std::sync::Arc;
+
+pub use kx::{SECP256R1, SECP384R1, X25519};
+pub use tls13::{
+    TLS13_AES_128_GCM_SHA256, TLS13_AES_256_GCM_SHA384, TLS13_CHACHA20_POLY1305_SHA256,
+};
+pub use verify::SUPPORTED_SIG_ALGS;
+
+/// Returns a `CryptoProvider` using the cipher suites, key exchange groups and signature
+/// verification algorithms of this module.
+#[must_use]
+pub fn default_provider() -> CryptoProvider {
+    CryptoProvider {
+        cipher_suites: DEFAULT_CIPHER_SUITES.to_vec(),
+        kx_groups: ALL_KX_GROUPS.to_vec(),
+        signature_verification_algorithms: SUPPORTED_SIG_ALGS,
+        secure_random: &AwsLcRs,
+        key_provider: &AwsLcRs,
+    }
+}
+
+/// The cipher suites supported by this provider, in order of preference.
+pub static DEFAULT_CIPHER_SUITES: &[SupportedCipherSuite] = &[
+    TLS13_AES_256_GCM_SHA384,
+    TLS13_AES_128_GCM_SHA256,
+    TLS13_CHACHA20_POLY1305_SHA256,
+];
+
+/// The key exchange groups supported by this provider, in order of preference.
+pub static ALL_KX_GROUPS: &[&dyn SupportedKxGroup] = &[X25519, SECP256R1, SECP384R1];
+
+#[derive(Debug)]
+struct AwsLcRs;
+
+impl SecureRandom for AwsLcRs {
+    fn fill(&self, buf: &mut [u8]) -> Result<(), GetRandomFailed> {
+        crate::rand::SystemRandom::new()
+            .fill(buf)
+            .map_err(|_| GetRandomFailed)
+    }
+
+    fn fips(&self) -> bool {
+        fips()
+    }
+}
+
+impl KeyProvider for AwsLcRs {
+    fn load_private_key(
+        &self,
+        key_der: PrivateKeyDer<'static>,
+    ) -> Result<Arc<dyn SigningKey>, Error> {
+        sign::any_supported_type(&key_der)
+    }
+
+    fn fips(&self) -> bool {
+        fips()
+    }
+}
+
+fn fips() -> bool {
+    crate::try_fips_mode().is_ok()
+}
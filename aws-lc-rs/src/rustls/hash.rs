@@ -0,0 +1,56 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::digest;
+use ::rustls::crypto::hash::{Context as HashContext, Hash as RustlsHash, HashAlgorithm, Output};
+
+pub(super) static SHA256: Hash = Hash(&digest::SHA256, HashAlgorithm::SHA256);
+pub(super) static SHA384: Hash = Hash(&digest::SHA384, HashAlgorithm::SHA384);
+
+pub(super) struct Hash(&'static digest::Algorithm, HashAlgorithm);
+
+impl RustlsHash for Hash {
+    fn start(&self) -> Box<dyn HashContext> {
+        Box::new(Context(digest::Context::new(self.0)))
+    }
+
+    fn hash(&self, bytes: &[u8]) -> Output {
+        convert(&digest::digest(self.0, bytes))
+    }
+
+    fn output_len(&self) -> usize {
+        self.0.output_len()
+    }
+
+    fn algorithm(&self) -> HashAlgorithm {
+        self.1
+    }
+
+    fn fips(&self) -> bool {
+        super::fips()
+    }
+}
+
+struct Context(digest::Context);
+
+impl HashContext for Context {
+    fn fork_finish(&self) -> Output {
+        convert(&self.0.clone().finish())
+    }
+
+    fn fork(&self) -> Box<dyn HashContext> {
+        Box::new(Self(self.0.clone()))
+    }
+
+    fn finish(self: Box<Self>) -> Output {
+        convert(&self.0.finish())
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+}
+
+fn convert(digest: &digest::Digest) -> Output {
+    Output::new(digest.as_ref())
+}
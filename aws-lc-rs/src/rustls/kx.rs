@@ -0,0 +1,138 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::agreement;
+use crate::rand::SystemRandom;
+use ::rustls::crypto::{ActiveKeyExchange, GetRandomFailed, SharedSecret, SupportedKxGroup};
+use ::rustls::{Error, NamedGroup, PeerMisbehaved};
+use core::fmt;
+
+/// Ephemeral ECDH on curve25519 (see RFC 7748).
+pub static X25519: &dyn SupportedKxGroup = &KxGroup {
+    name: NamedGroup::X25519,
+    agreement_algorithm: &agreement::X25519,
+    // X25519 is not an approved key agreement scheme under SP 800-56Arev3.
+    fips_allowed: false,
+    pub_key_validator: |point: &[u8]| point.len() == 32,
+};
+
+/// Ephemeral ECDH on secp256r1 (aka NIST P-256).
+pub static SECP256R1: &dyn SupportedKxGroup = &KxGroup {
+    name: NamedGroup::secp256r1,
+    agreement_algorithm: &agreement::ECDH_P256,
+    fips_allowed: true,
+    pub_key_validator: uncompressed_point,
+};
+
+/// Ephemeral ECDH on secp384r1 (aka NIST P-384).
+pub static SECP384R1: &dyn SupportedKxGroup = &KxGroup {
+    name: NamedGroup::secp384r1,
+    agreement_algorithm: &agreement::ECDH_P384,
+    fips_allowed: true,
+    pub_key_validator: uncompressed_point,
+};
+
+/// A key exchange group backed by an `agreement::Algorithm`.
+struct KxGroup {
+    name: NamedGroup,
+    agreement_algorithm: &'static agreement::Algorithm,
+    fips_allowed: bool,
+    /// `agreement` also accepts compressed points, which TLS 1.3 does not allow. This checks the
+    /// shape of the peer's key share before it is parsed.
+    pub_key_validator: fn(&[u8]) -> bool,
+}
+
+impl SupportedKxGroup for KxGroup {
+    fn start(&self) -> Result<Box<dyn ActiveKeyExchange>, Error> {
+        let rng = SystemRandom::new();
+        let priv_key = agreement::EphemeralPrivateKey::generate(self.agreement_algorithm, &rng)
+            .map_err(|_| GetRandomFailed)?;
+        let pub_key = priv_key.compute_public_key().map_err(|_| GetRandomFailed)?;
+
+        Ok(Box::new(KeyExchange {
+            name: self.name,
+            agreement_algorithm: self.agreement_algorithm,
+            priv_key,
+            pub_key,
+            pub_key_validator: self.pub_key_validator,
+        }))
+    }
+
+    fn name(&self) -> NamedGroup {
+        self.name
+    }
+
+    fn fips(&self) -> bool {
+        self.fips_allowed && super::fips()
+    }
+}
+
+impl fmt::Debug for KxGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name.fmt(f)
+    }
+}
+
+fn uncompressed_point(point: &[u8]) -> bool {
+    matches!(point.first(), Some(0x04))
+}
+
+/// An in-progress key exchange.
+struct KeyExchange {
+    name: NamedGroup,
+    agreement_algorithm: &'static agreement::Algorithm,
+    priv_key: agreement::EphemeralPrivateKey,
+    pub_key: agreement::PublicKey,
+    pub_key_validator: fn(&[u8]) -> bool,
+}
+
+impl ActiveKeyExchange for KeyExchange {
+    fn complete(self: Box<Self>, peer: &[u8]) -> Result<SharedSecret, Error> {
+        if !(self.pub_key_validator)(peer) {
+            return Err(PeerMisbehaved::InvalidKeyShare.into());
+        }
+        let peer_key = agreement::UnparsedPublicKey::new(self.agreement_algorithm, peer);
+        agreement::agree_ephemeral(self.priv_key, &peer_key, (), |secret| {
+            Ok(SharedSecret::from(secret))
+        })
+        .map_err(|()| PeerMisbehaved::InvalidKeyShare.into())
+    }
+
+    fn group(&self) -> NamedGroup {
+        self.name
+    }
+
+    fn pub_key(&self) -> &[u8] {
+        self.pub_key.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SECP256R1, SECP384R1, X25519};
+
+    #[test]
+    fn test_kx_groups() {
+        for group in [X25519, SECP256R1, SECP384R1] {
+            let alice = group.start().unwrap();
+            let bob = group.start().unwrap();
+            let alice_pub = alice.pub_key().to_vec();
+            let bob_pub = bob.pub_key().to_vec();
+
+            let alice_secret = alice.complete(&bob_pub).unwrap();
+            let bob_secret = bob.complete(&alice_pub).unwrap();
+            assert_eq!(alice_secret.secret_bytes(), bob_secret.secret_bytes());
+
+            // Compressed or truncated key shares are rejected.
+            let mut invalid = alice_pub.clone();
+            invalid[0] = 0x02;
+            invalid.truncate(if group.name() == X25519.name() {
+                31
+            } else {
+                33
+            });
+            assert!(group.start().unwrap().complete(&invalid).is_err());
+        }
+        assert_eq!("X25519", format!("{X25519:?}"));
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Loading of private keys as rustls `SigningKey`s.
+
+use crate::rand::SystemRandom;
+use crate::signature::{self, EcdsaKeyPair, Ed25519KeyPair, KeyPair, RsaEncoding, RsaKeyPair};
+use ::rustls::pki_types::{alg_id, AlgorithmIdentifier, PrivateKeyDer, SubjectPublicKeyInfoDer};
+use ::rustls::sign::{public_key_to_spki, Signer, SigningKey};
+use ::rustls::{Error, SignatureAlgorithm, SignatureScheme};
+use core::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+
+/// Parses `der` as an RSA, ECDSA or Ed25519 private key, returning the first which works.
+///
+/// RSA keys may be PKCS#1 or PKCS#8 encoded, ECDSA keys may be SEC1 or PKCS#8 encoded and
+/// Ed25519 keys must be PKCS#8 encoded.
+///
+/// # Errors
+/// `rustls::Error::General` if the key is not a supported type or encoding.
+pub fn any_supported_type(der: &PrivateKeyDer<'_>) -> Result<Arc<dyn SigningKey>, Error> {
+    if let Ok(rsa) = RsaSigningKey::new(der) {
+        return Ok(Arc::new(rsa));
+    }
+    if let Ok(ecdsa) = any_ecdsa_type(der) {
+        return Ok(ecdsa);
+    }
+    if let PrivateKeyDer::Pkcs8(pkcs8) = der {
+        if let Ok(key_pair) = Ed25519KeyPair::from_pkcs8(pkcs8.secret_pkcs8_der()) {
+            return Ok(Arc::new(Ed25519SigningKey(Arc::new(key_pair))));
+        }
+    }
+
+    Err(Error::General(
+        "failed to parse private key as RSA, ECDSA, or EdDSA".into(),
+    ))
+}
+
+/// Parses `der` as an ECDSA P-256, P-384 or P-521 private key, returning the first which
+/// works.
+///
+/// # Errors
+/// `rustls::Error::General` if the key is not a supported ECDSA key.
+pub fn any_ecdsa_type(der: &PrivateKeyDer<'_>) -> Result<Arc<dyn SigningKey>, Error> {
+    let bytes = match der {
+        PrivateKeyDer::Sec1(sec1) => sec1.secret_sec1_der(),
+        PrivateKeyDer::Pkcs8(pkcs8) => pkcs8.secret_pkcs8_der(),
+        _ => return Err(Error::General("unsupported ECDSA key encoding".into())),
+    };
+
+    for (scheme, alg, curve_alg_id) in [
+        (
+            SignatureScheme::ECDSA_NISTP256_SHA256,
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            alg_id::ECDSA_P256,
+        ),
+        (
+            SignatureScheme::ECDSA_NISTP384_SHA384,
+            &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            alg_id::ECDSA_P384,
+        ),
+        (
+            SignatureScheme::ECDSA_NISTP521_SHA512,
+            &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+            alg_id::ECDSA_P521,
+        ),
+    ] {
+        if let Ok(key_pair) = EcdsaKeyPair::from_private_key_der(alg, bytes) {
+            return Ok(Arc::new(EcdsaSigningKey {
+                key: Arc::new(key_pair),
+                scheme,
+                curve_alg_id,
+            }));
+        }
+    }
+
+    Err(Error::General(
+        "failed to parse ECDSA private key as PKCS#8 or SEC1".into(),
+    ))
+}
+
+static ALL_RSA_SCHEMES: &[SignatureScheme] = &[
+    SignatureScheme::RSA_PSS_SHA512,
+    SignatureScheme::RSA_PSS_SHA384,
+    SignatureScheme::RSA_PSS_SHA256,
+    SignatureScheme::RSA_PKCS1_SHA512,
+    SignatureScheme::RSA_PKCS1_SHA384,
+    SignatureScheme::RSA_PKCS1_SHA256,
+];
+
+struct RsaSigningKey(Arc<RsaKeyPair>);
+
+impl RsaSigningKey {
+    fn new(der: &PrivateKeyDer<'_>) -> Result<Self, Error> {
+        match der {
+            PrivateKeyDer::Pkcs1(pkcs1) => RsaKeyPair::from_der(pkcs1.secret_pkcs1_der()),
+            PrivateKeyDer::Pkcs8(pkcs8) => RsaKeyPair::from_pkcs8(pkcs8.secret_pkcs8_der()),
+            _ => return Err(Error::General("unsupported RSA key encoding".into())),
+        }
+        .map(|key_pair| Self(Arc::new(key_pair)))
+        .map_err(|e| Error::General(format!("failed to parse RSA private key: {e}")))
+    }
+}
+
+impl SigningKey for RsaSigningKey {
+    fn choose_scheme(&self, offered: &[SignatureScheme]) -> Option<Box<dyn Signer>> {
+        let scheme = *ALL_RSA_SCHEMES
+            .iter()
+            .find(|scheme| offered.contains(scheme))?;
+        let encoding: &'static dyn RsaEncoding = match scheme {
+            SignatureScheme::RSA_PSS_SHA512 => &signature::RSA_PSS_SHA512,
+            SignatureScheme::RSA_PSS_SHA384 => &signature::RSA_PSS_SHA384,
+            SignatureScheme::RSA_PSS_SHA256 => &signature::RSA_PSS_SHA256,
+            SignatureScheme::RSA_PKCS1_SHA512 => &signature::RSA_PKCS1_SHA512,
+            SignatureScheme::RSA_PKCS1_SHA384 => &signature::RSA_PKCS1_SHA384,
+            _ => &signature::RSA_PKCS1_SHA256,
+        };
+        Some(Box::new(RsaSigner {
+            key: self.0.clone(),
+            scheme,
+            encoding,
+        }))
+    }
+
+    fn public_key(&self) -> Option<SubjectPublicKeyInfoDer<'_>> {
+        Some(public_key_to_spki(
+            &alg_id::RSA_ENCRYPTION,
+            self.0.public_key(),
+        ))
+    }
+
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::RSA
+    }
+}
+
+impl Debug for RsaSigningKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RsaSigningKey").finish_non_exhaustive()
+    }
+}
+
+struct RsaSigner {
+    key: Arc<RsaKeyPair>,
+    scheme: SignatureScheme,
+    encoding: &'static dyn RsaEncoding,
+}
+
+impl Signer for RsaSigner {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut sig = vec![0; self.key.public_modulus_len()];
+        self.key
+            .sign(self.encoding, &SystemRandom::new(), message, &mut sig)
+            .map_err(|_| Error::General("signing failed".into()))?;
+        Ok(sig)
+    }
+
+    fn scheme(&self) -> SignatureScheme {
+        self.scheme
+    }
+}
+
+impl Debug for RsaSigner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RsaSigner")
+            .field("scheme", &self.scheme)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Clone)]
+struct EcdsaSigningKey {
+    key: Arc<EcdsaKeyPair>,
+    scheme: SignatureScheme,
+    curve_alg_id: AlgorithmIdentifier,
+}
+
+impl SigningKey for EcdsaSigningKey {
+    fn choose_scheme(&self, offered: &[SignatureScheme]) -> Option<Box<dyn Signer>> {
+        if offered.contains(&self.scheme) {
+            Some(Box::new(self.clone()))
+        } else {
+            None
+        }
+    }
+
+    fn public_key(&self) -> Option<SubjectPublicKeyInfoDer<'_>> {
+        Some(public_key_to_spki(
+            &self.curve_alg_id,
+            self.key.public_key(),
+        ))
+    }
+
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::ECDSA
+    }
+}
+
+impl Signer for EcdsaSigningKey {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        self.key
+            .sign(&SystemRandom::new(), message)
+            .map(|sig| sig.as_ref().to_vec())
+            .map_err(|_| Error::General("signing failed".into()))
+    }
+
+    fn scheme(&self) -> SignatureScheme {
+        self.scheme
+    }
+}
+
+impl Debug for EcdsaSigningKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EcdsaSigningKey")
+            .field("scheme", &self.scheme)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Clone)]
+struct Ed25519SigningKey(Arc<Ed25519KeyPair>);
+
+impl SigningKey for Ed25519SigningKey {
+    fn choose_scheme(&self, offered: &[SignatureScheme]) -> Option<Box<dyn Signer>> {
+        if offered.contains(&SignatureScheme::ED25519) {
+            Some(Box::new(self.clone()))
+        } else {
+            None
+        }
+    }
+
+    fn public_key(&self) -> Option<SubjectPublicKeyInfoDer<'_>> {
+        Some(public_key_to_spki(&alg_id::ED25519, self.0.public_key()))
+    }
+
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::ED25519
+    }
+}
+
+impl Signer for Ed25519SigningKey {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        Ok(self.0.sign(message).as_ref().to_vec())
+    }
+
+    fn scheme(&self) -> SignatureScheme {
+        SignatureScheme::ED25519
+    }
+}
+
+impl Debug for Ed25519SigningKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ed25519SigningKey").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::hkdf::KeyType;
+use crate::{aead, hkdf, hmac};
+use ::rustls::crypto::cipher::{
+    make_tls13_aad, AeadKey, InboundOpaqueMessage, InboundPlainMessage, Iv, MessageDecrypter,
+    MessageEncrypter, Nonce, OutboundOpaqueMessage, OutboundPlainMessage, PrefixedPayload,
+    Tls13AeadAlgorithm, UnsupportedOperationError,
+};
+use ::rustls::crypto::tls13::{Hkdf, HkdfExpander, OkmBlock, OutputLengthError};
+use ::rustls::crypto::{hmac::Tag, CipherSuiteCommon};
+use ::rustls::{
+    CipherSuite, ConnectionTrafficSecrets, ContentType, Error, ProtocolVersion,
+    SupportedCipherSuite, Tls13CipherSuite,
+};
+
+/// The TLS 1.3 cipher suite `TLS_AES_128_GCM_SHA256`.
+pub static TLS13_AES_128_GCM_SHA256: SupportedCipherSuite =
+    SupportedCipherSuite::Tls13(&Tls13CipherSuite {
+        common: CipherSuiteCommon {
+            suite: CipherSuite::TLS13_AES_128_GCM_SHA256,
+            hash_provider: &super::hash::SHA256,
+            confidentiality_limit: 1 << 24,
+        },
+        hkdf_provider: &AwsLcRsHkdf(hkdf::HKDF_SHA256, hmac::HMAC_SHA256),
+        aead_alg: &AeadAlgorithm(&aead::AES_128_GCM),
+        quic: None,
+    });
+
+/// The TLS 1.3 cipher suite `TLS_AES_256_GCM_SHA384`.
+pub static TLS13_AES_256_GCM_SHA384: SupportedCipherSuite =
+    SupportedCipherSuite::Tls13(&Tls13CipherSuite {
+        common: CipherSuiteCommon {
+            suite: CipherSuite::TLS13_AES_256_GCM_SHA384,
+            hash_provider: &super::hash::SHA384,
+            confidentiality_limit: 1 << 24,
+        },
+        hkdf_provider: &AwsLcRsHkdf(hkdf::HKDF_SHA384, hmac::HMAC_SHA384),
+        aead_alg: &AeadAlgorithm(&aead::AES_256_GCM),
+        quic: None,
+    });
+
+/// The TLS 1.3 cipher suite `TLS_CHACHA20_POLY1305_SHA256`.
+pub static TLS13_CHACHA20_POLY1305_SHA256: SupportedCipherSuite =
+    SupportedCipherSuite::Tls13(&Tls13CipherSuite {
+        common: CipherSuiteCommon {
+            suite: CipherSuite::TLS13_CHACHA20_POLY1305_SHA256,
+            hash_provider: &super::hash::SHA256,
+            confidentiality_limit: u64::MAX,
+        },
+        hkdf_provider: &AwsLcRsHkdf(hkdf::HKDF_SHA256, hmac::HMAC_SHA256),
+        aead_alg: &AeadAlgorithm(&aead::CHACHA20_POLY1305),
+        quic: None,
+    });
+
+struct AeadAlgorithm(&'static aead::Algorithm);
+
+impl Tls13AeadAlgorithm for AeadAlgorithm {
+    fn encrypter(&self, key: AeadKey, iv: Iv) -> Box<dyn MessageEncrypter> {
+        // `key` is always `key_len()` bytes long.
+        Box::new(Tls13MessageEncrypter {
+            enc_key: aead::LessSafeKey::new(aead::UnboundKey::new(self.0, key.as_ref()).unwrap()),
+            iv,
+        })
+    }
+
+    fn decrypter(&self, key: AeadKey, iv: Iv) -> Box<dyn MessageDecrypter> {
+        // `key` is always `key_len()` bytes long.
+        Box::new(Tls13MessageDecrypter {
+            dec_key: aead::LessSafeKey::new(aead::UnboundKey::new(self.0, key.as_ref()).unwrap()),
+            iv,
+        })
+    }
+
+    fn key_len(&self) -> usize {
+        self.0.key_len()
+    }
+
+    fn extract_keys(
+        &self,
+        key: AeadKey,
+        iv: Iv,
+    ) -> Result<ConnectionTrafficSecrets, UnsupportedOperationError> {
+        Ok(if self.0 == &aead::AES_128_GCM {
+            ConnectionTrafficSecrets::Aes128Gcm { key, iv }
+        } else if self.0 == &aead::AES_256_GCM {
+            ConnectionTrafficSecrets::Aes256Gcm { key, iv }
+        } else {
+            ConnectionTrafficSecrets::Chacha20Poly1305 { key, iv }
+        })
+    }
+
+    fn fips(&self) -> bool {
+        // ChaCha20-Poly1305 is not a FIPS approved algorithm.
+        self.0 != &aead::CHACHA20_POLY1305 && super::fips()
+    }
+}
+
+struct Tls13MessageEncrypter {
+    enc_key: aead::LessSafeKey,
+    iv: Iv,
+}
+
+struct Tls13MessageDecrypter {
+    dec_key: aead::LessSafeKey,
+    iv: Iv,
+}
+
+impl MessageEncrypter for Tls13MessageEncrypter {
+    fn encrypt(
+        &mut self,
+        msg: OutboundPlainMessage<'_>,
+        seq: u64,
+    ) -> Result<OutboundOpaqueMessage, Error> {
+        let total_len = self.encrypted_payload_len(msg.payload.len());
+        let mut payload = PrefixedPayload::with_capacity(total_len);
+
+        let nonce = aead::Nonce::assume_unique_for_key(Nonce::new(&self.iv, seq).0);
+        let aad = aead::Aad::from(make_tls13_aad(total_len));
+        payload.extend_from_chunks(&msg.payload);
+        payload.extend_from_slice(&msg.typ.to_array());
+
+        self.enc_key
+            .seal_in_place_append_tag(nonce, aad, &mut payload)
+            .map_err(|_| Error::EncryptError)?;
+
+        // TLS 1.3 records use TLS 1.2 as the legacy record version (RFC 8446, section 5.1).
+        Ok(OutboundOpaqueMessage::new(
+            ContentType::ApplicationData,
+            ProtocolVersion::TLSv1_2,
+            payload,
+        ))
+    }
+
+    fn encrypted_payload_len(&self, payload_len: usize) -> usize {
+        payload_len + 1 + self.enc_key.algorithm().tag_len()
+    }
+}
+
+impl MessageDecrypter for Tls13MessageDecrypter {
+    fn decrypt<'a>(
+        &mut self,
+        mut msg: InboundOpaqueMessage<'a>,
+        seq: u64,
+    ) -> Result<InboundPlainMessage<'a>, Error> {
+        let payload = &mut msg.payload;
+        if payload.len() < self.dec_key.algorithm().tag_len() {
+            return Err(Error::DecryptError);
+        }
+
+        let nonce = aead::Nonce::assume_unique_for_key(Nonce::new(&self.iv, seq).0);
+        let aad = aead::Aad::from(make_tls13_aad(payload.len()));
+        let plain_len = self
+            .dec_key
+            .open_in_place(nonce, aad, payload)
+            .map_err(|_| Error::DecryptError)?
+            .len();
+
+        payload.truncate(plain_len);
+        msg.into_tls13_unpadded_message()
+    }
+}
+
+struct AwsLcRsHkdf(hkdf::Algorithm, hmac::Algorithm);
+
+impl AwsLcRsHkdf {
+    fn expander(&self, salt: Option<&[u8]>, secret: Option<&[u8]>) -> Box<dyn HkdfExpander> {
+        let zeroes = [0u8; OkmBlock::MAX_LEN];
+        let zeroes = &zeroes[..self.0.len()];
+        let salt = hkdf::Salt::new(self.0, salt.unwrap_or(zeroes));
+        Box::new(AwsLcRsHkdfExpander {
+            alg: self.0,
+            prk: salt.extract(secret.unwrap_or(zeroes)),
+        })
+    }
+}
+
+impl Hkdf for AwsLcRsHkdf {
+    fn extract_from_zero_ikm(&self, salt: Option<&[u8]>) -> Box<dyn HkdfExpander> {
+        self.expander(salt, None)
+    }
+
+    fn extract_from_secret(&self, salt: Option<&[u8]>, secret: &[u8]) -> Box<dyn HkdfExpander> {
+        self.expander(salt, Some(secret))
+    }
+
+    fn expander_for_okm(&self, okm: &OkmBlock) -> Box<dyn HkdfExpander> {
+        Box::new(AwsLcRsHkdfExpander {
+            alg: self.0,
+            prk: hkdf::Prk::new_less_safe(self.0, okm.as_ref()),
+        })
+    }
+
+    fn hmac_sign(&self, key: &OkmBlock, message: &[u8]) -> Tag {
+        Tag::new(hmac::sign(&hmac::Key::new(self.1, key.as_ref()), message).as_ref())
+    }
+
+    fn fips(&self) -> bool {
+        super::fips()
+    }
+}
+
+struct AwsLcRsHkdfExpander {
+    alg: hkdf::Algorithm,
+    prk: hkdf::Prk,
+}
+
+impl HkdfExpander for AwsLcRsHkdfExpander {
+    fn expand_slice(&self, info: &[&[u8]], output: &mut [u8]) -> Result<(), OutputLengthError> {
+        self.prk
+            .expand(info, hkdf::OutputLen(output.len()))
+            .and_then(|okm| okm.fill(output))
+            .map_err(|_| OutputLengthError)
+    }
+
+    fn expand_block(&self, info: &[&[u8]]) -> OkmBlock {
+        let mut buf = [0u8; OkmBlock::MAX_LEN];
+        let output = &mut buf[..self.hash_len()];
+        // The output is never longer than the maximum HKDF output length.
+        self.prk
+            .expand(info, hkdf::OutputLen(output.len()))
+            .and_then(|okm| okm.fill(output))
+            .unwrap();
+        OkmBlock::new(output)
+    }
+
+    fn hash_len(&self) -> usize {
+        self.alg.len()
+    }
+}
@@ -23,6 +23,9 @@
 //! requiring signing large messages. An interface for efficiently supporting
 //! larger messages may be added later.
 //!
+//! The message to be signed or verified may be empty. For the algorithms that digest the
+//! message, the digest of the empty string is signed or verified like any other digest.
+//!
 //!
 //! # Algorithm Details
 //!
@@ -32,6 +35,11 @@
 //! [RFC 3279 Section 2.2.3]. This is the form of ECDSA signature used in
 //! X.509-related structures and in TLS's `ServerKeyExchange` messages.
 //!
+//! Signatures are parsed strictly: a signature that is not exactly the DER
+//! encoding of its `Ecdsa-Sig-Value` is rejected. This includes signatures with
+//! trailing data, non-minimal lengths, and negative or non-minimally encoded
+//! integers. There is no lenient mode.
+//!
 //! The public key is encoding in uncompressed form using the
 //! Octet-String-to-Elliptic-Curve-Point algorithm in
 //! [SEC 1: Elliptic Curve Cryptography, Version 2.0].
@@ -113,6 +121,13 @@
 //!     https://tools.ietf.org/html/rfc3447#appendix-A.1.1
 //!
 //!
+//! ## Unsupported algorithms
+//!
+//! Ed448 ([RFC 8032]) is not available. *AWS-LC* defines an identifier for the algorithm but does
+//! not provide an implementation of it, so Ed25519 is the only supported `EdDSA` algorithm.
+//!
+//! [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032
+//!
 //! # Examples
 //!
 //! ## Signing and verifying with Ed25519
@@ -254,23 +269,31 @@ use crate::rsa::{
     RsaVerificationAlgorithmId,
 };
 
+pub mod cose;
+mod scheme;
+
+#[cfg(feature = "danger-test-only")]
+pub use crate::ec::key_pair::sign_with_fixed_nonce;
 pub use crate::ec::key_pair::{EcdsaKeyPair, PrivateKey as EcdsaPrivateKey};
 use crate::ec::EcdsaSignatureFormat;
 pub use crate::ec::{
-    EcdsaSigningAlgorithm, EcdsaVerificationAlgorithm, PublicKey as EcdsaPublicKey,
+    derive_public_key, verify_p256_fixed, verify_rs, verify_with_context, CurveId,
+    EcdsaSigningAlgorithm, EcdsaVerificationAlgorithm, Endianness, PublicKey as EcdsaPublicKey,
+    ECDSA_CONTEXT_MAX_LEN, ECDSA_P256_PUBLIC_KEY_LEN, ECDSA_P256_SIGNATURE_ASN1_MAX_LEN,
+    ECDSA_P256_SIGNATURE_FIXED_LEN, ECDSA_P384_PUBLIC_KEY_LEN, ECDSA_P384_SIGNATURE_ASN1_MAX_LEN,
+    ECDSA_P384_SIGNATURE_FIXED_LEN, ECDSA_P521_PUBLIC_KEY_LEN, ECDSA_P521_SIGNATURE_ASN1_MAX_LEN,
+    ECDSA_P521_SIGNATURE_FIXED_LEN, ECDSA_SIGNATURE_MAX_LEN,
 };
 pub use crate::ed25519::{
     Ed25519KeyPair, EdDSAParameters, Seed as Ed25519Seed, ED25519_PUBLIC_KEY_LEN,
 };
 use crate::rsa;
 use crate::{digest, ec, error, hex, sealed};
+pub use scheme::SignatureScheme;
+use zeroize::Zeroize;
 
-/// The longest signature is an ASN.1 P-384 signature where *r* and *s* are of
-/// maximum length with the leading high bit set on each. Then each component
-/// will have a tag, a one-byte length, and a one-byte “I'm not negative”
-/// prefix, and the outer sequence will have a two-byte length.
-pub(crate) const MAX_LEN: usize = 1/*tag:SEQUENCE*/ + 2/*len*/ +
-    (2 * (1/*tag:INTEGER*/ + 1/*len*/ + 1/*zero*/ + ec::SCALAR_MAX_BYTES));
+/// The longest signature is an ASN.1 ECDSA signature; see `ECDSA_SIGNATURE_MAX_LEN`.
+pub(crate) const MAX_LEN: usize = ec::ECDSA_SIGNATURE_MAX_LEN;
 
 /// A public key signature returned from a signing operation.
 #[derive(Clone, Copy)]
@@ -292,6 +315,25 @@ impl Signature {
         r.len = fill(&mut r.value);
         r
     }
+
+    pub(crate) fn try_new<F>(fill: F) -> Result<Self, error::Unspecified>
+    where
+        F: FnOnce(&mut [u8; MAX_LEN]) -> Result<usize, error::Unspecified>,
+    {
+        let mut r = Self {
+            value: [0; MAX_LEN],
+            len: 0,
+        };
+        r.len = fill(&mut r.value)?;
+        Ok(r)
+    }
+
+    /// Overwrites the signature with zeros. The length of the signature is unchanged.
+    ///
+    /// Since `Signature` is `Copy`, this only scrubs this particular value, not any copies of it.
+    pub fn zeroize(&mut self) {
+        self.value.zeroize();
+    }
 }
 
 impl AsRef<[u8]> for Signature {
@@ -301,7 +343,18 @@ impl AsRef<[u8]> for Signature {
     }
 }
 
+impl PartialEq for Signature {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl Eq for Signature {}
+
 /// Key pairs for signing messages (private key and public key).
+///
+/// Implemented by `EcdsaKeyPair`, `Ed25519KeyPair` and `RsaKeyPair`, allowing code that is
+/// generic over the key type to obtain the public key.
 pub trait KeyPair: Debug + Send + Sized + Sync {
     /// The type of the public key.
     type PublicKey: AsRef<[u8]> + Debug + Clone + Send + Sized + Sync;
@@ -349,6 +402,23 @@ pub trait VerificationAlgorithm: Debug + Sync + sealed::Sealed {
         msg: &[u8],
         signature: &[u8],
     ) -> Result<(), error::Unspecified>;
+
+    /// Verify the signature `signature` of the concatenation of `segments` with the public
+    /// key `public_key`.
+    ///
+    /// The ECDSA and RSA algorithms feed each segment into the message digest in turn. Ed25519
+    /// requires the whole message at once, so the segments are joined into a single buffer.
+    ///
+    /// # Errors
+    /// `error::Unspecified` if inputs not verified.
+    fn verify_sig_segments(
+        &self,
+        public_key: &[u8],
+        segments: &[&[u8]],
+        signature: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        self.verify_sig(public_key, &segments.concat(), signature)
+    }
 }
 
 /// An unparsed, possibly malformed, public key for signature verification.
@@ -373,7 +443,9 @@ impl<B: AsRef<[u8]>> Debug for UnparsedPublicKey<B> {
 impl<B: AsRef<[u8]>> UnparsedPublicKey<B> {
     /// Construct a new `UnparsedPublicKey`.
     ///
-    /// No validation of `bytes` is done until `verify()` is called.
+    /// No validation of `bytes` is done until `verify()` is called. `bytes` is
+    /// borrowed as-is during verification, so a fixed-size array such as
+    /// `[u8; 65]` can be used to avoid a heap allocation.
     #[inline]
     pub fn new(algorithm: &'static dyn VerificationAlgorithm, bytes: B) -> Self {
         Self { algorithm, bytes }
@@ -397,6 +469,104 @@ impl<B: AsRef<[u8]>> UnparsedPublicKey<B> {
         self.algorithm
             .verify_sig(self.bytes.as_ref(), message, signature)
     }
+
+    /// The verification algorithm this key is used with.
+    ///
+    /// Since keys for different algorithms share the `UnparsedPublicKey<B>` type, a collection
+    /// such as `Vec<UnparsedPublicKey<Vec<u8>>>` can hold keys for any mix of algorithms.
+    #[inline]
+    #[must_use]
+    pub fn algorithm(&self) -> &'static dyn VerificationAlgorithm {
+        self.algorithm
+    }
+}
+
+impl<B: AsRef<[u8]>> AsRef<[u8]> for UnparsedPublicKey<B> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.bytes.as_ref()
+    }
+}
+
+/// Verifies `signature` of `msg` with each of the candidate public keys in `keys` in turn,
+/// returning the index of the first key for which the signature is valid.
+///
+/// This is useful during key rotation, when several keys are valid at the same time. Keys after
+/// the first one that verifies are not tried, so ordering `keys` with the most likely key first
+/// bounds the work done.
+///
+/// # Errors
+/// `error::Unspecified` if the signature is not valid under any of `keys`.
+pub fn verify_with_keys(
+    alg: &'static dyn VerificationAlgorithm,
+    keys: &[&[u8]],
+    msg: &[u8],
+    signature: &[u8],
+) -> Result<usize, error::Unspecified> {
+    keys.iter()
+        .position(|public_key| alg.verify_sig(public_key, msg, signature).is_ok())
+        .ok_or(error::Unspecified)
+}
+
+/// Verifies `signature` of `msg` using `public_key` with each algorithm in `algs` in turn,
+/// returning the first algorithm for which the signature is valid.
+///
+/// This is useful when a signature may have been produced with any of several permitted
+/// algorithms. Note that `public_key` is parsed by each algorithm that is tried.
+///
+/// # Errors
+/// `error::Unspecified` if the signature is not valid under any of `algs`.
+pub fn verify_any(
+    algs: &[&'static dyn VerificationAlgorithm],
+    public_key: &[u8],
+    msg: &[u8],
+    signature: &[u8],
+) -> Result<&'static dyn VerificationAlgorithm, error::Unspecified> {
+    algs.iter()
+        .copied()
+        .find(|alg| alg.verify_sig(public_key, msg, signature).is_ok())
+        .ok_or(error::Unspecified)
+}
+
+/// Verifies `signature` over the concatenation of `segments` using `alg` and `public_key`.
+///
+/// This is equivalent to verifying the joined message, but for the ECDSA and RSA algorithms
+/// the segments are digested incrementally, so multi-part messages (e.g. a header and a body)
+/// need not be copied into a single buffer.
+///
+/// # Errors
+/// `error::Unspecified` if the signature is not valid.
+pub fn verify_segments(
+    alg: &'static dyn VerificationAlgorithm,
+    public_key: &[u8],
+    segments: &[&[u8]],
+    signature: &[u8],
+) -> Result<(), error::Unspecified> {
+    alg.verify_sig_segments(public_key, segments, signature)
+}
+
+/// Verifies each `(alg, public_key, msg, signature)` item of `items` independently, returning
+/// whether each signature is valid, in the same order as `items`.
+///
+/// The items are verified in parallel on the [rayon](https://docs.rs/rayon) global thread pool,
+/// which speeds up CPU-bound workloads such as a validator checking many signatures at once.
+/// Each verification parses its own public key, so no state is shared between threads.
+///
+/// This function is only available with the `rayon` feature.
+#[cfg(feature = "rayon")]
+#[allow(clippy::type_complexity)]
+#[must_use]
+pub fn verify_batch_parallel(
+    items: &[(&'static dyn VerificationAlgorithm, &[u8], &[u8], &[u8])],
+) -> Vec<bool> {
+    use rayon::prelude::*;
+
+    items
+        .par_iter()
+        .map(|&(alg, public_key, msg, signature)| {
+            alg.verify_sig(public_key, msg, signature).is_ok()
+        })
+        .collect()
 }
 
 /// Verification of signatures using RSA keys of 1024-8192 bits, PKCS#1.5 padding, and SHA-1.
@@ -585,6 +755,33 @@ pub static ECDSA_P256K1_SHA3_256_FIXED: EcdsaVerificationAlgorithm = EcdsaVerifi
     sig_format: EcdsaSignatureFormat::Fixed,
 };
 
+/// Verification of fixed-length (PKCS#11 style) ECDSA signatures using the brainpoolP256r1 curve and SHA-256.
+pub static ECDSA_BRAINPOOL_P256R1_SHA256_FIXED: EcdsaVerificationAlgorithm =
+    EcdsaVerificationAlgorithm {
+        id: &ec::AlgorithmID::ECDSA_BRAINPOOL_P256R1,
+        digest: &digest::SHA256,
+        bits: 256,
+        sig_format: EcdsaSignatureFormat::Fixed,
+    };
+
+/// Verification of fixed-length (PKCS#11 style) ECDSA signatures using the brainpoolP384r1 curve and SHA-384.
+pub static ECDSA_BRAINPOOL_P384R1_SHA384_FIXED: EcdsaVerificationAlgorithm =
+    EcdsaVerificationAlgorithm {
+        id: &ec::AlgorithmID::ECDSA_BRAINPOOL_P384R1,
+        digest: &digest::SHA384,
+        bits: 384,
+        sig_format: EcdsaSignatureFormat::Fixed,
+    };
+
+/// Verification of fixed-length (PKCS#11 style) ECDSA signatures using the brainpoolP512r1 curve and SHA-512.
+pub static ECDSA_BRAINPOOL_P512R1_SHA512_FIXED: EcdsaVerificationAlgorithm =
+    EcdsaVerificationAlgorithm {
+        id: &ec::AlgorithmID::ECDSA_BRAINPOOL_P512R1,
+        digest: &digest::SHA512,
+        bits: 512,
+        sig_format: EcdsaSignatureFormat::Fixed,
+    };
+
 /// Verification of ASN.1 DER-encoded ECDSA signatures using the P-256 curve and SHA-256.
 pub static ECDSA_P256_SHA256_ASN1: EcdsaVerificationAlgorithm = EcdsaVerificationAlgorithm {
     id: &ec::AlgorithmID::ECDSA_P256,
@@ -601,6 +798,31 @@ pub static ECDSA_P256_SHA384_ASN1: EcdsaVerificationAlgorithm = EcdsaVerificatio
     sig_format: EcdsaSignatureFormat::ASN1,
 };
 
+/// *Not recommended.* Verification of ASN.1 DER-encoded ECDSA signatures using the P-256 curve and SHA-1.
+///
+/// SHA-1 is not collision resistant, so a signature verified with this algorithm may have been
+/// made over a different message that has the same digest. Use it only to verify existing legacy
+/// signatures, such as those in old X.509 certificates, and never for new signatures.
+pub static ECDSA_P256_SHA1_FOR_LEGACY_USE_ONLY_ASN1: EcdsaVerificationAlgorithm =
+    EcdsaVerificationAlgorithm {
+        id: &ec::AlgorithmID::ECDSA_P256,
+        digest: &digest::SHA1_FOR_LEGACY_USE_ONLY,
+        bits: 256,
+        sig_format: EcdsaSignatureFormat::ASN1,
+    };
+
+/// *Not recommended.* Verification of ASN.1 DER-encoded ECDSA signatures using the P-384 curve and SHA-1.
+///
+/// See [`ECDSA_P256_SHA1_FOR_LEGACY_USE_ONLY_ASN1`] for why SHA-1 should only be used to verify
+/// existing legacy signatures.
+pub static ECDSA_P384_SHA1_FOR_LEGACY_USE_ONLY_ASN1: EcdsaVerificationAlgorithm =
+    EcdsaVerificationAlgorithm {
+        id: &ec::AlgorithmID::ECDSA_P384,
+        digest: &digest::SHA1_FOR_LEGACY_USE_ONLY,
+        bits: 384,
+        sig_format: EcdsaSignatureFormat::ASN1,
+    };
+
 /// *Not recommended.* Verification of ASN.1 DER-encoded ECDSA signatures using the P-384 curve and SHA-256.
 pub static ECDSA_P384_SHA256_ASN1: EcdsaVerificationAlgorithm = EcdsaVerificationAlgorithm {
     id: &ec::AlgorithmID::ECDSA_P384,
@@ -657,6 +879,33 @@ pub static ECDSA_P256K1_SHA3_256_ASN1: EcdsaVerificationAlgorithm = EcdsaVerific
     sig_format: EcdsaSignatureFormat::ASN1,
 };
 
+/// Verification of ASN.1 DER-encoded ECDSA signatures using the brainpoolP256r1 curve and SHA-256.
+pub static ECDSA_BRAINPOOL_P256R1_SHA256_ASN1: EcdsaVerificationAlgorithm =
+    EcdsaVerificationAlgorithm {
+        id: &ec::AlgorithmID::ECDSA_BRAINPOOL_P256R1,
+        digest: &digest::SHA256,
+        bits: 256,
+        sig_format: EcdsaSignatureFormat::ASN1,
+    };
+
+/// Verification of ASN.1 DER-encoded ECDSA signatures using the brainpoolP384r1 curve and SHA-384.
+pub static ECDSA_BRAINPOOL_P384R1_SHA384_ASN1: EcdsaVerificationAlgorithm =
+    EcdsaVerificationAlgorithm {
+        id: &ec::AlgorithmID::ECDSA_BRAINPOOL_P384R1,
+        digest: &digest::SHA384,
+        bits: 384,
+        sig_format: EcdsaSignatureFormat::ASN1,
+    };
+
+/// Verification of ASN.1 DER-encoded ECDSA signatures using the brainpoolP512r1 curve and SHA-512.
+pub static ECDSA_BRAINPOOL_P512R1_SHA512_ASN1: EcdsaVerificationAlgorithm =
+    EcdsaVerificationAlgorithm {
+        id: &ec::AlgorithmID::ECDSA_BRAINPOOL_P512R1,
+        digest: &digest::SHA512,
+        bits: 512,
+        sig_format: EcdsaSignatureFormat::ASN1,
+    };
+
 /// Signing of fixed-length (PKCS#11 style) ECDSA signatures using the P-256 curve and SHA-256.
 pub static ECDSA_P256_SHA256_FIXED_SIGNING: EcdsaSigningAlgorithm =
     EcdsaSigningAlgorithm(&ECDSA_P256_SHA256_FIXED);
@@ -721,7 +970,12 @@ mod tests {
     use regex::Regex;
 
     use crate::rand::{generate, SystemRandom};
-    use crate::signature::{UnparsedPublicKey, ED25519};
+    use crate::signature::{
+        verify_any, verify_segments, verify_with_keys, EcdsaKeyPair, Ed25519KeyPair, KeyPair,
+        RsaKeyPair, UnparsedPublicKey, VerificationAlgorithm, ECDSA_P256_SHA256_ASN1,
+        ECDSA_P256_SHA256_FIXED, ECDSA_P256_SHA256_FIXED_SIGNING, ECDSA_P384_SHA384_ASN1,
+        ECDSA_P384_SHA384_ASN1_SIGNING, ED25519, RSA_PKCS1_2048_8192_SHA256, RSA_PKCS1_SHA256,
+    };
 
     #[cfg(feature = "fips")]
     mod fips;
@@ -742,4 +996,270 @@ mod tests {
 
         assert!(pubkey_re.is_match(&unparsed_pubkey_debug));
     }
+
+    #[test]
+    fn test_heterogeneous_public_keys() {
+        let rng = SystemRandom::new();
+        let message = b"heterogeneous verification algorithms";
+
+        let mut public_keys: Vec<UnparsedPublicKey<Vec<u8>>> = Vec::new();
+        let mut signatures: Vec<Vec<u8>> = Vec::new();
+
+        let p256 = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_FIXED_SIGNING).unwrap();
+        public_keys.push(UnparsedPublicKey::new(
+            &ECDSA_P256_SHA256_FIXED,
+            p256.public_key().as_ref().to_vec(),
+        ));
+        signatures.push(p256.sign(&rng, message).unwrap().as_ref().to_vec());
+
+        let p384 = EcdsaKeyPair::generate(&ECDSA_P384_SHA384_ASN1_SIGNING).unwrap();
+        public_keys.push(UnparsedPublicKey::new(
+            &ECDSA_P384_SHA384_ASN1,
+            p384.public_key().as_ref().to_vec(),
+        ));
+        signatures.push(p384.sign(&rng, message).unwrap().as_ref().to_vec());
+
+        let ed25519 =
+            Ed25519KeyPair::from_pkcs8(Ed25519KeyPair::generate_pkcs8(&rng).unwrap().as_ref())
+                .unwrap();
+        public_keys.push(UnparsedPublicKey::new(
+            &ED25519,
+            ed25519.public_key().as_ref().to_vec(),
+        ));
+        signatures.push(ed25519.sign(message).as_ref().to_vec());
+
+        let rsa = RsaKeyPair::generate(crate::rsa::KeySize::Rsa2048).unwrap();
+        public_keys.push(UnparsedPublicKey::new(
+            &RSA_PKCS1_2048_8192_SHA256,
+            rsa.public_key().as_ref().to_vec(),
+        ));
+        let mut rsa_signature = vec![0u8; rsa.public_modulus_len()];
+        rsa.sign(&RSA_PKCS1_SHA256, &rng, message, &mut rsa_signature)
+            .unwrap();
+        signatures.push(rsa_signature);
+
+        for (i, public_key) in public_keys.iter().enumerate() {
+            for (j, signature) in signatures.iter().enumerate() {
+                let result = public_key.verify(message, signature);
+                assert_eq!(i == j, result.is_ok(), "{:?}", public_key.algorithm());
+            }
+            assert!(public_key.verify(b"other message", &signatures[i]).is_err());
+        }
+
+        assert_eq!(ed25519.public_key().as_ref(), public_keys[2].as_ref());
+    }
+
+    #[test]
+    fn test_key_pair_generic() {
+        fn public_key_bytes<K: KeyPair>(key_pair: &K) -> Vec<u8> {
+            key_pair.public_key().as_ref().to_vec()
+        }
+
+        let rng = SystemRandom::new();
+
+        let ecdsa = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_FIXED_SIGNING).unwrap();
+        assert_eq!(ecdsa.public_key().as_ref(), public_key_bytes(&ecdsa));
+        assert_eq!(65, public_key_bytes(&ecdsa).len());
+
+        let ed25519 =
+            Ed25519KeyPair::from_pkcs8(Ed25519KeyPair::generate_pkcs8(&rng).unwrap().as_ref())
+                .unwrap();
+        assert_eq!(ed25519.public_key().as_ref(), public_key_bytes(&ed25519));
+        assert_eq!(32, public_key_bytes(&ed25519).len());
+
+        let rsa = RsaKeyPair::generate(crate::rsa::KeySize::Rsa2048).unwrap();
+        assert_eq!(rsa.public_key().as_ref(), public_key_bytes(&rsa));
+    }
+
+    #[test]
+    fn test_verify_segments() {
+        let rng = SystemRandom::new();
+        let segments: [&[u8]; 4] = [b"header", b"", b"body ", b"trailer"];
+        let message = segments.concat();
+
+        let ecdsa = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_FIXED_SIGNING).unwrap();
+        let ecdsa_sig = ecdsa.sign(&rng, &message).unwrap();
+
+        let ed25519 =
+            Ed25519KeyPair::from_pkcs8(Ed25519KeyPair::generate_pkcs8(&rng).unwrap().as_ref())
+                .unwrap();
+        let ed25519_sig = ed25519.sign(&message);
+
+        let rsa = RsaKeyPair::generate(crate::rsa::KeySize::Rsa2048).unwrap();
+        let mut rsa_sig = vec![0u8; rsa.public_modulus_len()];
+        rsa.sign(&RSA_PKCS1_SHA256, &rng, &message, &mut rsa_sig)
+            .unwrap();
+
+        let cases: [(&'static dyn VerificationAlgorithm, &[u8], &[u8]); 3] = [
+            (
+                &ECDSA_P256_SHA256_FIXED,
+                ecdsa.public_key().as_ref(),
+                ecdsa_sig.as_ref(),
+            ),
+            (
+                &ED25519,
+                ed25519.public_key().as_ref(),
+                ed25519_sig.as_ref(),
+            ),
+            (
+                &RSA_PKCS1_2048_8192_SHA256,
+                rsa.public_key().as_ref(),
+                &rsa_sig,
+            ),
+        ];
+        for (alg, public_key, signature) in cases {
+            alg.verify_sig(public_key, &message, signature).unwrap();
+            verify_segments(alg, public_key, &segments, signature).unwrap();
+            verify_segments(alg, public_key, &[&message], signature).unwrap();
+            verify_segments(alg, public_key, &[&message[..3], &message[3..]], signature).unwrap();
+
+            assert!(verify_segments(alg, public_key, &segments[..3], signature).is_err());
+            assert!(verify_segments(alg, public_key, &[], signature).is_err());
+            let reordered: [&[u8]; 3] = [b"body ", b"header", b"trailer"];
+            assert!(verify_segments(alg, public_key, &reordered, signature).is_err());
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[allow(clippy::type_complexity)]
+    #[test]
+    fn test_verify_batch_parallel() {
+        use crate::signature::verify_batch_parallel;
+
+        let rng = SystemRandom::new();
+        let ecdsa = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_FIXED_SIGNING).unwrap();
+        let ed25519 =
+            Ed25519KeyPair::from_pkcs8(Ed25519KeyPair::generate_pkcs8(&rng).unwrap().as_ref())
+                .unwrap();
+
+        let messages: Vec<Vec<u8>> = (0..32u8).map(|i| vec![i; usize::from(i)]).collect();
+        let ecdsa_sigs: Vec<_> = messages
+            .iter()
+            .map(|msg| ecdsa.sign(&rng, msg).unwrap())
+            .collect();
+        let ed25519_sigs: Vec<_> = messages.iter().map(|msg| ed25519.sign(msg)).collect();
+
+        let mut items: Vec<(&'static dyn VerificationAlgorithm, &[u8], &[u8], &[u8])> = Vec::new();
+        for (i, msg) in messages.iter().enumerate() {
+            // Every third item is checked against the wrong message or the wrong key.
+            let other_msg = &messages[(i + 1) % messages.len()];
+            items.push((
+                &ECDSA_P256_SHA256_FIXED,
+                ecdsa.public_key().as_ref(),
+                if i % 3 == 0 { other_msg } else { msg },
+                ecdsa_sigs[i].as_ref(),
+            ));
+            items.push((
+                &ED25519,
+                if i % 3 == 1 {
+                    ecdsa.public_key().as_ref()
+                } else {
+                    ed25519.public_key().as_ref()
+                },
+                msg,
+                ed25519_sigs[i].as_ref(),
+            ));
+        }
+
+        let sequential: Vec<bool> = items
+            .iter()
+            .map(|&(alg, public_key, msg, signature)| {
+                alg.verify_sig(public_key, msg, signature).is_ok()
+            })
+            .collect();
+        assert_eq!(sequential, verify_batch_parallel(&items));
+        assert!(sequential.contains(&true));
+        assert!(sequential.contains(&false));
+
+        assert!(verify_batch_parallel(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_verify_any() {
+        fn same(a: &dyn VerificationAlgorithm, b: &dyn VerificationAlgorithm) -> bool {
+            core::ptr::eq(
+                a as *const dyn VerificationAlgorithm as *const u8,
+                b as *const dyn VerificationAlgorithm as *const u8,
+            )
+        }
+
+        let rng = SystemRandom::new();
+        let message = b"one of several permitted algorithms";
+        let key_pair = EcdsaKeyPair::generate(&ECDSA_P384_SHA384_ASN1_SIGNING).unwrap();
+        let public_key = key_pair.public_key().as_ref();
+        let signature = key_pair.sign(&rng, message).unwrap();
+
+        let algs: [&'static dyn VerificationAlgorithm; 4] = [
+            &ED25519,
+            &ECDSA_P256_SHA256_ASN1,
+            &ECDSA_P384_SHA384_ASN1,
+            &RSA_PKCS1_2048_8192_SHA256,
+        ];
+        let matched = verify_any(&algs, public_key, message, signature.as_ref()).unwrap();
+        assert!(same(&ECDSA_P384_SHA384_ASN1, matched));
+
+        assert!(verify_any(&algs[..2], public_key, message, signature.as_ref()).is_err());
+        assert!(verify_any(&algs, public_key, b"other message", signature.as_ref()).is_err());
+        assert!(verify_any(&[], public_key, message, signature.as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_verify_with_keys() {
+        let rng = SystemRandom::new();
+        let message = b"key rotation";
+
+        let old_key = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_FIXED_SIGNING).unwrap();
+        let new_key = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_FIXED_SIGNING).unwrap();
+        let other_key = EcdsaKeyPair::generate(&ECDSA_P256_SHA256_FIXED_SIGNING).unwrap();
+        let signature = new_key.sign(&rng, message).unwrap();
+        let keys = [
+            old_key.public_key().as_ref(),
+            new_key.public_key().as_ref(),
+            other_key.public_key().as_ref(),
+        ];
+
+        assert_eq!(
+            1,
+            verify_with_keys(&ECDSA_P256_SHA256_FIXED, &keys, message, signature.as_ref()).unwrap()
+        );
+        assert_eq!(
+            0,
+            verify_with_keys(
+                &ECDSA_P256_SHA256_FIXED,
+                &keys[1..],
+                message,
+                signature.as_ref()
+            )
+            .unwrap()
+        );
+        assert!(verify_with_keys(
+            &ECDSA_P256_SHA256_FIXED,
+            &[keys[0], keys[2]],
+            message,
+            signature.as_ref()
+        )
+        .is_err());
+        assert!(verify_with_keys(
+            &ECDSA_P256_SHA256_FIXED,
+            &keys,
+            b"other",
+            signature.as_ref()
+        )
+        .is_err());
+        assert!(
+            verify_with_keys(&ECDSA_P256_SHA256_FIXED, &[], message, signature.as_ref()).is_err()
+        );
+
+        // Malformed candidate keys are skipped.
+        assert_eq!(
+            1,
+            verify_with_keys(
+                &ECDSA_P256_SHA256_FIXED,
+                &[b"not a key", keys[1]],
+                message,
+                signature.as_ref()
+            )
+            .unwrap()
+        );
+    }
 }
@@ -0,0 +1,119 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Verification of COSE ([RFC 9053]) ECDSA signatures.
+//!
+//! COSE identifies algorithms with integers and encodes ECDSA signatures in the fixed-length
+//! `r || s` form. This module maps those identifiers to the matching `ECDSA_*_FIXED`
+//! verification algorithm, e.g. for verifying WebAuthn/FIDO assertions.
+//!
+//! The `msg` passed to [`verify_cose`] is the serialized `Sig_structure` ("ToBeSigned") of the
+//! COSE message; this module does not perform any CBOR encoding or decoding.
+//!
+//! [RFC 9053]: https://www.rfc-editor.org/rfc/rfc9053#section-2.1
+
+use crate::error::Unspecified;
+use crate::signature::{
+    EcdsaVerificationAlgorithm, UnparsedPublicKey, ECDSA_P256_SHA256_FIXED, ECDSA_P384_SHA384_FIXED,
+};
+
+/// The COSE algorithm identifier for ECDSA using P-256 and SHA-256.
+pub const ES256: i64 = -7;
+
+/// The COSE algorithm identifier for ECDSA using P-384 and SHA-384.
+pub const ES384: i64 = -35;
+
+/// Returns the verification algorithm for the COSE algorithm identifier `alg_id`, or `None` if
+/// the identifier is not supported.
+#[must_use]
+pub fn verification_algorithm(alg_id: i64) -> Option<&'static EcdsaVerificationAlgorithm> {
+    match alg_id {
+        ES256 => Some(&ECDSA_P256_SHA256_FIXED),
+        ES384 => Some(&ECDSA_P384_SHA384_FIXED),
+        _ => None,
+    }
+}
+
+/// Verifies the COSE signature `sig` of `msg` using the uncompressed SEC 1 encoded
+/// `public_key`, with the algorithm identified by `alg_id`.
+///
+/// # Errors
+/// `error::Unspecified` if `alg_id` is not supported or the signature is not valid.
+pub fn verify_cose(
+    alg_id: i64,
+    public_key: &[u8],
+    msg: &[u8],
+    sig: &[u8],
+) -> Result<(), Unspecified> {
+    let algorithm = verification_algorithm(alg_id).ok_or(Unspecified)?;
+    UnparsedPublicKey::new(algorithm, public_key).verify(msg, sig)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hex::decode_dirty;
+    use crate::signature::cose::{verification_algorithm, verify_cose, ES256, ES384};
+    use crate::signature::{ECDSA_P256_SHA256_FIXED, ECDSA_P384_SHA384_FIXED};
+
+    // RFC 8152 Appendix C.2.1: COSE_Sign1 with ES256 using key "11".
+    const ES256_PUBLIC_KEY: &str = "04\
+        bac5b11cad8f99f9c72b05cf4b9e26d244dc189f745228255a219a86d6a09eff\
+        20138bf82dc1b6d562be0fa54ab7804a3a64b6d72ccfed6b6fb6ed28bbfc117e";
+    const ES256_TO_BE_SIGNED: &str =
+        "846a5369676e61747572653143a101264054546869732069732074686520636f6e74656e742e";
+    const ES256_SIGNATURE: &str = "\
+        8eb33e4ca31d1c465ab05aac34cc6b23d58fef5c083106c4d25a91aef0b0117e\
+        2af9a291aa32e14ab834dc56ed2a223444547e01f11d3b0916e5a4c345cacb36";
+
+    const ES384_PUBLIC_KEY: &str = "04\
+        6abce72b1cfd0e0a2d2620128dd5c1f9f113f21d19663d2091ca94009376125e\
+        9f2e457b2057bacc80c629164d04749a969eba10683a208350dc00b527b797a0\
+        f05ed0d4e85648410060c518339477102e70829cd7a454fc1fac9913d3d649c1";
+    const ES384_TO_BE_SIGNED: &str =
+        "846a5369676e61747572653144a10138224054546869732069732074686520636f6e74656e742e";
+    const ES384_SIGNATURE: &str = "\
+        6860207233a13e16e80adda17f23a559f61f6c620e4444d54d6709a3dd2f3360\
+        03503b6a516dc94e1f7a04a17d52195e9809a524bea95ccc7fd1c2018e0d3935\
+        d4ab11d6665b675cc189965c439054db7b6058d1e93177fa4b3dfc6be7891c77";
+
+    #[test]
+    fn test_verification_algorithm() {
+        assert_eq!(
+            Some(&ECDSA_P256_SHA256_FIXED),
+            verification_algorithm(ES256)
+        );
+        assert_eq!(
+            Some(&ECDSA_P384_SHA384_FIXED),
+            verification_algorithm(ES384)
+        );
+        // ES512, EdDSA and an unassigned value.
+        for alg_id in [-36, -8, 0] {
+            assert!(verification_algorithm(alg_id).is_none());
+        }
+    }
+
+    #[test]
+    fn test_verify_cose() {
+        for (alg_id, public_key, msg, sig) in [
+            (ES256, ES256_PUBLIC_KEY, ES256_TO_BE_SIGNED, ES256_SIGNATURE),
+            (ES384, ES384_PUBLIC_KEY, ES384_TO_BE_SIGNED, ES384_SIGNATURE),
+        ] {
+            let public_key = decode_dirty(public_key);
+            let msg = decode_dirty(msg);
+            let mut sig = decode_dirty(sig);
+
+            verify_cose(alg_id, &public_key, &msg, &sig).unwrap();
+            assert!(verify_cose(-36, &public_key, &msg, &sig).is_err());
+            assert!(verify_cose(alg_id, &public_key, &msg[1..], &sig).is_err());
+
+            sig[0] ^= 1;
+            assert!(verify_cose(alg_id, &public_key, &msg, &sig).is_err());
+        }
+
+        // The algorithm must match the key.
+        let public_key = decode_dirty(ES256_PUBLIC_KEY);
+        let msg = decode_dirty(ES256_TO_BE_SIGNED);
+        let sig = decode_dirty(ES256_SIGNATURE);
+        assert!(verify_cose(ES384, &public_key, &msg, &sig).is_err());
+    }
+}
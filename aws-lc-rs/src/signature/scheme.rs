@@ -0,0 +1,219 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+use crate::error::Unspecified;
+use crate::signature::{
+    VerificationAlgorithm, ECDSA_P256_SHA256_ASN1, ECDSA_P384_SHA384_ASN1, ECDSA_P521_SHA512_ASN1,
+    ED25519, RSA_PKCS1_2048_8192_SHA256, RSA_PKCS1_2048_8192_SHA384, RSA_PKCS1_2048_8192_SHA512,
+    RSA_PSS_2048_8192_SHA256, RSA_PSS_2048_8192_SHA384, RSA_PSS_2048_8192_SHA512,
+};
+
+/// A TLS `SignatureScheme`, as registered in the IANA
+/// [TLS SignatureScheme registry](https://www.iana.org/assignments/tls-parameters/tls-parameters.xhtml#tls-signaturescheme).
+///
+/// This covers the schemes used for certificate and handshake signatures in TLS 1.2 and
+/// TLS 1.3 that this crate can verify. The wire value of a scheme is obtained with
+/// `u16::from(scheme)` and parsed with `SignatureScheme::try_from(value)`.
+#[allow(non_camel_case_types)]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SignatureScheme {
+    /// `rsa_pkcs1_sha256`
+    RSA_PKCS1_SHA256,
+
+    /// `rsa_pkcs1_sha384`
+    RSA_PKCS1_SHA384,
+
+    /// `rsa_pkcs1_sha512`
+    RSA_PKCS1_SHA512,
+
+    /// `ecdsa_secp256r1_sha256`
+    ECDSA_NISTP256_SHA256,
+
+    /// `ecdsa_secp384r1_sha384`
+    ECDSA_NISTP384_SHA384,
+
+    /// `ecdsa_secp521r1_sha512`
+    ECDSA_NISTP521_SHA512,
+
+    /// `rsa_pss_rsae_sha256`
+    RSA_PSS_SHA256,
+
+    /// `rsa_pss_rsae_sha384`
+    RSA_PSS_SHA384,
+
+    /// `rsa_pss_rsae_sha512`
+    RSA_PSS_SHA512,
+
+    /// `ed25519`
+    ED25519,
+}
+
+impl SignatureScheme {
+    /// The verification algorithm for signatures produced with this scheme.
+    ///
+    /// ECDSA schemes use ASN.1 DER-encoded signatures, as TLS does. Each ECDSA scheme maps to
+    /// the single curve that TLS 1.3 binds it to, e.g. `ecdsa_secp256r1_sha256` (0x0403) only
+    /// verifies P-256 signatures. TLS 1.2 reuses these code points for ECDSA with the given
+    /// hash over any negotiated curve; such signatures must be verified with the algorithm for
+    /// the peer's curve instead.
+    #[must_use]
+    pub fn verification_algorithm(self) -> &'static dyn VerificationAlgorithm {
+        match self {
+            SignatureScheme::RSA_PKCS1_SHA256 => &RSA_PKCS1_2048_8192_SHA256,
+            SignatureScheme::RSA_PKCS1_SHA384 => &RSA_PKCS1_2048_8192_SHA384,
+            SignatureScheme::RSA_PKCS1_SHA512 => &RSA_PKCS1_2048_8192_SHA512,
+            SignatureScheme::ECDSA_NISTP256_SHA256 => &ECDSA_P256_SHA256_ASN1,
+            SignatureScheme::ECDSA_NISTP384_SHA384 => &ECDSA_P384_SHA384_ASN1,
+            SignatureScheme::ECDSA_NISTP521_SHA512 => &ECDSA_P521_SHA512_ASN1,
+            SignatureScheme::RSA_PSS_SHA256 => &RSA_PSS_2048_8192_SHA256,
+            SignatureScheme::RSA_PSS_SHA384 => &RSA_PSS_2048_8192_SHA384,
+            SignatureScheme::RSA_PSS_SHA512 => &RSA_PSS_2048_8192_SHA512,
+            SignatureScheme::ED25519 => &ED25519,
+        }
+    }
+
+    /// Whether this scheme may be used for `CertificateVerify` signatures in TLS 1.3.
+    ///
+    /// RSA PKCS#1 v1.5 schemes are only permitted in certificate signatures in TLS 1.3. For
+    /// ECDSA schemes this assumes the TLS 1.3 curve binding described in
+    /// [`Self::verification_algorithm`].
+    #[must_use]
+    pub fn supported_in_tls13(self) -> bool {
+        !matches!(
+            self,
+            SignatureScheme::RSA_PKCS1_SHA256
+                | SignatureScheme::RSA_PKCS1_SHA384
+                | SignatureScheme::RSA_PKCS1_SHA512
+        )
+    }
+}
+
+impl From<SignatureScheme> for u16 {
+    fn from(scheme: SignatureScheme) -> Self {
+        match scheme {
+            SignatureScheme::RSA_PKCS1_SHA256 => 0x0401,
+            SignatureScheme::RSA_PKCS1_SHA384 => 0x0501,
+            SignatureScheme::RSA_PKCS1_SHA512 => 0x0601,
+            SignatureScheme::ECDSA_NISTP256_SHA256 => 0x0403,
+            SignatureScheme::ECDSA_NISTP384_SHA384 => 0x0503,
+            SignatureScheme::ECDSA_NISTP521_SHA512 => 0x0603,
+            SignatureScheme::RSA_PSS_SHA256 => 0x0804,
+            SignatureScheme::RSA_PSS_SHA384 => 0x0805,
+            SignatureScheme::RSA_PSS_SHA512 => 0x0806,
+            SignatureScheme::ED25519 => 0x0807,
+        }
+    }
+}
+
+impl TryFrom<u16> for SignatureScheme {
+    type Error = Unspecified;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0x0401 => SignatureScheme::RSA_PKCS1_SHA256,
+            0x0501 => SignatureScheme::RSA_PKCS1_SHA384,
+            0x0601 => SignatureScheme::RSA_PKCS1_SHA512,
+            0x0403 => SignatureScheme::ECDSA_NISTP256_SHA256,
+            0x0503 => SignatureScheme::ECDSA_NISTP384_SHA384,
+            0x0603 => SignatureScheme::ECDSA_NISTP521_SHA512,
+            0x0804 => SignatureScheme::RSA_PSS_SHA256,
+            0x0805 => SignatureScheme::RSA_PSS_SHA384,
+            0x0806 => SignatureScheme::RSA_PSS_SHA512,
+            0x0807 => SignatureScheme::ED25519,
+            _ => return Err(Unspecified),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rand::SystemRandom;
+    use crate::rsa::KeySize;
+    use crate::signature::{
+        EcdsaKeyPair, Ed25519KeyPair, KeyPair, RsaEncoding, RsaKeyPair, SignatureScheme,
+        UnparsedPublicKey, ECDSA_P256_SHA256_ASN1_SIGNING, ECDSA_P384_SHA384_ASN1_SIGNING,
+        ECDSA_P521_SHA512_ASN1_SIGNING, RSA_PSS_SHA256, RSA_PSS_SHA384, RSA_PSS_SHA512,
+    };
+
+    const TLS13_SCHEMES: [(SignatureScheme, u16); 7] = [
+        (SignatureScheme::ECDSA_NISTP256_SHA256, 0x0403),
+        (SignatureScheme::ECDSA_NISTP384_SHA384, 0x0503),
+        (SignatureScheme::ECDSA_NISTP521_SHA512, 0x0603),
+        (SignatureScheme::RSA_PSS_SHA256, 0x0804),
+        (SignatureScheme::RSA_PSS_SHA384, 0x0805),
+        (SignatureScheme::RSA_PSS_SHA512, 0x0806),
+        (SignatureScheme::ED25519, 0x0807),
+    ];
+
+    #[test]
+    fn test_wire_values() {
+        for (scheme, value) in TLS13_SCHEMES {
+            assert!(scheme.supported_in_tls13());
+            assert_eq!(value, u16::from(scheme));
+            assert_eq!(Ok(scheme), SignatureScheme::try_from(value));
+        }
+        for (scheme, value) in [
+            (SignatureScheme::RSA_PKCS1_SHA256, 0x0401),
+            (SignatureScheme::RSA_PKCS1_SHA384, 0x0501),
+            (SignatureScheme::RSA_PKCS1_SHA512, 0x0601),
+        ] {
+            assert!(!scheme.supported_in_tls13());
+            assert_eq!(value, u16::from(scheme));
+            assert_eq!(Ok(scheme), SignatureScheme::try_from(value));
+        }
+        // rsa_pkcs1_sha1, ecdsa_sha1, rsa_pss_pss_sha256, ed448
+        for value in [0x0201, 0x0203, 0x0809, 0x0808] {
+            assert!(SignatureScheme::try_from(value).is_err());
+        }
+    }
+
+    #[test]
+    fn test_tls13_verification_algorithms() {
+        let rng = SystemRandom::new();
+        let message = b"TLS 1.3, server CertificateVerify";
+        let rsa = RsaKeyPair::generate(KeySize::Rsa2048).unwrap();
+
+        for (scheme, _) in TLS13_SCHEMES {
+            let (public_key, signature) = match scheme {
+                SignatureScheme::ECDSA_NISTP256_SHA256
+                | SignatureScheme::ECDSA_NISTP384_SHA384
+                | SignatureScheme::ECDSA_NISTP521_SHA512 => {
+                    let alg = match scheme {
+                        SignatureScheme::ECDSA_NISTP256_SHA256 => &ECDSA_P256_SHA256_ASN1_SIGNING,
+                        SignatureScheme::ECDSA_NISTP384_SHA384 => &ECDSA_P384_SHA384_ASN1_SIGNING,
+                        _ => &ECDSA_P521_SHA512_ASN1_SIGNING,
+                    };
+                    let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+                    let signature = key_pair.sign(&rng, message).unwrap();
+                    (
+                        key_pair.public_key().as_ref().to_vec(),
+                        signature.as_ref().to_vec(),
+                    )
+                }
+                SignatureScheme::ED25519 => {
+                    let pkcs8 = Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+                    let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+                    (
+                        key_pair.public_key().as_ref().to_vec(),
+                        key_pair.sign(message).as_ref().to_vec(),
+                    )
+                }
+                _ => {
+                    let encoding: &'static dyn RsaEncoding = match scheme {
+                        SignatureScheme::RSA_PSS_SHA256 => &RSA_PSS_SHA256,
+                        SignatureScheme::RSA_PSS_SHA384 => &RSA_PSS_SHA384,
+                        _ => &RSA_PSS_SHA512,
+                    };
+                    let mut signature = vec![0u8; rsa.public_modulus_len()];
+                    rsa.sign(encoding, &rng, message, &mut signature).unwrap();
+                    (rsa.public_key().as_ref().to_vec(), signature)
+                }
+            };
+
+            let public_key = UnparsedPublicKey::new(scheme.verification_algorithm(), public_key);
+            public_key.verify(message, &signature).unwrap();
+            assert!(public_key.verify(b"tampered", &signature).is_err());
+        }
+    }
+}
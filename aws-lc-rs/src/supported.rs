@@ -0,0 +1,195 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+//! Runtime queries for the algorithms that this crate exposes.
+
+use crate::try_fips_mode;
+
+/// Expands to a list of the names of the given items of `crate::$module`, checking at compile
+/// time that each item exists.
+macro_rules! algorithm_names {
+    ($module:ident: $($name:ident),* $(,)?) => {
+        &[$({
+            #[allow(unused_imports)]
+            use crate::$module::$name;
+            stringify!($name)
+        }),*]
+    };
+}
+
+static SIGNATURE_ALGORITHMS: &[&str] = algorithm_names!(signature:
+    ECDSA_P256_SHA256_ASN1,
+    ECDSA_P256_SHA256_FIXED,
+    ECDSA_P256_SHA384_ASN1,
+    ECDSA_P256_SHA1_FOR_LEGACY_USE_ONLY_ASN1,
+    ECDSA_P384_SHA256_ASN1,
+    ECDSA_P384_SHA384_ASN1,
+    ECDSA_P384_SHA384_FIXED,
+    ECDSA_P384_SHA1_FOR_LEGACY_USE_ONLY_ASN1,
+    ECDSA_P384_SHA3_384_ASN1,
+    ECDSA_P384_SHA3_384_FIXED,
+    ECDSA_P521_SHA512_ASN1,
+    ECDSA_P521_SHA512_FIXED,
+    ECDSA_P521_SHA3_512_ASN1,
+    ECDSA_P521_SHA3_512_FIXED,
+    ECDSA_P256K1_SHA256_ASN1,
+    ECDSA_P256K1_SHA256_FIXED,
+    ECDSA_P256K1_SHA3_256_ASN1,
+    ECDSA_P256K1_SHA3_256_FIXED,
+    ECDSA_BRAINPOOL_P256R1_SHA256_ASN1,
+    ECDSA_BRAINPOOL_P256R1_SHA256_FIXED,
+    ECDSA_BRAINPOOL_P384R1_SHA384_ASN1,
+    ECDSA_BRAINPOOL_P384R1_SHA384_FIXED,
+    ECDSA_BRAINPOOL_P512R1_SHA512_ASN1,
+    ECDSA_BRAINPOOL_P512R1_SHA512_FIXED,
+    ED25519,
+    RSA_PKCS1_1024_8192_SHA1_FOR_LEGACY_USE_ONLY,
+    RSA_PKCS1_1024_8192_SHA256_FOR_LEGACY_USE_ONLY,
+    RSA_PKCS1_1024_8192_SHA512_FOR_LEGACY_USE_ONLY,
+    RSA_PKCS1_2048_8192_SHA1_FOR_LEGACY_USE_ONLY,
+    RSA_PKCS1_2048_8192_SHA256,
+    RSA_PKCS1_2048_8192_SHA384,
+    RSA_PKCS1_2048_8192_SHA512,
+    RSA_PKCS1_3072_8192_SHA384,
+    RSA_PSS_2048_8192_SHA256,
+    RSA_PSS_2048_8192_SHA384,
+    RSA_PSS_2048_8192_SHA512,
+);
+
+/// `SIGNATURE_ALGORITHMS` without Ed25519, secp256k1, the Brainpool curves, and ECDSA verification
+/// with SHA-3, which are not approved services of the *AWS-LC* FIPS module.
+static FIPS_SIGNATURE_ALGORITHMS: &[&str] = algorithm_names!(signature:
+    ECDSA_P256_SHA256_ASN1,
+    ECDSA_P256_SHA256_FIXED,
+    ECDSA_P256_SHA384_ASN1,
+    ECDSA_P256_SHA1_FOR_LEGACY_USE_ONLY_ASN1,
+    ECDSA_P384_SHA256_ASN1,
+    ECDSA_P384_SHA384_ASN1,
+    ECDSA_P384_SHA384_FIXED,
+    ECDSA_P384_SHA1_FOR_LEGACY_USE_ONLY_ASN1,
+    ECDSA_P521_SHA512_ASN1,
+    ECDSA_P521_SHA512_FIXED,
+    RSA_PKCS1_1024_8192_SHA1_FOR_LEGACY_USE_ONLY,
+    RSA_PKCS1_1024_8192_SHA256_FOR_LEGACY_USE_ONLY,
+    RSA_PKCS1_1024_8192_SHA512_FOR_LEGACY_USE_ONLY,
+    RSA_PKCS1_2048_8192_SHA1_FOR_LEGACY_USE_ONLY,
+    RSA_PKCS1_2048_8192_SHA256,
+    RSA_PKCS1_2048_8192_SHA384,
+    RSA_PKCS1_2048_8192_SHA512,
+    RSA_PKCS1_3072_8192_SHA384,
+    RSA_PSS_2048_8192_SHA256,
+    RSA_PSS_2048_8192_SHA384,
+    RSA_PSS_2048_8192_SHA512,
+);
+
+static AEAD_ALGORITHMS: &[&str] = algorithm_names!(aead:
+    AES_128_GCM,
+    AES_256_GCM,
+    AES_128_GCM_TAG_96,
+    AES_256_GCM_TAG_96,
+    AES_128_GCM_TAG_64,
+    AES_256_GCM_TAG_64,
+    AES_128_GCM_SIV,
+    AES_256_GCM_SIV,
+    CHACHA20_POLY1305,
+);
+
+/// `AEAD_ALGORITHMS` without AES-GCM-SIV and ChaCha20-Poly1305, which are not approved services
+/// of the *AWS-LC* FIPS module.
+static FIPS_AEAD_ALGORITHMS: &[&str] = algorithm_names!(aead:
+    AES_128_GCM,
+    AES_256_GCM,
+    AES_128_GCM_TAG_96,
+    AES_256_GCM_TAG_96,
+    AES_128_GCM_TAG_64,
+    AES_256_GCM_TAG_64,
+);
+
+/// Every digest algorithm is an approved service of the *AWS-LC* FIPS module.
+static DIGEST_ALGORITHMS: &[&str] = algorithm_names!(digest:
+    SHA1_FOR_LEGACY_USE_ONLY,
+    SHA224,
+    SHA256,
+    SHA384,
+    SHA512,
+    SHA512_256,
+    SHA3_256,
+    SHA3_384,
+    SHA3_512,
+);
+
+/// Returns the names of the signature verification algorithms that can be used, as named by
+/// their constants in the `signature` module (e.g. `"ECDSA_P256_SHA256_ASN1"`).
+///
+/// When the underlying implementation is in FIPS mode (see `try_fips_mode`), only the algorithms
+/// that are approved services of the FIPS module are returned.
+#[must_use]
+pub fn supported_signature_algorithms() -> &'static [&'static str] {
+    if try_fips_mode().is_ok() {
+        FIPS_SIGNATURE_ALGORITHMS
+    } else {
+        SIGNATURE_ALGORITHMS
+    }
+}
+
+/// Returns the names of the AEAD algorithms that can be used, as named by their constants in
+/// the `aead` module (e.g. `"AES_128_GCM"`).
+///
+/// When the underlying implementation is in FIPS mode (see `try_fips_mode`), only the algorithms
+/// that are approved services of the FIPS module are returned.
+#[must_use]
+pub fn supported_aead_algorithms() -> &'static [&'static str] {
+    if try_fips_mode().is_ok() {
+        FIPS_AEAD_ALGORITHMS
+    } else {
+        AEAD_ALGORITHMS
+    }
+}
+
+/// Returns the names of the digest algorithms that can be used, as named by their constants in
+/// the `digest` module (e.g. `"SHA256"`).
+#[must_use]
+pub fn supported_digest_algorithms() -> &'static [&'static str] {
+    DIGEST_ALGORITHMS
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::supported::{
+        AEAD_ALGORITHMS, FIPS_AEAD_ALGORITHMS, FIPS_SIGNATURE_ALGORITHMS, SIGNATURE_ALGORITHMS,
+    };
+    use crate::{
+        supported_aead_algorithms, supported_digest_algorithms, supported_signature_algorithms,
+        try_fips_mode,
+    };
+
+    #[test]
+    fn test_supported_algorithms() {
+        let signature_algorithms = supported_signature_algorithms();
+        let aead_algorithms = supported_aead_algorithms();
+        assert!(signature_algorithms.contains(&"ECDSA_P256_SHA256_ASN1"));
+        assert!(aead_algorithms.contains(&"AES_256_GCM"));
+        assert!(supported_digest_algorithms().contains(&"SHA256"));
+
+        if try_fips_mode().is_ok() {
+            assert!(!signature_algorithms.contains(&"ED25519"));
+            assert!(!signature_algorithms.contains(&"ECDSA_P256K1_SHA256_ASN1"));
+            assert!(!aead_algorithms.contains(&"CHACHA20_POLY1305"));
+        } else {
+            assert!(signature_algorithms.contains(&"ED25519"));
+            assert!(aead_algorithms.contains(&"CHACHA20_POLY1305"));
+        }
+    }
+
+    #[test]
+    fn test_fips_algorithms_are_subsets() {
+        for (fips, all) in [
+            (FIPS_SIGNATURE_ALGORITHMS, SIGNATURE_ALGORITHMS),
+            (FIPS_AEAD_ALGORITHMS, AEAD_ALGORITHMS),
+        ] {
+            assert!(!fips.is_empty());
+            assert!(fips.len() < all.len());
+            assert!(fips.iter().all(|name| all.contains(name)));
+        }
+    }
+}
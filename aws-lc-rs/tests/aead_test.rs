@@ -3,7 +3,7 @@
 // Modifications copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
-use aws_lc_rs::{aead, error, test, test_file};
+use aws_lc_rs::{aead, error, hkdf, test, test_file};
 
 use aws_lc_rs::aead::{Nonce, NONCE_LEN};
 use core::ops::RangeFrom;
@@ -553,16 +553,276 @@ fn aead_chacha20_poly1305_openssh() {
     );
 }
 
+#[test]
+fn test_aead_recommended_algorithm() {
+    let alg = aead::recommended_algorithm();
+    if aws_lc_rs::has_aes_hardware() {
+        assert_eq!(&aead::AES_256_GCM, alg);
+    } else {
+        assert_eq!(&aead::CHACHA20_POLY1305, alg);
+    }
+
+    let key = aead::LessSafeKey::new(aead::UnboundKey::new(alg, &[0u8; 32]).unwrap());
+    let mut in_out = b"hello, world".to_vec();
+    key.seal_in_place_append_tag(
+        Nonce::assume_unique_for_key([0u8; NONCE_LEN]),
+        aead::Aad::empty(),
+        &mut in_out,
+    )
+    .unwrap();
+    let plaintext = key
+        .open_in_place(
+            Nonce::assume_unique_for_key([0u8; NONCE_LEN]),
+            aead::Aad::empty(),
+            &mut in_out,
+        )
+        .unwrap();
+    assert_eq!(b"hello, world", plaintext);
+}
+
+#[test]
+fn test_aead_less_safe_key_seal_open() {
+    for alg in [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        &aead::AES_256_GCM_SIV,
+        &aead::CHACHA20_POLY1305,
+    ] {
+        let key_bytes = vec![0x42u8; alg.key_len()];
+        let key = aead::LessSafeKey::new(aead::UnboundKey::new(alg, &key_bytes).unwrap());
+        let nonce = || Nonce::assume_unique_for_key([7u8; NONCE_LEN]);
+
+        for plaintext in [&b""[..], b"hello, world", &[0xa5u8; 100]] {
+            let sealed = key
+                .seal(nonce(), aead::Aad::from(b"aad"), plaintext)
+                .unwrap();
+            assert_eq!(plaintext.len() + alg.tag_len(), sealed.len());
+
+            // The result matches the in-place API.
+            let mut in_out = plaintext.to_vec();
+            key.seal_in_place_append_tag(nonce(), aead::Aad::from(b"aad"), &mut in_out)
+                .unwrap();
+            assert_eq!(in_out, sealed);
+
+            let opened = key.open(nonce(), aead::Aad::from(b"aad"), &sealed).unwrap();
+            assert_eq!(plaintext, opened.as_slice());
+
+            assert!(key.open(nonce(), aead::Aad::from(b"aaa"), &sealed).is_err());
+            assert!(key
+                .open(nonce(), aead::Aad::from(b"aad"), &sealed[1..])
+                .is_err());
+        }
+    }
+}
+
+#[test]
+fn test_aead_empty_aad() {
+    const NO_AAD: aead::Aad<[u8; 0]> = aead::Aad::empty();
+    assert!(NO_AAD.as_ref().is_empty());
+
+    for alg in [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        &aead::AES_256_GCM_SIV,
+        &aead::CHACHA20_POLY1305,
+    ] {
+        let key =
+            aead::LessSafeKey::new(aead::UnboundKey::new(alg, &vec![1u8; alg.key_len()]).unwrap());
+        let nonce = || Nonce::assume_unique_for_key([7u8; NONCE_LEN]);
+
+        let sealed = key
+            .seal(nonce(), aead::Aad::empty(), b"hello, world")
+            .unwrap();
+        // An empty AAD is the same as a zero-length slice.
+        assert_eq!(
+            sealed,
+            key.seal(nonce(), aead::Aad::from(&[][..]), b"hello, world")
+                .unwrap()
+        );
+        let opened = key.open(nonce(), aead::Aad::empty(), &sealed).unwrap();
+        assert_eq!(b"hello, world", opened.as_slice());
+
+        // A one-byte AAD authenticates differently from an empty one.
+        let sealed_one_byte = key
+            .seal(nonce(), aead::Aad::from([0u8]), b"hello, world")
+            .unwrap();
+        assert_ne!(sealed, sealed_one_byte);
+        assert!(key.open(nonce(), aead::Aad::from([0u8]), &sealed).is_err());
+        assert!(key
+            .open(nonce(), aead::Aad::empty(), &sealed_one_byte)
+            .is_err());
+    }
+}
+
+#[test]
+fn test_aead_integer_aad() {
+    assert_eq!([0, 0, 1, 0], aead::Aad::from_u32_be(256).as_ref());
+    assert_eq!(
+        [0, 0, 0, 0, 0, 0, 1, 0],
+        aead::Aad::from_u64_be(256).as_ref()
+    );
+
+    for alg in [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        &aead::AES_256_GCM_SIV,
+        &aead::CHACHA20_POLY1305,
+    ] {
+        let key =
+            aead::LessSafeKey::new(aead::UnboundKey::new(alg, &vec![1u8; alg.key_len()]).unwrap());
+        let nonce = || Nonce::assume_unique_for_key([7u8; NONCE_LEN]);
+
+        // Sealing with the helper authenticates the same bytes as passing them manually.
+        let sealed = key
+            .seal(nonce(), aead::Aad::from_u32_be(256), b"hello, world")
+            .unwrap();
+        assert_eq!(
+            sealed,
+            key.seal(nonce(), aead::Aad::from([0u8, 0, 1, 0]), b"hello, world")
+                .unwrap()
+        );
+        key.open(nonce(), aead::Aad::from(256u32.to_be_bytes()), &sealed)
+            .unwrap();
+        assert!(key
+            .open(nonce(), aead::Aad::from_u32_be(257), &sealed)
+            .is_err());
+        // The width of the integer is authenticated too.
+        assert!(key
+            .open(nonce(), aead::Aad::from_u64_be(256), &sealed)
+            .is_err());
+
+        let sealed = key
+            .seal(nonce(), aead::Aad::from_u64_be(256), b"hello, world")
+            .unwrap();
+        key.open(nonce(), aead::Aad::from(256u64.to_be_bytes()), &sealed)
+            .unwrap();
+    }
+}
+
+#[test]
+fn test_aead_less_safe_key_open_in_place_checked() {
+    for alg in [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        &aead::AES_256_GCM_SIV,
+        &aead::CHACHA20_POLY1305,
+    ] {
+        let key =
+            aead::LessSafeKey::new(aead::UnboundKey::new(alg, &vec![1u8; alg.key_len()]).unwrap());
+        let wrong_key =
+            aead::LessSafeKey::new(aead::UnboundKey::new(alg, &vec![2u8; alg.key_len()]).unwrap());
+        let nonce = || Nonce::assume_unique_for_key([7u8; NONCE_LEN]);
+
+        let sealed = key
+            .seal(nonce(), aead::Aad::from(b"aad"), b"hello, world")
+            .unwrap();
+        let mut in_out = sealed.clone();
+
+        // A failed open leaves the ciphertext intact, so it can be retried.
+        assert!(wrong_key
+            .open_in_place_checked(nonce(), aead::Aad::from(b"aad"), &mut in_out)
+            .is_err());
+        assert_eq!(sealed, in_out);
+        assert!(key
+            .open_in_place_checked(nonce(), aead::Aad::from(b"aaa"), &mut in_out)
+            .is_err());
+        assert_eq!(sealed, in_out);
+        assert!(key
+            .open_in_place_checked(nonce(), aead::Aad::from(b"aad"), &mut in_out[..3])
+            .is_err());
+        assert_eq!(sealed, in_out);
+
+        let plaintext = key
+            .open_in_place_checked(nonce(), aead::Aad::from(b"aad"), &mut in_out)
+            .unwrap();
+        assert_eq!(b"hello, world", plaintext);
+    }
+}
+
+#[test]
+fn test_aead_derive_traffic_key() {
+    // RFC 8448, Section 3: the server handshake traffic key of the simple 1-RTT handshake.
+    let secret = test::from_dirty_hex(
+        "b6 7b 7d 69 0c c1 6c 4e 75 e5 42 13 cb 2d 37 b4
+         e9 c9 12 bc de d9 10 5d 42 be fd 59 d3 91 ad 38",
+    );
+    let expected_key = test::from_dirty_hex("3f ce 51 60 09 c2 17 27 d0 f2 e4 e8 6e e4 03 bc");
+    let expected_iv = test::from_dirty_hex("5d 31 3e b2 67 12 76 ee 13 00 0b 30");
+
+    let prk = hkdf::Prk::new_less_safe(hkdf::HKDF_SHA256, &secret);
+    let (key, iv) = aead::derive_traffic_key(&aead::AES_128_GCM, &prk).unwrap();
+    assert_eq!(&aead::AES_128_GCM, key.algorithm());
+    assert_eq!(expected_iv, iv.as_ref());
+
+    // The derived key seals identically to one constructed from the expected key bytes.
+    let expected =
+        aead::LessSafeKey::new(aead::UnboundKey::new(&aead::AES_128_GCM, &expected_key).unwrap());
+    let key = aead::LessSafeKey::new(key);
+    let nonce = || Nonce::assume_unique_for_key([0u8; NONCE_LEN]);
+    assert_eq!(
+        expected
+            .seal(nonce(), aead::Aad::empty(), b"hello, world")
+            .unwrap(),
+        key.seal(nonce(), aead::Aad::empty(), b"hello, world")
+            .unwrap()
+    );
+
+    let mut out = [0u8; 16];
+    prk.expand_label(b"key", &[], &mut out).unwrap();
+    assert_eq!(expected_key, out);
+    assert!(prk.expand_label(&[b'a'; 250], &[], &mut out).is_err());
+    assert!(prk.expand_label(b"key", &[0u8; 256], &mut out).is_err());
+}
+
 #[test]
 fn test_aead_traits() {
     test::compile_time_assert_send::<aead::Tag>();
     test::compile_time_assert_sync::<aead::Tag>();
+    test::compile_time_assert_eq::<aead::Tag>();
     test::compile_time_assert_send::<aead::UnboundKey>();
     test::compile_time_assert_sync::<aead::UnboundKey>();
     test::compile_time_assert_send::<aead::LessSafeKey>();
     test::compile_time_assert_sync::<aead::LessSafeKey>();
 }
 
+#[test]
+fn test_aead_tag_eq() {
+    let key = aead::LessSafeKey::new(
+        aead::UnboundKey::new(&aead::AES_128_GCM, b"this is a test! ").unwrap(),
+    );
+    let seal = |nonce: u8, plaintext: &[u8]| {
+        let mut in_out = plaintext.to_vec();
+        key.seal_in_place_separate_tag(
+            Nonce::try_assume_unique_for_key(&[nonce; NONCE_LEN]).unwrap(),
+            aead::Aad::empty(),
+            &mut in_out,
+        )
+        .unwrap()
+    };
+
+    let tag = seal(0, b"message");
+    assert!(tag == seal(0, b"message"));
+    assert!(tag != seal(0, b"massage"));
+    assert!(tag != seal(1, b"message"));
+
+    // Tags of different lengths never compare equal.
+    let truncated_key = aead::LessSafeKey::new(
+        aead::UnboundKey::new(&aead::AES_128_GCM_TAG_96, b"this is a test! ").unwrap(),
+    );
+    let truncated_tag = truncated_key
+        .seal_in_place_separate_tag(
+            Nonce::try_assume_unique_for_key(&[0; NONCE_LEN]).unwrap(),
+            aead::Aad::empty(),
+            &mut b"message".to_vec(),
+        )
+        .unwrap();
+    assert_eq!(
+        truncated_tag.as_ref(),
+        &tag.as_ref()[..truncated_tag.as_ref().len()]
+    );
+    assert!(tag != truncated_tag);
+}
+
 #[test]
 fn test_aead_thread_safeness() {
     lazy_static::lazy_static! {
@@ -600,6 +860,123 @@ fn test_aead_thread_safeness() {
     }
 }
 
+#[test]
+fn test_aead_unbound_key_try_clone_thread_safeness() {
+    use std::thread;
+
+    let key_bytes = [0x42; 32];
+    let key = aead::UnboundKey::new(&aead::AES_256_GCM, &key_bytes).unwrap();
+
+    let mut join_handles = Vec::new();
+    for i in 0..16u8 {
+        let key = key.try_clone().unwrap();
+        let join_handle = thread::spawn(move || {
+            let key = aead::LessSafeKey::new(key);
+            let mut nonce = [0u8; NONCE_LEN];
+            nonce[0] = i;
+            let mut in_out = vec![i; 64];
+            key.seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                aead::Aad::from([i]),
+                &mut in_out,
+            )
+            .unwrap();
+            (nonce, in_out)
+        });
+        join_handles.push(join_handle);
+    }
+
+    let opening_key = aead::LessSafeKey::new(key);
+    for (i, handle) in (0..16u8).zip(join_handles) {
+        let (nonce, mut in_out) = handle.join().unwrap();
+        let plaintext = opening_key
+            .open_in_place(
+                Nonce::assume_unique_for_key(nonce),
+                aead::Aad::from([i]),
+                &mut in_out,
+            )
+            .unwrap();
+        assert_eq!(&[i; 64], plaintext);
+    }
+}
+
+#[test]
+fn test_aead_less_safe_key_reuse() {
+    for (algorithm, key_bytes) in [
+        (&aead::AES_128_GCM, &[0x42; 16][..]),
+        (&aead::AES_256_GCM, &[0x42; 32][..]),
+        (&aead::CHACHA20_POLY1305, &[0x42; 32][..]),
+    ] {
+        let key = make_less_safe_key(algorithm, key_bytes);
+        for i in 0..64u8 {
+            let mut nonce = [0u8; NONCE_LEN];
+            nonce[NONCE_LEN - 1] = i;
+            let plaintext = vec![i; usize::from(i)];
+
+            // A key reused across messages produces the same output as a new key per message.
+            let mut in_out = plaintext.clone();
+            key.seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                aead::Aad::from([i]),
+                &mut in_out,
+            )
+            .unwrap();
+            let mut expected = plaintext.clone();
+            make_less_safe_key(algorithm, key_bytes)
+                .seal_in_place_append_tag(
+                    Nonce::assume_unique_for_key(nonce),
+                    aead::Aad::from([i]),
+                    &mut expected,
+                )
+                .unwrap();
+            assert_eq!(expected, in_out);
+
+            let opened = key
+                .open_in_place(
+                    Nonce::assume_unique_for_key(nonce),
+                    aead::Aad::from([i]),
+                    &mut in_out,
+                )
+                .unwrap();
+            assert_eq!(plaintext, opened);
+        }
+    }
+}
+
+#[test]
+fn test_aead_seal_with_random_nonce() {
+    for (algorithm, key_bytes) in [
+        (&aead::AES_128_GCM, &[0x42; 16][..]),
+        (&aead::AES_256_GCM, &[0x42; 32][..]),
+        (&aead::CHACHA20_POLY1305, &[0x42; 32][..]),
+    ] {
+        let sealing_key: aead::SealingKey<OneNonceSequence> = make_key(
+            algorithm,
+            key_bytes,
+            Nonce::assume_unique_for_key([0; NONCE_LEN]),
+        );
+        let opening_key = make_less_safe_key(algorithm, key_bytes);
+
+        let mut first = b"one-shot message".to_vec();
+        let first_nonce = sealing_key
+            .seal_with_random_nonce(aead::Aad::from(b"aad"), &mut first)
+            .unwrap();
+        let mut second = b"one-shot message".to_vec();
+        let second_nonce = sealing_key
+            .seal_with_random_nonce(aead::Aad::from(b"aad"), &mut second)
+            .unwrap();
+        assert_ne!(first_nonce.as_ref(), second_nonce.as_ref());
+        assert_ne!(first, second);
+
+        for (nonce, in_out) in [(first_nonce, &mut first), (second_nonce, &mut second)] {
+            let plaintext = opening_key
+                .open_in_place(nonce, aead::Aad::from(b"aad"), in_out)
+                .unwrap();
+            assert_eq!(b"one-shot message", plaintext);
+        }
+    }
+}
+
 #[test]
 fn test_aead_key_debug() {
     let key_bytes = [0; 32];
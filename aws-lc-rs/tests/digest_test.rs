@@ -280,6 +280,7 @@ test_i_u_f!(digest_test_i_u_f_sha224, digest::SHA224);
 test_i_u_f!(digest_test_i_u_f_sha256, digest::SHA256);
 test_i_u_f!(digest_test_i_u_f_sha384, digest::SHA384);
 test_i_u_f!(digest_test_i_u_f_sha512, digest::SHA512);
+test_i_u_f!(digest_test_i_u_f_sha512_256, digest::SHA512_256);
 
 /// See <https://bugzilla.mozilla.org/show_bug.cgi?id=610162/>. This tests the
 /// calculation of 8GB of the byte 123.
@@ -383,8 +384,53 @@ test_large_digest!(
     ]
 );
 
-// TODO: test_large_digest!(digest_test_large_digest_sha512_256,
-//                            digest::SHA512_256, 256 / 8, [ ... ]);
+test_large_digest!(
+    digest_test_large_digest_sha512_256,
+    digest::SHA512_256,
+    256 / 8,
+    [
+        0x32, 0xCB, 0xB8, 0x5A, 0x5E, 0x66, 0x05, 0xA3, 0xB9, 0x4C, 0x99, 0x07, 0xB6, 0xE4, 0xFC,
+        0xC6, 0x00, 0x1B, 0x05, 0x91, 0x1C, 0xAE, 0xB0, 0x7F, 0xB0, 0xD3, 0x0E, 0xA9, 0x81, 0x7C,
+        0xDC, 0x62
+    ]
+);
+
+#[test]
+fn test_from_nid_and_oid() {
+    const SHA256_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01];
+    const SHA384_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02];
+    const SHA512_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03];
+    const SHA1_OID: &[u8] = &[0x2b, 0x0e, 0x03, 0x02, 0x1a];
+
+    assert_eq!(Some(&digest::SHA256), digest::from_oid(SHA256_OID));
+    assert_eq!(Some(&digest::SHA384), digest::from_oid(SHA384_OID));
+    assert_eq!(Some(&digest::SHA512), digest::from_oid(SHA512_OID));
+    assert_eq!(None, digest::from_oid(SHA1_OID));
+    assert_eq!(None, digest::from_oid(&SHA256_OID[..8]));
+    assert_eq!(None, digest::from_oid(&[SHA256_OID, &[0x00]].concat()));
+    assert_eq!(None, digest::from_oid(&[]));
+
+    // NID_sha256, NID_sha384 and NID_sha512.
+    assert_eq!(Some(&digest::SHA256), digest::from_nid(672));
+    assert_eq!(Some(&digest::SHA384), digest::from_nid(673));
+    assert_eq!(Some(&digest::SHA512), digest::from_nid(674));
+    // NID_sha1
+    assert_eq!(None, digest::from_nid(64));
+    assert_eq!(None, digest::from_nid(0));
+}
+
+#[test]
+fn test_sha512_256_lengths() {
+    assert_eq!(32, digest::SHA512_256.output_len);
+    assert_eq!(digest::SHA512_256_OUTPUT_LEN, digest::SHA512_256.output_len);
+    assert_eq!(128, digest::SHA512_256.block_len);
+    assert_eq!(digest::SHA512.block_len, digest::SHA512_256.block_len);
+    assert_ne!(digest::SHA512_256, digest::SHA256);
+    assert_ne!(digest::SHA512_256, digest::SHA512);
+
+    assert_eq!(48, digest::SHA384.output_len);
+    assert_eq!(128, digest::SHA384.block_len);
+}
 
 #[test]
 fn test_fmt_algorithm() {
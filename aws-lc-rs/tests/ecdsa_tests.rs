@@ -3,11 +3,11 @@
 // Modifications copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
-use aws_lc_rs::encoding::{AsBigEndian, EcPrivateKeyRfc5915Der};
+use aws_lc_rs::encoding::{AsBigEndian, EcPrivateKeyBin, EcPrivateKeyRfc5915Der};
 use aws_lc_rs::{
     encoding::AsDer,
     rand::SystemRandom,
-    signature::{self, EcdsaKeyPair, KeyPair, Signature, UnparsedPublicKey},
+    signature::{self, EcdsaKeyPair, EcdsaPublicKey, KeyPair, Signature, UnparsedPublicKey},
     test, test_file,
 };
 use mirai_annotations::unrecoverable;
@@ -18,6 +18,8 @@ fn ecdsa_traits() {
     test::compile_time_assert_sync::<EcdsaKeyPair>();
     test::compile_time_assert_send::<Signature>();
     test::compile_time_assert_sync::<Signature>();
+    test::compile_time_assert_clone::<Signature>();
+    test::compile_time_assert_eq::<Signature>();
     test::compile_time_assert_send::<UnparsedPublicKey<&[u8]>>();
     test::compile_time_assert_sync::<UnparsedPublicKey<&[u8]>>();
     test::compile_time_assert_send::<UnparsedPublicKey<Vec<u8>>>();
@@ -115,6 +117,30 @@ fn ecdsa_from_pkcs8_test() {
     );
 }
 
+#[test]
+fn ecdsa_from_pkcs8_wrong_algorithm_test() {
+    let rng = SystemRandom::new();
+
+    // A P-384 key is rejected by a P-256 constructor.
+    let p384_pkcs8 =
+        EcdsaKeyPair::generate_pkcs8(&signature::ECDSA_P384_SHA384_ASN1_SIGNING, &rng).unwrap();
+    let error = EcdsaKeyPair::from_pkcs8(
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        p384_pkcs8.as_ref(),
+    )
+    .unwrap_err();
+    assert_eq!("WrongAlgorithm", error.to_string());
+
+    // A key that is not an EC key at all is rejected in the same way.
+    let ed25519_pkcs8 = signature::Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+    let error = EcdsaKeyPair::from_pkcs8(
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        ed25519_pkcs8.as_ref(),
+    )
+    .unwrap_err();
+    assert_eq!("WrongAlgorithm", error.to_string());
+}
+
 // Verify that, at least, we generate PKCS#8 documents that we can read.
 #[test]
 fn ecdsa_generate_pkcs8_test() {
@@ -158,6 +184,21 @@ fn signature_ecdsa_verify_asn1_sha3_test() {
     test_signature_ecdsa_verify_asn1(test_file!("data/ecdsa_verify_asn1_sha3_tests.txt"));
 }
 
+#[test]
+fn signature_ecdsa_verify_compressed_test() {
+    test_signature_ecdsa_verify_asn1(test_file!("data/ecdsa_verify_compressed_tests.txt"));
+}
+
+#[test]
+fn signature_ecdsa_verify_asn1_wycheproof_test() {
+    test_signature_ecdsa_verify_asn1(test_file!("data/ecdsa_verify_asn1_wycheproof_tests.txt"));
+}
+
+#[test]
+fn signature_ecdsa_verify_asn1_brainpool_test() {
+    test_signature_ecdsa_verify_asn1(test_file!("data/ecdsa_verify_asn1_brainpool_tests.txt"));
+}
+
 fn test_signature_ecdsa_verify_asn1(data_file: test::File) {
     test::run(data_file, |section, test_case| {
         assert_eq!(section, "");
@@ -179,6 +220,9 @@ fn test_signature_ecdsa_verify_asn1(data_file: test::File) {
             ("P-521", "SHA3-512") => &signature::ECDSA_P521_SHA3_512_ASN1,
             ("secp256k1", "SHA256") => &signature::ECDSA_P256K1_SHA256_ASN1,
             ("secp256k1", "SHA3-256") => &signature::ECDSA_P256K1_SHA3_256_ASN1,
+            ("brainpoolP256r1", "SHA256") => &signature::ECDSA_BRAINPOOL_P256R1_SHA256_ASN1,
+            ("brainpoolP384r1", "SHA384") => &signature::ECDSA_BRAINPOOL_P384R1_SHA384_ASN1,
+            ("brainpoolP512r1", "SHA512") => &signature::ECDSA_BRAINPOOL_P512R1_SHA512_ASN1,
             _ => {
                 panic!("Unsupported curve+digest: {curve_name}+{digest_name}");
             }
@@ -201,6 +245,16 @@ fn signature_ecdsa_verify_sha3_fixed_test() {
     test_signature_ecdsa_verify_fixed(test_file!("data/ecdsa_verify_fixed_sha3_tests.txt"));
 }
 
+#[test]
+fn signature_ecdsa_verify_fixed_wycheproof_test() {
+    test_signature_ecdsa_verify_fixed(test_file!("data/ecdsa_verify_fixed_wycheproof_tests.txt"));
+}
+
+#[test]
+fn signature_ecdsa_verify_fixed_brainpool_test() {
+    test_signature_ecdsa_verify_fixed(test_file!("data/ecdsa_verify_fixed_brainpool_tests.txt"));
+}
+
 fn test_signature_ecdsa_verify_fixed(data_file: test::File) {
     test::run(data_file, |section, test_case| {
         assert_eq!(section, "");
@@ -221,6 +275,9 @@ fn test_signature_ecdsa_verify_fixed(data_file: test::File) {
             ("P-521", "SHA3-512") => &signature::ECDSA_P521_SHA3_512_FIXED,
             ("secp256k1", "SHA256") => &signature::ECDSA_P256K1_SHA256_FIXED,
             ("secp256k1", "SHA3-256") => &signature::ECDSA_P256K1_SHA3_256_FIXED,
+            ("brainpoolP256r1", "SHA256") => &signature::ECDSA_BRAINPOOL_P256R1_SHA256_FIXED,
+            ("brainpoolP384r1", "SHA384") => &signature::ECDSA_BRAINPOOL_P384R1_SHA384_FIXED,
+            ("brainpoolP512r1", "SHA512") => &signature::ECDSA_BRAINPOOL_P512R1_SHA512_FIXED,
             _ => {
                 unrecoverable!("Unsupported curve+digest: {}+{}", curve_name, digest_name);
             }
@@ -231,6 +288,17 @@ fn test_signature_ecdsa_verify_fixed(data_file: test::File) {
         let actual_result = UnparsedPublicKey::new(alg, &public_key).verify(&msg, &sig);
         assert_eq!(actual_result.is_ok(), is_valid);
 
+        // The specialized P-256 entry point must agree with the generic one.
+        if alg == &signature::ECDSA_P256_SHA256_FIXED {
+            if let (Ok(public_key), Ok(sig)) = (
+                <&[u8; 65]>::try_from(public_key.as_slice()),
+                <&[u8; 64]>::try_from(sig.as_slice()),
+            ) {
+                let result = signature::verify_p256_fixed(public_key, &msg, sig);
+                assert_eq!(result.is_ok(), is_valid);
+            }
+        }
+
         Ok(())
     });
 }
@@ -257,10 +325,15 @@ fn ecdsa_test_public_key_coverage() {
 
     // Test `Debug`.
     assert_eq!(PUBLIC_KEY_DEBUG, format!("{:?}", key_pair.public_key()));
-    assert_eq!(
-        format!("EcdsaKeyPair {{ public_key: {:?} }}", key_pair.public_key()),
-        format!("{key_pair:?}")
-    );
+    let public_key_hex = test::to_hex(key_pair.public_key());
+    assert!(PUBLIC_KEY_DEBUG.contains(&public_key_hex));
+
+    // The key pair's `Debug` shows only the algorithm, never key material.
+    let key_pair_debug = format!("{key_pair:?}");
+    assert_eq!("EcdsaKeyPair { algorithm: ECDSA_P256 }", key_pair_debug);
+    assert!(!key_pair_debug.contains(&public_key_hex));
+    let private_key = key_pair.private_key().as_be_bytes().unwrap();
+    assert!(!key_pair_debug.contains(&test::to_hex(private_key.as_ref())));
 }
 
 #[test]
@@ -486,3 +559,1144 @@ fn test_private_key() {
         }
     }
 }
+
+#[test]
+fn test_sign_into() {
+    for (signing_alg, verification_alg, max_len) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+            72,
+        ),
+        (
+            &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256_SHA256_FIXED,
+            64,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            &signature::ECDSA_P384_SHA384_ASN1,
+            104,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+            96,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+            &signature::ECDSA_P521_SHA512_ASN1,
+            141,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+            &signature::ECDSA_P521_SHA512_FIXED,
+            132,
+        ),
+    ] {
+        let rnd = SystemRandom::new();
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let public_key = UnparsedPublicKey::new(verification_alg, key_pair.public_key().as_ref());
+        let message = b"sign_into message";
+
+        let mut exact = vec![0u8; max_len];
+        let len = key_pair.sign_into(&rnd, message, &mut exact).unwrap();
+        assert!(len <= max_len);
+        public_key.verify(message, &exact[..len]).unwrap();
+
+        let mut too_small = vec![0u8; max_len - 1];
+        assert!(key_pair.sign_into(&rnd, message, &mut too_small).is_err());
+        assert!(key_pair.sign_into(&rnd, message, &mut []).is_err());
+    }
+}
+
+#[test]
+fn test_signature_max_len() {
+    assert_eq!(
+        signature::ECDSA_P521_SIGNATURE_ASN1_MAX_LEN,
+        signature::ECDSA_SIGNATURE_MAX_LEN
+    );
+
+    let rnd = SystemRandom::new();
+    for signing_alg in [
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+        &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+        &signature::ECDSA_P384_SHA3_384_FIXED_SIGNING,
+        &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+        &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+        &signature::ECDSA_P256K1_SHA256_ASN1_SIGNING,
+        &signature::ECDSA_P256K1_SHA256_FIXED_SIGNING,
+    ] {
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let signature = key_pair.sign(&rnd, b"message").unwrap();
+        assert!(signature.as_ref().len() <= signature::ECDSA_SIGNATURE_MAX_LEN);
+
+        let mut buffer = [0u8; signature::ECDSA_SIGNATURE_MAX_LEN];
+        let len = key_pair.sign_into(&rnd, b"message", &mut buffer).unwrap();
+        assert!(len <= signature::ECDSA_SIGNATURE_MAX_LEN);
+    }
+}
+
+#[test]
+fn test_public_key_from_coordinates() {
+    let rng = SystemRandom::new();
+    for (alg, verification_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            &signature::ECDSA_P384_SHA384_ASN1,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+            &signature::ECDSA_P521_SHA512_ASN1,
+        ),
+    ] {
+        let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+        let public_key = key_pair.public_key().as_ref();
+        let (x, y) = public_key[1..].split_at((public_key.len() - 1) / 2);
+
+        let big =
+            EcdsaPublicKey::from_coordinates(alg, x, y, signature::Endianness::default()).unwrap();
+        assert_eq!(public_key, big.as_ref());
+
+        let x_le: Vec<u8> = x.iter().rev().copied().collect();
+        let y_le: Vec<u8> = y.iter().rev().copied().collect();
+        let little =
+            EcdsaPublicKey::from_coordinates(alg, &x_le, &y_le, signature::Endianness::Little)
+                .unwrap();
+        assert_eq!(public_key, little.as_ref());
+
+        // The signature made by the key pair verifies with the imported key.
+        let sig = key_pair.sign(&rng, b"hello, world").unwrap();
+        UnparsedPublicKey::new(verification_alg, little.as_ref())
+            .verify(b"hello, world", sig.as_ref())
+            .unwrap();
+
+        // Coordinates in the wrong byte order are (overwhelmingly likely) not on the curve.
+        assert!(
+            EcdsaPublicKey::from_coordinates(alg, &x_le, &y_le, signature::Endianness::Big)
+                .is_err()
+        );
+        // Coordinates must be exactly the field length.
+        assert!(
+            EcdsaPublicKey::from_coordinates(alg, &x[1..], y, signature::Endianness::Big).is_err()
+        );
+        assert!(EcdsaPublicKey::from_coordinates(
+            alg,
+            &[&[0u8][..], x].concat(),
+            y,
+            signature::Endianness::Big
+        )
+        .is_err());
+    }
+}
+
+#[test]
+fn test_public_key_normalize() {
+    for alg in [
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+        &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+    ] {
+        let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+        let uncompressed = key_pair.public_key().as_ref();
+        let compressed = key_pair.public_key_compressed().unwrap();
+
+        let from_compressed = EcdsaPublicKey::from_bytes(alg, &compressed).unwrap();
+        let from_uncompressed = EcdsaPublicKey::from_bytes(alg, uncompressed).unwrap();
+        assert_eq!(&*compressed, from_compressed.as_ref());
+        assert_eq!(uncompressed, from_uncompressed.as_ref());
+
+        assert_eq!(uncompressed, from_compressed.normalize().unwrap().as_ref());
+        assert_eq!(
+            uncompressed,
+            from_uncompressed.normalize().unwrap().as_ref()
+        );
+        assert_eq!(
+            key_pair.public_key().as_der().unwrap().as_ref(),
+            from_compressed.as_der().unwrap().as_ref()
+        );
+
+        let mut invalid = compressed.to_vec();
+        invalid[0] = 0x05;
+        assert!(EcdsaPublicKey::from_bytes(alg, &invalid).is_err());
+        assert!(EcdsaPublicKey::from_bytes(alg, &compressed[..compressed.len() - 1]).is_err());
+    }
+
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+    assert!(EcdsaPublicKey::from_bytes(
+        &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+        key_pair.public_key().as_ref()
+    )
+    .is_err());
+}
+
+#[test]
+#[allow(clippy::clone_on_copy)]
+fn test_signature_clone() {
+    let rng = SystemRandom::new();
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+    let message = b"retained and forwarded";
+    let sig = key_pair.sign(&rng, message).unwrap();
+
+    let clone = sig.clone();
+    assert!(clone == sig);
+    assert_eq!(sig.as_ref(), clone.as_ref());
+    UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_ASN1, key_pair.public_key())
+        .verify(message, clone.as_ref())
+        .unwrap();
+
+    let other = key_pair.sign(&rng, message).unwrap();
+    assert!(other != sig);
+}
+
+#[test]
+fn test_public_key_compressed() {
+    for (signing_alg, verification_alg, compressed_len) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+            33,
+        ),
+        (
+            &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256_SHA256_FIXED,
+            33,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            &signature::ECDSA_P384_SHA384_ASN1,
+            49,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+            &signature::ECDSA_P521_SHA512_FIXED,
+            67,
+        ),
+    ] {
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let uncompressed = key_pair.public_key().as_ref();
+        let compressed = key_pair.public_key_compressed().unwrap();
+        assert_eq!(compressed_len, compressed.len());
+        assert_eq!(
+            0x02 | (uncompressed[uncompressed.len() - 1] & 1),
+            compressed[0]
+        );
+        assert_eq!(&uncompressed[1..compressed_len], &compressed[1..]);
+
+        let message = b"compressed public key";
+        let sig = key_pair.sign(&SystemRandom::new(), message).unwrap();
+        UnparsedPublicKey::new(verification_alg, uncompressed)
+            .verify(message, sig.as_ref())
+            .unwrap();
+        UnparsedPublicKey::new(verification_alg, &compressed)
+            .verify(message, sig.as_ref())
+            .unwrap();
+        assert!(UnparsedPublicKey::new(verification_alg, &compressed)
+            .verify(b"other message", sig.as_ref())
+            .is_err());
+    }
+}
+
+#[test]
+fn test_length_constants() {
+    for (fixed_alg, asn1_alg, public_key_len, fixed_len, asn1_max_len) in [
+        (
+            &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            signature::ECDSA_P256_PUBLIC_KEY_LEN,
+            signature::ECDSA_P256_SIGNATURE_FIXED_LEN,
+            signature::ECDSA_P256_SIGNATURE_ASN1_MAX_LEN,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            signature::ECDSA_P384_PUBLIC_KEY_LEN,
+            signature::ECDSA_P384_SIGNATURE_FIXED_LEN,
+            signature::ECDSA_P384_SIGNATURE_ASN1_MAX_LEN,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+            &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+            signature::ECDSA_P521_PUBLIC_KEY_LEN,
+            signature::ECDSA_P521_SIGNATURE_FIXED_LEN,
+            signature::ECDSA_P521_SIGNATURE_ASN1_MAX_LEN,
+        ),
+    ] {
+        let rnd = SystemRandom::new();
+        let message = b"length constants";
+
+        let key_pair = EcdsaKeyPair::generate(fixed_alg).unwrap();
+        assert_eq!(public_key_len, key_pair.public_key().as_ref().len());
+        assert_eq!(
+            fixed_len,
+            key_pair.sign(&rnd, message).unwrap().as_ref().len()
+        );
+
+        let key_pair = EcdsaKeyPair::generate(asn1_alg).unwrap();
+        assert_eq!(public_key_len, key_pair.public_key().as_ref().len());
+        let mut signature = [0u8; signature::ECDSA_P521_SIGNATURE_ASN1_MAX_LEN];
+        let len = key_pair
+            .sign_into(&rnd, message, &mut signature[..asn1_max_len])
+            .unwrap();
+        assert!(len <= asn1_max_len);
+        assert!(key_pair
+            .sign_into(&rnd, message, &mut signature[..asn1_max_len - 1])
+            .is_err());
+    }
+}
+
+#[test]
+fn test_sign_and_verify_empty_message() {
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256_SHA256_FIXED,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            &signature::ECDSA_P384_SHA384_ASN1,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_FIXED_SIGNING,
+            &signature::ECDSA_P521_SHA512_FIXED,
+        ),
+    ] {
+        let rnd = SystemRandom::new();
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let public_key = UnparsedPublicKey::new(verification_alg, key_pair.public_key().as_ref());
+
+        let sig = key_pair.sign(&rnd, b"").unwrap();
+        public_key.verify(b"", sig.as_ref()).unwrap();
+        public_key.verify(&[], sig.as_ref()).unwrap();
+        assert!(public_key.verify(b"\0", sig.as_ref()).is_err());
+
+        let mut buf = [0u8; 141];
+        let len = key_pair.sign_into(&rnd, &[], &mut buf).unwrap();
+        public_key.verify(b"", &buf[..len]).unwrap();
+
+        let non_empty_sig = key_pair.sign(&rnd, b"\0").unwrap();
+        assert!(public_key.verify(b"", non_empty_sig.as_ref()).is_err());
+    }
+}
+
+#[test]
+fn test_try_clone() {
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256_SHA256_FIXED,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            &signature::ECDSA_P384_SHA384_ASN1,
+        ),
+    ] {
+        let rnd = SystemRandom::new();
+        let message = b"try_clone message";
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let public_key = key_pair.public_key().as_ref().to_vec();
+        let pkcs8 = key_pair.to_pkcs8v1().unwrap();
+
+        let clones: Vec<EcdsaKeyPair> = (0..4).map(|_| key_pair.try_clone().unwrap()).collect();
+        let clone_of_clone = clones[0].try_clone().unwrap();
+        drop(key_pair);
+
+        for clone in clones.iter().chain(core::iter::once(&clone_of_clone)) {
+            assert_eq!(public_key.as_slice(), clone.public_key().as_ref());
+            let signature = clone.sign(&rnd, message).unwrap();
+            UnparsedPublicKey::new(verification_alg, &public_key)
+                .verify(message, signature.as_ref())
+                .unwrap();
+            assert_eq!(pkcs8.as_ref(), clone.to_pkcs8v1().unwrap().as_ref());
+        }
+
+        drop(clones);
+        let signature = clone_of_clone.sign(&rnd, message).unwrap();
+        UnparsedPublicKey::new(verification_alg, &public_key)
+            .verify(message, signature.as_ref())
+            .unwrap();
+    }
+}
+
+#[test]
+fn test_pkcs8v2() {
+    let rnd = SystemRandom::new();
+    for signing_alg in [
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+        &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+        &signature::ECDSA_P256K1_SHA256_ASN1_SIGNING,
+    ] {
+        let pkcs8v2 = EcdsaKeyPair::generate_pkcs8v2(signing_alg, &rnd).unwrap();
+        let key_pair = EcdsaKeyPair::from_pkcs8(signing_alg, pkcs8v2.as_ref()).unwrap();
+        let public_key = key_pair.public_key().as_ref();
+
+        // OneAsymmetricKey ::= SEQUENCE { version INTEGER (1), ... }
+        let der = pkcs8v2.as_ref();
+        assert_eq!(0x30, der[0]);
+        let header_len = match der[1] {
+            0x81 => 3,
+            0x82 => 4,
+            _ => 2,
+        };
+        assert_eq!(&[0x02, 0x01, 0x01], &der[header_len..header_len + 3]);
+        assert!(pkcs8v2.as_ref().ends_with(public_key));
+
+        let reencoded = key_pair.to_pkcs8v2().unwrap();
+        assert_eq!(pkcs8v2.as_ref(), reencoded.as_ref());
+
+        let pkcs8v1 = key_pair.to_pkcs8v1().unwrap();
+        assert!(pkcs8v1.as_ref().len() < pkcs8v2.as_ref().len());
+        let from_v1 = EcdsaKeyPair::from_pkcs8(signing_alg, pkcs8v1.as_ref()).unwrap();
+        assert_eq!(public_key, from_v1.public_key().as_ref());
+    }
+}
+
+#[test]
+fn test_curve_id() {
+    use signature::CurveId;
+
+    fn security_bits(alg: &signature::EcdsaVerificationAlgorithm) -> Option<usize> {
+        match alg.curve_id() {
+            CurveId::P256 => Some(128),
+            CurveId::P384 => Some(192),
+            CurveId::P521 => Some(256),
+            _ => None,
+        }
+    }
+
+    for (alg, expected) in [
+        (&signature::ECDSA_P256_SHA256_ASN1, CurveId::P256),
+        (&signature::ECDSA_P256_SHA384_ASN1, CurveId::P256),
+        (&signature::ECDSA_P256_SHA256_FIXED, CurveId::P256),
+        (&signature::ECDSA_P384_SHA384_ASN1, CurveId::P384),
+        (&signature::ECDSA_P384_SHA256_ASN1, CurveId::P384),
+        (&signature::ECDSA_P384_SHA384_FIXED, CurveId::P384),
+        (&signature::ECDSA_P521_SHA512_ASN1, CurveId::P521),
+        (&signature::ECDSA_P256K1_SHA256_FIXED, CurveId::P256K1),
+    ] {
+        assert_eq!(expected, alg.curve_id());
+        assert_eq!(expected != CurveId::P256K1, security_bits(alg).is_some());
+    }
+
+    // Signing algorithms dereference to their verification algorithm.
+    assert_eq!(
+        CurveId::P256,
+        signature::ECDSA_P256_SHA256_FIXED_SIGNING.curve_id()
+    );
+    assert_eq!(
+        CurveId::P384,
+        signature::ECDSA_P384_SHA384_ASN1_SIGNING.curve_id()
+    );
+}
+
+#[test]
+fn test_verify_with_array_public_key() {
+    let rnd = SystemRandom::new();
+    let message = b"array public key";
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+    let signature = key_pair.sign(&rnd, message).unwrap();
+
+    let mut uncompressed = [0u8; 65];
+    uncompressed.copy_from_slice(key_pair.public_key().as_ref());
+    let public_key = UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_ASN1, uncompressed);
+    let copied = public_key;
+    public_key.verify(message, signature.as_ref()).unwrap();
+    copied.verify(message, signature.as_ref()).unwrap();
+    assert!(public_key
+        .verify(b"another message", signature.as_ref())
+        .is_err());
+
+    let mut compressed = [0u8; 33];
+    compressed[0] = 0x02 | (uncompressed[64] & 1);
+    compressed[1..].copy_from_slice(&uncompressed[1..33]);
+    UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_ASN1, compressed)
+        .verify(message, signature.as_ref())
+        .unwrap();
+}
+
+#[test]
+fn test_generate_with_rng() {
+    use aws_lc_rs::test::rand::{FixedByteRandom, FixedSliceSequenceRandom};
+    use core::cell::UnsafeCell;
+
+    for signing_alg in [
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+        &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+        &signature::ECDSA_P256K1_SHA256_ASN1_SIGNING,
+    ] {
+        let rng = FixedByteRandom { byte: 0x42 };
+        let key_pair = EcdsaKeyPair::generate_with_rng(signing_alg, &rng).unwrap();
+        let again = EcdsaKeyPair::generate_with_rng(signing_alg, &rng).unwrap();
+        assert_eq!(key_pair.public_key().as_ref(), again.public_key().as_ref());
+
+        let private_key = AsBigEndian::<EcPrivateKeyBin>::as_be_bytes(&key_pair.private_key())
+            .unwrap()
+            .as_ref()
+            .to_vec();
+        assert_eq!(
+            private_key,
+            AsBigEndian::<EcPrivateKeyBin>::as_be_bytes(&again.private_key())
+                .unwrap()
+                .as_ref()
+        );
+        assert!(private_key[1..].iter().all(|b| *b == 0x42));
+
+        let other =
+            EcdsaKeyPair::generate_with_rng(signing_alg, &FixedByteRandom { byte: 0x43 }).unwrap();
+        assert_ne!(key_pair.public_key().as_ref(), other.public_key().as_ref());
+    }
+
+    // Out-of-range candidates are skipped.
+    let out_of_range = [0xff; 32];
+    let in_range = [0x42; 32];
+    let rng = FixedSliceSequenceRandom {
+        bytes: &[&out_of_range, &[0u8; 32], &in_range],
+        current: UnsafeCell::new(0),
+    };
+    let key_pair =
+        EcdsaKeyPair::generate_with_rng(&signature::ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+    let expected = EcdsaKeyPair::generate_with_rng(
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &FixedByteRandom { byte: 0x42 },
+    )
+    .unwrap();
+    assert_eq!(
+        expected.public_key().as_ref(),
+        key_pair.public_key().as_ref()
+    );
+
+    // An RNG that never produces a valid scalar is rejected.
+    assert!(EcdsaKeyPair::generate_with_rng(
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &FixedByteRandom { byte: 0 }
+    )
+    .is_err());
+}
+
+#[test]
+fn test_sign_and_verify_with_context() {
+    let rng = SystemRandom::new();
+    let message = b"message";
+
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+            &signature::ECDSA_P521_SHA512_ASN1,
+        ),
+    ] {
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let public_key = key_pair.public_key().as_ref();
+
+        let sig = key_pair
+            .sign_with_context(&rng, b"context", message)
+            .unwrap();
+        signature::verify_with_context(
+            verification_alg,
+            public_key,
+            b"context",
+            message,
+            sig.as_ref(),
+        )
+        .unwrap();
+
+        // A different context, or moving bytes between context and message, fails.
+        for (context, msg) in [
+            (&b"other"[..], &message[..]),
+            (b"", message),
+            (b"contextm", b"essage"),
+        ] {
+            assert!(signature::verify_with_context(
+                verification_alg,
+                public_key,
+                context,
+                msg,
+                sig.as_ref()
+            )
+            .is_err());
+        }
+
+        // The signature is not over the plain message.
+        assert!(UnparsedPublicKey::new(verification_alg, public_key)
+            .verify(message, sig.as_ref())
+            .is_err());
+
+        // An empty context is allowed; an overlong one is rejected.
+        let sig = key_pair.sign_with_context(&rng, b"", message).unwrap();
+        signature::verify_with_context(verification_alg, public_key, b"", message, sig.as_ref())
+            .unwrap();
+        let long_context = [0u8; signature::ECDSA_CONTEXT_MAX_LEN + 1];
+        assert!(key_pair
+            .sign_with_context(&rng, &long_context, message)
+            .is_err());
+        assert!(signature::verify_with_context(
+            verification_alg,
+            public_key,
+            &long_context,
+            message,
+            sig.as_ref()
+        )
+        .is_err());
+    }
+}
+
+#[test]
+fn test_zeroize() {
+    let rng = SystemRandom::new();
+    let mut pkcs8 =
+        EcdsaKeyPair::generate_pkcs8(&signature::ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+    let len = pkcs8.as_ref().len();
+    assert!(pkcs8.as_ref().iter().any(|b| *b != 0));
+    pkcs8.zeroize();
+    assert_eq!(len, pkcs8.as_ref().len());
+    assert!(pkcs8.as_ref().iter().all(|b| *b == 0));
+
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_FIXED_SIGNING).unwrap();
+    let mut sig = key_pair.sign(&rng, b"message").unwrap();
+    assert!(sig.as_ref().iter().any(|b| *b != 0));
+    sig.zeroize();
+    assert_eq!(
+        signature::ECDSA_P256_SIGNATURE_FIXED_LEN,
+        sig.as_ref().len()
+    );
+    assert!(sig.as_ref().iter().all(|b| *b == 0));
+}
+
+#[test]
+fn test_sign_checked() {
+    let rng = SystemRandom::new();
+    let message = b"message";
+
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+            &signature::ECDSA_P521_SHA512_ASN1,
+        ),
+        (
+            &signature::ECDSA_P256K1_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256K1_SHA256_FIXED,
+        ),
+    ] {
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let sig = key_pair.sign_checked(&rng, message).unwrap();
+        UnparsedPublicKey::new(verification_alg, key_pair.public_key())
+            .verify(message, sig.as_ref())
+            .unwrap();
+        let sig = key_pair.sign_checked(&rng, b"").unwrap();
+        UnparsedPublicKey::new(verification_alg, key_pair.public_key())
+            .verify(b"", sig.as_ref())
+            .unwrap();
+    }
+}
+
+// Wycheproof-style checks that ASN.1 signatures must be strictly DER-encoded.
+#[test]
+fn test_verify_asn1_rejects_non_der() {
+    fn der_integer(value: &[u8]) -> Vec<u8> {
+        let mut out = vec![0x02, u8::try_from(value.len()).unwrap()];
+        out.extend_from_slice(value);
+        out
+    }
+
+    fn der_sequence(r: &[u8], s: &[u8]) -> Vec<u8> {
+        let contents = [der_integer(r), der_integer(s)].concat();
+        let mut out = vec![0x30, u8::try_from(contents.len()).unwrap()];
+        out.extend_from_slice(&contents);
+        out
+    }
+
+    // Returns the contents of the two INTEGERs of a P-256 signature.
+    fn parse(sig: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        assert_eq!(0x30, sig[0]);
+        assert_eq!(sig.len() - 2, usize::from(sig[1]));
+        assert_eq!(0x02, sig[2]);
+        let r_end = 4 + usize::from(sig[3]);
+        assert_eq!(0x02, sig[r_end]);
+        let s_end = r_end + 2 + usize::from(sig[r_end + 1]);
+        assert_eq!(sig.len(), s_end);
+        (sig[4..r_end].to_vec(), sig[r_end + 2..].to_vec())
+    }
+
+    let rng = SystemRandom::new();
+    let message = b"message";
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+    let public_key =
+        UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_ASN1, key_pair.public_key());
+
+    // Find a signature whose `r` needs a leading zero byte, so that dropping it makes `r` negative.
+    let (sig, r, s) = loop {
+        let sig = key_pair.sign(&rng, message).unwrap().as_ref().to_vec();
+        let (r, s) = parse(&sig);
+        if r[0] == 0 {
+            break (sig, r, s);
+        }
+    };
+    public_key.verify(message, &sig).unwrap();
+    assert_eq!(sig, der_sequence(&r, &s));
+
+    let trailing_data = [sig.as_slice(), &[0x00]].concat();
+    let trailing_data_in_sequence = {
+        let mut sig = [sig.as_slice(), &[0x00]].concat();
+        sig[1] += 1;
+        sig
+    };
+    let non_minimal_r = der_sequence(&[&[0x00], r.as_slice()].concat(), &s);
+    let non_minimal_s = der_sequence(&r, &[&[0x00, 0x00], s.as_slice()].concat());
+    let negative_r = der_sequence(&r[1..], &s);
+    let long_form_length = [&[0x30, 0x81], &sig[1..]].concat();
+    let indefinite_length = [&[0x30, 0x80], &sig[2..], &[0x00, 0x00]].concat();
+
+    for bad_sig in [
+        trailing_data,
+        trailing_data_in_sequence,
+        non_minimal_r,
+        non_minimal_s,
+        negative_r,
+        long_form_length,
+        indefinite_length,
+    ] {
+        assert!(public_key.verify(message, &bad_sig).is_err());
+    }
+}
+
+#[test]
+fn test_verify_strict() {
+    let rng = SystemRandom::new();
+    let message = b"message";
+
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+            &signature::ECDSA_P521_SHA512_ASN1,
+        ),
+        (
+            &signature::ECDSA_P256K1_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256K1_SHA256_FIXED,
+        ),
+    ] {
+        let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+        let public_key = key_pair.public_key().as_ref();
+        let sig = key_pair.sign(&rng, message).unwrap();
+
+        // The supported curves have a cofactor of one, so every valid public key passes.
+        verification_alg
+            .verify_strict(public_key, message, sig.as_ref())
+            .unwrap();
+        assert!(verification_alg
+            .verify_strict(public_key, b"other", sig.as_ref())
+            .is_err());
+
+        // Points that are not on the curve, or the point at infinity, are rejected.
+        let mut off_curve = public_key.to_vec();
+        *off_curve.last_mut().unwrap() ^= 1;
+        assert!(verification_alg
+            .verify_strict(&off_curve, message, sig.as_ref())
+            .is_err());
+        assert!(verification_alg
+            .verify_strict(&[0x00], message, sig.as_ref())
+            .is_err());
+    }
+}
+
+#[test]
+fn test_public_key_from_key_pair() {
+    let rng = SystemRandom::new();
+    let message = b"message";
+
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+        ),
+        (
+            &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+            &signature::ECDSA_P521_SHA512_ASN1,
+        ),
+    ] {
+        let (public_key, sig) = {
+            let key_pair = EcdsaKeyPair::generate(signing_alg).unwrap();
+            let public_key = EcdsaPublicKey::from(&key_pair);
+            assert_eq!(key_pair.public_key().as_ref(), public_key.as_ref());
+            (public_key, key_pair.sign(&rng, message).unwrap())
+        };
+        // The public key outlives the key pair it came from.
+        let public_key = UnparsedPublicKey::new(verification_alg, &public_key);
+        public_key.verify(message, sig.as_ref()).unwrap();
+        assert!(public_key.verify(b"other message", sig.as_ref()).is_err());
+    }
+}
+
+#[cfg(feature = "danger-test-only")]
+#[test]
+fn test_sign_with_fixed_nonce() {
+    // RFC 6979, Appendix A.2.5: ECDSA, 256 Bits (Prime Field), with SHA-256 and message "sample".
+    let private_key =
+        test::from_dirty_hex("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+    let public_key = test::from_dirty_hex(
+        "0460FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6\
+         7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299",
+    );
+    let nonce =
+        test::from_dirty_hex("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60");
+    let expected_sig = test::from_dirty_hex(
+        "EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716\
+         F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8",
+    );
+    let digest = aws_lc_rs::digest::digest(&aws_lc_rs::digest::SHA256, b"sample");
+
+    let key_pair = EcdsaKeyPair::from_private_key_and_public_key(
+        &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+        &private_key,
+        &public_key,
+    )
+    .unwrap();
+    let sig = signature::sign_with_fixed_nonce(&key_pair, digest.as_ref(), &nonce).unwrap();
+    assert_eq!(expected_sig, sig.as_ref());
+
+    let key_pair = EcdsaKeyPair::from_private_key_and_public_key(
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &private_key,
+        &public_key,
+    )
+    .unwrap();
+    let sig = signature::sign_with_fixed_nonce(&key_pair, digest.as_ref(), &nonce).unwrap();
+    UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_ASN1, &public_key)
+        .verify(b"sample", sig.as_ref())
+        .unwrap();
+
+    // The nonce must be in [1, n).
+    assert!(signature::sign_with_fixed_nonce(&key_pair, digest.as_ref(), &[0u8; 32]).is_err());
+    assert!(signature::sign_with_fixed_nonce(&key_pair, digest.as_ref(), &[0xffu8; 32]).is_err());
+}
+
+#[test]
+fn test_verification_algorithm_hash() {
+    use std::collections::HashSet;
+
+    let mut algorithms = HashSet::new();
+    assert!(algorithms.insert(&signature::ECDSA_P256_SHA256_ASN1));
+    assert!(algorithms.insert(&signature::ECDSA_P256_SHA256_FIXED));
+    assert!(!algorithms.insert(&signature::ECDSA_P256_SHA256_ASN1));
+
+    assert!(algorithms.contains(&signature::ECDSA_P256_SHA256_ASN1));
+    assert!(algorithms.contains(&signature::ECDSA_P256_SHA256_FIXED));
+    // Algorithms differing only in their curve or digest are distinct.
+    assert!(!algorithms.contains(&signature::ECDSA_P384_SHA256_ASN1));
+    assert!(!algorithms.contains(&signature::ECDSA_P256_SHA384_ASN1));
+    assert_eq!(2, algorithms.len());
+}
+
+#[test]
+fn test_verify_rs() {
+    // RFC 6979, A.2.5: ECDSA, 256 Bits (Prime Field), with SHA-256, message = "sample"
+    let public_key = test::from_dirty_hex(
+        "0460FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6
+         7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299",
+    );
+    let r =
+        test::from_hex("EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716").unwrap();
+    let s =
+        test::from_hex("F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8").unwrap();
+    let alg = &signature::ECDSA_P256_SHA256_ASN1;
+
+    signature::verify_rs(alg, &public_key, b"sample", &r, &s).unwrap();
+    // The fixed-length algorithm verifies the same (r, s).
+    signature::verify_rs(
+        &signature::ECDSA_P256_SHA256_FIXED,
+        &public_key,
+        b"sample",
+        &r,
+        &s,
+    )
+    .unwrap();
+
+    // A component may not be longer than the curve order, even with a leading zero.
+    let mut long_r = vec![0u8];
+    long_r.extend_from_slice(&r);
+    assert!(signature::verify_rs(alg, &public_key, b"sample", &long_r, &s).is_err());
+
+    // r and s must be in [1, n).
+    assert!(signature::verify_rs(alg, &public_key, b"sample", &[0u8; 32], &s).is_err());
+    assert!(signature::verify_rs(alg, &public_key, b"sample", &r, &[]).is_err());
+    assert!(signature::verify_rs(alg, &public_key, b"sample", &[0xffu8; 32], &s).is_err());
+
+    let mut bad_s = s.clone();
+    bad_s[31] ^= 1;
+    assert!(signature::verify_rs(alg, &public_key, b"sample", &r, &bad_s).is_err());
+    assert!(signature::verify_rs(alg, &public_key, b"test", &r, &s).is_err());
+}
+
+#[test]
+fn test_sha1_for_legacy_use_only() {
+    // RFC 6979, A.2.5: ECDSA, 256 Bits (Prime Field), with SHA-1, message = "sample"
+    let p256_public_key = test::from_dirty_hex(
+        "0460FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6
+         7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299",
+    );
+    let p256_sig = test::from_dirty_hex(
+        "3044022061340c88c3aaebeb4f6d667f672ca9759a6ccaa9fa8811313039ee4a35471d32
+         02206d7f147dac089441bb2e2fe8f7a3fa264b9c475098fdcf6e00d7c996e1b8b7eb",
+    );
+    // The key from RFC 6979, A.2.6, with a signature generated by another implementation.
+    let p384_public_key = test::from_dirty_hex(
+        "04ec3a4e415b4e19a4568618029f427fa5da9a8bc4ae92e02e06aae5286b300c64def8f0ea9055866064a254
+         515480bc138015d9b72d7d57244ea8ef9ac0c621896708a59367f9dfb9f54ca84b3f1c9db1288b231c3ae0d4
+         fe7344fd2533264720",
+    );
+    let p384_sig = test::from_dirty_hex(
+        "30650230450f9e04c27b6015eff4c03da857c9c9c2885d8fbf85d0887742a019029e497eb7162be18e50533e
+         834ae7ba4972cf16023100f12e0d09343aa768a230335cadc8d0a97c7affd32d4a1198fafe17fc3a3bc4f17d
+         b38781246f90b0e9e368953e64ba83",
+    );
+
+    for (alg, public_key, sig) in [
+        (
+            &signature::ECDSA_P256_SHA1_FOR_LEGACY_USE_ONLY_ASN1,
+            &p256_public_key,
+            &p256_sig,
+        ),
+        (
+            &signature::ECDSA_P384_SHA1_FOR_LEGACY_USE_ONLY_ASN1,
+            &p384_public_key,
+            &p384_sig,
+        ),
+    ] {
+        let public_key = UnparsedPublicKey::new(alg, public_key);
+        public_key.verify(b"sample", sig).unwrap();
+        assert!(public_key.verify(b"test", sig).is_err());
+    }
+
+    // The signatures are not valid with a different digest.
+    assert!(
+        UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_ASN1, &p256_public_key)
+            .verify(b"sample", &p256_sig)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_public_key_matches_and_verify() {
+    let rng = SystemRandom::new();
+    let key_pair = EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+    let other_key_pair =
+        EcdsaKeyPair::generate(&signature::ECDSA_P256_SHA256_ASN1_SIGNING).unwrap();
+    let pinned = key_pair.public_key().as_ref().to_vec();
+
+    let public_key = key_pair.public_key();
+    assert!(public_key.matches(&pinned));
+    assert!(!public_key.matches(other_key_pair.public_key().as_ref()));
+    assert!(!public_key.matches(&pinned[..pinned.len() - 1]));
+    assert!(!public_key.matches(&[]));
+    let mut tampered = pinned.clone();
+    tampered[32] ^= 1;
+    assert!(!public_key.matches(&tampered));
+
+    let msg = b"hello, world";
+    let sig = key_pair.sign(&rng, msg).unwrap();
+    public_key.verify(msg, sig.as_ref()).unwrap();
+    assert!(public_key.verify(b"goodbye, world", sig.as_ref()).is_err());
+    assert!(other_key_pair
+        .public_key()
+        .verify(msg, sig.as_ref())
+        .is_err());
+}
+
+#[test]
+fn test_from_pkcs8_any() {
+    let rng = SystemRandom::new();
+    for (alg, curve_id) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            signature::CurveId::P256,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            signature::CurveId::P384,
+        ),
+    ] {
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(alg, &rng).unwrap();
+        let (detected, key_pair) = EcdsaKeyPair::from_pkcs8_any(pkcs8.as_ref()).unwrap();
+        assert_eq!(curve_id, detected);
+        assert_eq!(
+            EcdsaKeyPair::from_pkcs8(alg, pkcs8.as_ref())
+                .unwrap()
+                .public_key()
+                .as_ref(),
+            key_pair.public_key().as_ref()
+        );
+
+        let msg = b"hello, world";
+        let sig = key_pair.sign(&rng, msg).unwrap();
+        key_pair.public_key().verify(msg, sig.as_ref()).unwrap();
+    }
+
+    assert!(EcdsaKeyPair::from_pkcs8_any(&[]).is_err());
+    // An Ed25519 key is not an ECDSA key.
+    let pkcs8 = signature::Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+    assert!(EcdsaKeyPair::from_pkcs8_any(pkcs8.as_ref()).is_err());
+}
+
+#[test]
+fn test_derive_public_key() {
+    // RFC 6979, A.2.5 and A.2.6
+    for (alg, private_key, public_key) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            "C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721",
+            "0460FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6
+             7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299",
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            "6B9D3DAD2E1B8C1C05B19875B6659F4DE23C3B667BF297BA
+             9AA47740787137D896D5724E4C70A825F872C9EA60D2EDF5",
+            "04EC3A4E415B4E19A4568618029F427FA5DA9A8BC4AE92E0
+             2E06AAE5286B300C64DEF8F0EA9055866064A254515480BC13
+             8015D9B72D7D57244EA8EF9AC0C621896708A59367F9DFB9
+             F54CA84B3F1C9DB1288B231C3AE0D4FE7344FD2533264720",
+        ),
+    ] {
+        let private_key = test::from_dirty_hex(private_key);
+        let public_key = test::from_dirty_hex(public_key);
+        let derived = signature::derive_public_key(alg, &private_key).unwrap();
+        assert_eq!(public_key, derived.as_ref());
+
+        // The derived key matches that of the key pair made from the same scalar.
+        let key_pair =
+            EcdsaKeyPair::from_private_key_and_public_key(alg, &private_key, &public_key).unwrap();
+        assert_eq!(key_pair.public_key().as_ref(), derived.as_ref());
+    }
+
+    // Out-of-range scalars are rejected.
+    let alg = &signature::ECDSA_P256_SHA256_ASN1_SIGNING;
+    assert!(signature::derive_public_key(alg, &[0u8; 32]).is_err());
+    let order =
+        test::from_dirty_hex("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551");
+    assert!(signature::derive_public_key(alg, &order).is_err());
+    assert!(signature::derive_public_key(alg, &[0xff; 33]).is_err());
+}
+
+/// Returns whether `der` is the minimal DER encoding of an ECDSA `SEQUENCE` of two positive
+/// `INTEGER`s, as TLS and other strict verifiers require.
+fn is_minimal_der_signature(der: &[u8]) -> bool {
+    // Splits a minimally-encoded definite length off the front of `input`.
+    fn length(input: &[u8]) -> Option<(usize, &[u8])> {
+        match *input {
+            [len @ 0..=0x7f, ref rest @ ..] => Some((usize::from(len), rest)),
+            [0x81, len @ 0x80..=0xff, ref rest @ ..] => Some((usize::from(len), rest)),
+            _ => None,
+        }
+    }
+
+    fn integer(input: &[u8]) -> Option<&[u8]> {
+        let (len, rest) = length(input.strip_prefix(&[0x02])?)?;
+        if rest.len() < len {
+            return None;
+        }
+        let (value, rest) = rest.split_at(len);
+        let minimal_positive = match *value {
+            [] => false,
+            [0x80..=0xff, ..] => false,
+            [0x00] => false,
+            [0x00, next, ..] => next >= 0x80,
+            _ => true,
+        };
+        minimal_positive.then_some(rest)
+    }
+
+    match der.strip_prefix(&[0x30]).and_then(length) {
+        Some((len, contents)) => {
+            len == contents.len()
+                && integer(contents)
+                    .and_then(integer)
+                    .map_or(false, <[u8]>::is_empty)
+        }
+        None => false,
+    }
+}
+
+#[test]
+fn test_asn1_signatures_are_minimal_der() {
+    // The checker itself rejects non-minimal encodings.
+    assert!(is_minimal_der_signature(&[
+        0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x7f
+    ]));
+    assert!(is_minimal_der_signature(&[
+        0x30, 0x07, 0x02, 0x01, 0x01, 0x02, 0x02, 0x00, 0x80
+    ]));
+    // Unnecessary leading zero.
+    assert!(!is_minimal_der_signature(&[
+        0x30, 0x07, 0x02, 0x02, 0x00, 0x01, 0x02, 0x01, 0x01
+    ]));
+    // Negative integer.
+    assert!(!is_minimal_der_signature(&[
+        0x30, 0x06, 0x02, 0x01, 0x80, 0x02, 0x01, 0x01
+    ]));
+    // Long-form length where the short form suffices.
+    assert!(!is_minimal_der_signature(&[
+        0x30, 0x81, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01
+    ]));
+    // Trailing data.
+    assert!(!is_minimal_der_signature(&[
+        0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01, 0x00
+    ]));
+
+    let rng = SystemRandom::new();
+    for alg in [
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+        &signature::ECDSA_P521_SHA512_ASN1_SIGNING,
+        &signature::ECDSA_P256K1_SHA256_ASN1_SIGNING,
+    ] {
+        let key_pair = EcdsaKeyPair::generate(alg).unwrap();
+        let mut buffer = [0u8; signature::ECDSA_SIGNATURE_MAX_LEN];
+        // About one in 256 signatures has an `r` or `s` short enough to need fewer bytes, and
+        // about half need a leading zero, so these cover every encoding length.
+        for i in 0..512u32 {
+            let msg = i.to_be_bytes();
+            let sig = key_pair.sign(&rng, &msg).unwrap();
+            assert!(is_minimal_der_signature(sig.as_ref()), "{:?}", sig.as_ref());
+
+            let len = key_pair.sign_into(&rng, &msg, &mut buffer).unwrap();
+            assert!(is_minimal_der_signature(&buffer[..len]));
+        }
+    }
+}
@@ -186,10 +186,7 @@ fn ed25519_test_public_key_coverage() {
     // Test `Debug`.
     assert_eq!(PUBLIC_KEY_DEBUG, format!("{:?}", key_pair.public_key()));
     assert_eq!(
-        format!(
-            "Ed25519KeyPair {{ public_key: {:?} }}",
-            key_pair.public_key()
-        ),
+        "Ed25519KeyPair { algorithm: ED25519 }",
         format!("{key_pair:?}")
     );
 }
@@ -233,3 +230,29 @@ fn test_seed() {
 
     assert_eq!(key_pair_doc.as_ref(), key_pair_copy_doc.as_ref());
 }
+
+#[test]
+fn test_ed25519_key_pair_public_key() {
+    fn public_key_bytes<K: KeyPair>(key_pair: &K) -> Vec<u8> {
+        key_pair.public_key().as_ref().to_vec()
+    }
+
+    const MESSAGE: &[u8] = b"hello, world";
+
+    let rnd = SystemRandom::new();
+    let key_pair_doc = Ed25519KeyPair::generate_pkcs8(&rnd).unwrap();
+    let key_pair = Ed25519KeyPair::from_pkcs8(key_pair_doc.as_ref()).unwrap();
+
+    let public_key = public_key_bytes(&key_pair);
+    assert_eq!(32, public_key.len());
+    assert_eq!(key_pair.public_key().as_ref(), public_key.as_slice());
+
+    let sig = key_pair.sign(MESSAGE);
+    test_signature_verification(&public_key, MESSAGE, sig.as_ref(), Ok(()));
+    test_signature_verification(
+        &public_key,
+        b"hello, world!",
+        sig.as_ref(),
+        Err(error::Unspecified),
+    );
+}
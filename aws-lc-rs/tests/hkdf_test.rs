@@ -52,7 +52,7 @@ fn hkdf_output_len_tests() {
             // Test zero length.
             let okm = prk.expand(&[b"info"], My(0)).unwrap();
             let result: My<Vec<u8>> = okm.into();
-            assert_eq!(&result.0, &[]);
+            assert_eq!(&result.0, &[] as &[u8]);
         }
 
         let max_out_len = MAX_BLOCKS * alg.hmac_algorithm().digest_algorithm().output_len;
@@ -92,6 +92,44 @@ fn hkdf_output_len_tests() {
     }
 }
 
+#[test]
+fn hkdf_fill_uses_expanded_len() {
+    /// A `KeyType` whose length doubles each time it is queried.
+    struct Growing(core::cell::Cell<usize>);
+
+    impl hkdf::KeyType for Growing {
+        fn len(&self) -> usize {
+            let len = self.0.get();
+            self.0.set(len * 2);
+            len
+        }
+    }
+
+    let alg = hkdf::HKDF_SHA256;
+    let max_out_len = 255 * alg.hmac_algorithm().digest_algorithm().output_len;
+    let prk = hkdf::Salt::new(alg, b"salt").extract(b"secret");
+    let My(expected) = prk.expand(&[b"info"], My(max_out_len)).unwrap().into();
+
+    // `fill` uses the length validated by `expand`, not a later value of `KeyType::len`.
+    let okm = prk
+        .expand(&[b"info"], Growing(core::cell::Cell::new(max_out_len)))
+        .unwrap();
+    let mut out = vec![0u8; max_out_len];
+    okm.fill(&mut out).unwrap();
+    assert_eq!(expected, out);
+
+    let okm = prk
+        .expand(&[b"info"], Growing(core::cell::Cell::new(max_out_len)))
+        .unwrap();
+    let mut out = vec![0u8; 2 * max_out_len];
+    assert_eq!(okm.fill(&mut out), Err(error::Unspecified));
+
+    // A buffer that does not match the key type's length is rejected.
+    let okm = prk.expand(&[b"info"], alg).unwrap();
+    let mut out = [0u8; 31];
+    assert_eq!(okm.fill(&mut out), Err(error::Unspecified));
+}
+
 #[test]
 fn hkdf_info_len_tests() {
     for &alg in &[hkdf::HKDF_SHA256, hkdf::HKDF_SHA384, hkdf::HKDF_SHA512] {
@@ -148,6 +186,80 @@ fn hkdf_key_types() {
     }
 }
 
+#[test]
+fn hkdf_fill_zeroizing() {
+    // RFC 5869 A.1.
+    let ikm = [0x0b; 22];
+    let salt = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c,
+    ];
+    let info: &[&[u8]] = &[&[0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9]];
+    let expected = [
+        0x3c, 0xb2, 0x5f, 0x25, 0xfa, 0xac, 0xd5, 0x7a, 0x90, 0x43, 0x4f, 0x64, 0xd0, 0x36, 0x2f,
+        0x2a, 0x2d, 0x2d, 0x0a, 0x90, 0xcf, 0x1a, 0x5a, 0x4c, 0x5d, 0xb0, 0x2d, 0x56, 0xec, 0xc4,
+        0xc5, 0xbf, 0x34, 0x00, 0x72, 0x08, 0xd5, 0xb8, 0x87, 0x18, 0x58, 0x65,
+    ];
+
+    let prk = hkdf::Salt::new(hkdf::HKDF_SHA256, &salt).extract(&ikm);
+    let okm = prk.expand(info, My(expected.len())).unwrap();
+    let out = okm.fill_zeroizing().unwrap();
+    assert_eq!(&expected[..], out.as_slice());
+
+    let mut filled = [0u8; 42];
+    prk.expand(info, My(expected.len()))
+        .unwrap()
+        .fill(&mut filled)
+        .unwrap();
+    assert_eq!(filled, out.as_slice());
+
+    let empty = prk.expand(info, My(0)).unwrap().fill_zeroizing().unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn hkdf_key_derivation() {
+    // RFC 5869 A.1.
+    let ikm = [0x0b; 22];
+    let salt = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c,
+    ];
+    let label = [0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9];
+    let expected = [
+        0x3c, 0xb2, 0x5f, 0x25, 0xfa, 0xac, 0xd5, 0x7a, 0x90, 0x43, 0x4f, 0x64, 0xd0, 0x36, 0x2f,
+        0x2a, 0x2d, 0x2d, 0x0a, 0x90, 0xcf, 0x1a, 0x5a, 0x4c, 0x5d, 0xb0, 0x2d, 0x56, 0xec, 0xc4,
+        0xc5, 0xbf, 0x34, 0x00, 0x72, 0x08, 0xd5, 0xb8, 0x87, 0x18, 0x58, 0x65,
+    ];
+
+    let derivation =
+        hkdf::KeyDerivation::new(hkdf::Salt::new(hkdf::HKDF_SHA256, &salt).extract(&ikm));
+    assert_eq!(
+        &expected[..],
+        derivation
+            .derive_key(&label, expected.len())
+            .unwrap()
+            .as_slice()
+    );
+
+    // Keys derived with distinct labels from the same PRK are independent.
+    let keys = [&b"client"[..], b"server", b"exporter"]
+        .map(|label| derivation.derive_key(label, 32).unwrap());
+    assert_ne!(keys[0], keys[1]);
+    assert_ne!(keys[0], keys[2]);
+    assert_ne!(keys[1], keys[2]);
+    // Deriving again with the same label gives the same key.
+    assert_eq!(keys[0], derivation.derive_key(b"client", 32).unwrap());
+    // Each derivation is an HKDF-Expand of the PRK.
+    let expanded = derivation
+        .prk()
+        .expand(&[b"server"], My(32))
+        .unwrap()
+        .fill_zeroizing()
+        .unwrap();
+    assert_eq!(keys[1], expanded);
+
+    assert!(derivation.derive_key(b"too long", 255 * 32 + 1).is_err());
+}
+
 #[test]
 fn hkdf_clone_tests() {
     for &alg in &[
@@ -84,6 +84,37 @@ fn hmac_test_case_inner(
     }
 }
 
+#[test]
+fn hmac_segments() {
+    const MSG: &[u8] = b"header || payload || trailer";
+
+    for alg in [
+        hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY,
+        hmac::HMAC_SHA256,
+        hmac::HMAC_SHA384,
+        hmac::HMAC_SHA512,
+    ] {
+        let key = hmac::Key::new(alg, b"this is a test!");
+        let tag = sign(&key, MSG);
+
+        for segments in [
+            &[MSG][..],
+            &[&MSG[..9], &MSG[9..20], &MSG[20..]],
+            &[b"", &MSG[..1], b"", &MSG[1..], b""],
+        ] {
+            assert_eq!(tag.as_ref(), hmac::sign_segments(&key, segments).as_ref());
+            assert!(hmac::verify_segments(&key, segments, tag.as_ref()).is_ok());
+        }
+
+        assert!(hmac::verify_segments(&key, &[&MSG[..9], &MSG[10..]], tag.as_ref()).is_err());
+        assert!(hmac::verify_segments(&key, &[MSG], &tag.as_ref()[1..]).is_err());
+        assert_eq!(
+            sign(&key, b"").as_ref(),
+            hmac::sign_segments(&key, &[]).as_ref()
+        );
+    }
+}
+
 #[test]
 fn hmac_debug() {
     let key = hmac::Key::new(hmac::HMAC_SHA256, &[0; 32]);
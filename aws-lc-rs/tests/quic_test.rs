@@ -3,7 +3,7 @@
 // Modifications copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0 OR ISC
 
-use aws_lc_rs::aead::quic;
+use aws_lc_rs::aead::{self, quic};
 use aws_lc_rs::{test, test_file};
 
 #[test]
@@ -21,6 +21,152 @@ fn quic_chacha20() {
     test_quic(&quic::CHACHA20, test_file!("data/quic_chacha20_tests.txt"));
 }
 
+// RFC 9001, Appendix A.3: Server Initial.
+#[test]
+fn quic_packet_key_aes_128_gcm() {
+    test_packet_key(
+        &aead::AES_128_GCM,
+        "cf3a5331653c364c88f0f379b6067e37",
+        "0ac1493ca1905853b0bba03e",
+        1,
+        "c1000000010008f067a5502a4262b50040750001",
+        "02000000000600405a020000560303eefce7f7b37ba1d1632e96677825ddf73988cfc79825df566dc543\
+         0b9a045a1200130100002e00330024001d00209d3c940d89690b84d08a60993c144eca684d1081287c834d\
+         5311bcf32bb9da1a002b00020304",
+        "5a482cd0991cd25b0aac406a5816b6394100f37a1c69797554780bb38cc5a99f5ede4cf73c3ec2493a18\
+         39b3dbcba3f6ea46c5b7684df3548e7ddeb9c3bf9c73cc3f3bded74b562bfb19fb84022f8ef4cdd93795\
+         d77d06edbb7aaf2f58891850abbdca3d20398c276456cbc42158407dd074ee",
+    );
+}
+
+// RFC 9001, Appendix A.5: ChaCha20-Poly1305 Short Header Packet.
+#[test]
+fn quic_packet_key_chacha20_poly1305() {
+    test_packet_key(
+        &aead::CHACHA20_POLY1305,
+        "c6d98ff3441c3fe1b2182094f69caa2ed4b716b65488960a7a984979fb23e1c8",
+        "e0459b3474bdd0e44a41c144",
+        654_360_564,
+        "4200bff4",
+        "01",
+        "655e5cd55c41f69080575d7999c25a5bfb",
+    );
+}
+
+fn test_packet_key(
+    alg: &'static aead::Algorithm,
+    key: &str,
+    iv: &str,
+    packet_number: u64,
+    header: &str,
+    payload: &str,
+    protected_payload: &str,
+) {
+    let key = quic::PacketKey::new(
+        alg,
+        &test::from_hex(key).unwrap(),
+        &test::from_hex(iv).unwrap(),
+    )
+    .unwrap();
+    let header = test::from_hex(header).unwrap();
+    let payload = test::from_hex(payload).unwrap();
+    let protected_payload = test::from_hex(protected_payload).unwrap();
+    assert_eq!(payload.len() + key.tag_len(), protected_payload.len());
+
+    let mut in_out = payload.clone();
+    let tag = key
+        .encrypt_in_place(packet_number, &header, &mut in_out)
+        .unwrap();
+    in_out.extend_from_slice(tag.as_ref());
+    assert_eq!(protected_payload, in_out);
+
+    let plaintext = key
+        .decrypt_in_place(packet_number, &header, &mut in_out)
+        .unwrap();
+    assert_eq!(payload, plaintext);
+
+    // The wrong packet number or header fails authentication.
+    let mut in_out = protected_payload.clone();
+    assert!(key
+        .decrypt_in_place(packet_number + 1, &header, &mut in_out)
+        .is_err());
+    let mut in_out = protected_payload;
+    assert!(key
+        .decrypt_in_place(packet_number, &header[1..], &mut in_out)
+        .is_err());
+}
+
+// RFC 9001, Appendix A.5: ChaCha20-Poly1305 Short Header Packet.
+#[test]
+fn quic_keys_from_secret() {
+    let secret =
+        test::from_hex("9ac312a7f877468ebe69422748ad00a15443f18203a07d6060f688f30f21632b").unwrap();
+    let header = test::from_hex("4200bff4").unwrap();
+    let packet_number = 654_360_564;
+
+    let hp_key = quic::HeaderProtectionKey::from_secret(&quic::CHACHA20, &secret).unwrap();
+    let sample = test::from_hex("5e5cd55c41f69080575d7999c25a5bfb").unwrap();
+    assert_eq!(
+        test::from_hex("aefefe7d03").unwrap(),
+        hp_key.new_mask(&sample).unwrap()
+    );
+
+    let key = quic::PacketKey::from_secret(&aead::CHACHA20_POLY1305, &secret).unwrap();
+    let mut in_out = vec![0x01];
+    let tag = key
+        .encrypt_in_place(packet_number, &header, &mut in_out)
+        .unwrap();
+    in_out.extend_from_slice(tag.as_ref());
+    assert_eq!(
+        test::from_hex("655e5cd55c41f69080575d7999c25a5bfb").unwrap(),
+        in_out
+    );
+
+    // The next generation uses the key and IV derived from the "quic ku" secret
+    // 1223504755036d556342ee9361d253421a826c9ecdf3c7148684b36b714881f9.
+    let next = key.next_generation().unwrap();
+    let expected_next = quic::PacketKey::new(
+        &aead::CHACHA20_POLY1305,
+        &test::from_hex("777ec1a510f50ec05d08d554ea5ef34a42c12200bb0f5a59c95908c9cd9189d2")
+            .unwrap(),
+        &test::from_hex("4159d18afd0156a1e564d16c").unwrap(),
+    )
+    .unwrap();
+    let mut next_in_out = vec![0x01];
+    let next_tag = next
+        .encrypt_in_place(packet_number, &header, &mut next_in_out)
+        .unwrap();
+    let mut expected_in_out = vec![0x01];
+    let expected_tag = expected_next
+        .encrypt_in_place(packet_number, &header, &mut expected_in_out)
+        .unwrap();
+    assert_eq!(expected_in_out, next_in_out);
+    assert_eq!(expected_tag.as_ref(), next_tag.as_ref());
+
+    // Successive generations use different keys.
+    next_in_out.extend_from_slice(next_tag.as_ref());
+    assert_ne!(in_out, next_in_out);
+    let next_next = next.next_generation().unwrap();
+    assert!(next_next
+        .decrypt_in_place(packet_number, &header, &mut next_in_out)
+        .is_err());
+
+    // Keys constructed from key bytes cannot be updated.
+    assert!(expected_next.next_generation().is_err());
+    // Secrets must be the length of the hash.
+    assert!(quic::PacketKey::from_secret(&aead::AES_256_GCM, &secret).is_err());
+    assert!(quic::HeaderProtectionKey::from_secret(&quic::AES_256, &secret).is_err());
+}
+
+#[test]
+fn quic_packet_key_invalid() {
+    let iv = [0u8; aead::NONCE_LEN];
+    assert!(quic::PacketKey::new(&aead::AES_128_GCM, &[0u8; 16], &iv).is_ok());
+    assert!(quic::PacketKey::new(&aead::AES_128_GCM, &[0u8; 32], &iv).is_err());
+    assert!(quic::PacketKey::new(&aead::AES_128_GCM, &[0u8; 16], &iv[1..]).is_err());
+    assert!(quic::PacketKey::new(&aead::AES_128_GCM_SIV, &[0u8; 16], &iv).is_err());
+}
+
 fn test_quic(alg: &'static quic::Algorithm, test_file: test::File) {
     test_sample_len(alg);
 
@@ -0,0 +1,50 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+// This test forks the process, which is only safe while no other threads are running. It is
+// therefore built without the libtest harness, which runs tests on a pool of threads.
+
+#[cfg(unix)]
+use aws_lc_rs::rand::{self, SecureRandom as _};
+
+fn main() {
+    #[cfg(unix)]
+    test_system_random_fork();
+}
+
+#[cfg(unix)]
+fn test_system_random_fork() {
+    let rng = rand::SystemRandom::new();
+
+    // Make sure the parent's generator is initialized before forking.
+    let mut parent = [0u8; 32];
+    rng.fill(&mut parent).unwrap();
+
+    let mut fds = [0; 2];
+    assert_eq!(0, unsafe { libc::pipe(fds.as_mut_ptr()) });
+    let pid = unsafe { libc::fork() };
+    assert!(pid >= 0);
+    if pid == 0 {
+        let mut child = [0u8; 32];
+        let written = if rng.fill(&mut child).is_ok() {
+            unsafe { libc::write(fds[1], child.as_ptr().cast(), child.len()) }
+        } else {
+            -1
+        };
+        unsafe { libc::_exit(i32::from(written != 32)) };
+    }
+    unsafe { libc::close(fds[1]) };
+
+    rng.fill(&mut parent).unwrap();
+
+    let mut child = [0u8; 32];
+    let read = unsafe { libc::read(fds[0], child.as_mut_ptr().cast(), child.len()) };
+    unsafe { libc::close(fds[0]) };
+    let mut status = 0;
+    assert_eq!(pid, unsafe { libc::waitpid(pid, &mut status, 0) });
+    assert!(libc::WIFEXITED(status));
+    assert_eq!(0, libc::WEXITSTATUS(status));
+    assert_eq!(32, read);
+
+    assert_ne!(parent, child);
+}
@@ -270,10 +270,7 @@ fn rsa_test_public_key_coverage() {
 
     // Test `Debug`
     assert_eq!(PUBLIC_KEY_DEBUG, format!("{:?}", key_pair.public_key()));
-    assert_eq!(
-        format!("RsaKeyPair {{ public_key: {:?} }}", key_pair.public_key()),
-        format!("{key_pair:?}")
-    );
+    assert_eq!("RsaKeyPair { bits: 2048 }", format!("{key_pair:?}"));
 }
 
 #[test]
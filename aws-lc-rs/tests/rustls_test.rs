@@ -0,0 +1,159 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0 OR ISC
+
+#![cfg(feature = "rustls")]
+
+use aws_lc_rs::rustls::{
+    default_provider, TLS13_AES_128_GCM_SHA256, TLS13_AES_256_GCM_SHA384,
+    TLS13_CHACHA20_POLY1305_SHA256,
+};
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer, ServerName};
+use rustls::{
+    ClientConfig, ClientConnection, Connection, RootCertStore, ServerConfig, ServerConnection,
+    SupportedCipherSuite,
+};
+use std::io::{Read, Write};
+use std::sync::Arc;
+
+const CA_CERT: &[u8] = include_bytes!("data/rustls/ca_cert.der");
+
+const SERVER_KEYS: [(&[u8], &[u8]); 3] = [
+    (
+        include_bytes!("data/rustls/ecdsa_p256_cert.der"),
+        include_bytes!("data/rustls/ecdsa_p256_key.der"),
+    ),
+    (
+        include_bytes!("data/rustls/rsa_2048_cert.der"),
+        include_bytes!("data/rustls/rsa_2048_key.der"),
+    ),
+    (
+        include_bytes!("data/rustls/ed25519_cert.der"),
+        include_bytes!("data/rustls/ed25519_key.der"),
+    ),
+];
+
+fn client_config(suite: SupportedCipherSuite, roots: RootCertStore) -> ClientConfig {
+    let mut provider = default_provider();
+    provider.cipher_suites = vec![suite];
+    ClientConfig::builder_with_provider(Arc::new(provider))
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .unwrap()
+        .with_root_certificates(roots)
+        .with_no_client_auth()
+}
+
+fn server_config(cert: &[u8], key: &[u8]) -> ServerConfig {
+    ServerConfig::builder_with_provider(Arc::new(default_provider()))
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .unwrap()
+        .with_no_client_auth()
+        .with_single_cert(
+            vec![CertificateDer::from(cert.to_vec())],
+            PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(key.to_vec())),
+        )
+        .unwrap()
+}
+
+fn connect(client_config: ClientConfig, server_config: ServerConfig) -> (Connection, Connection) {
+    let server_name = ServerName::try_from("localhost").unwrap();
+    let client = ClientConnection::new(Arc::new(client_config), server_name).unwrap();
+    let server = ServerConnection::new(Arc::new(server_config)).unwrap();
+    (client.into(), server.into())
+}
+
+/// Moves all pending TLS records from `from` to `to`, then processes them.
+fn transfer(from: &mut Connection, to: &mut Connection) -> Result<(), rustls::Error> {
+    let mut records = Vec::new();
+    while from.wants_write() {
+        from.write_tls(&mut records).unwrap();
+    }
+    let mut records = records.as_slice();
+    while !records.is_empty() {
+        to.read_tls(&mut records).unwrap();
+        to.process_new_packets()?;
+    }
+    Ok(())
+}
+
+fn handshake(client: &mut Connection, server: &mut Connection) -> Result<(), rustls::Error> {
+    while client.is_handshaking() || server.is_handshaking() {
+        transfer(client, server)?;
+        transfer(server, client)?;
+    }
+    Ok(())
+}
+
+fn trusted_roots() -> RootCertStore {
+    let mut roots = RootCertStore::empty();
+    roots.add(CertificateDer::from(CA_CERT)).unwrap();
+    roots
+}
+
+#[test]
+fn test_tls13_handshake() {
+    for suite in [
+        TLS13_AES_128_GCM_SHA256,
+        TLS13_AES_256_GCM_SHA384,
+        TLS13_CHACHA20_POLY1305_SHA256,
+    ] {
+        for (cert, key) in SERVER_KEYS {
+            let (mut client, mut server) = connect(
+                client_config(suite, trusted_roots()),
+                server_config(cert, key),
+            );
+            handshake(&mut client, &mut server).unwrap();
+
+            assert_eq!(
+                Some(suite.suite()),
+                client.negotiated_cipher_suite().map(|s| s.suite())
+            );
+            assert_eq!(
+                Some(suite.suite()),
+                server.negotiated_cipher_suite().map(|s| s.suite())
+            );
+
+            client.writer().write_all(b"ping").unwrap();
+            transfer(&mut client, &mut server).unwrap();
+            let mut received = [0u8; 4];
+            server.reader().read_exact(&mut received).unwrap();
+            assert_eq!(b"ping", &received);
+
+            server.writer().write_all(b"pong").unwrap();
+            transfer(&mut server, &mut client).unwrap();
+            client.reader().read_exact(&mut received).unwrap();
+            assert_eq!(b"pong", &received);
+        }
+    }
+}
+
+#[test]
+fn test_tls13_handshake_untrusted_server() {
+    let (cert, key) = SERVER_KEYS[0];
+    let (mut client, mut server) = connect(
+        client_config(TLS13_AES_128_GCM_SHA256, RootCertStore::empty()),
+        server_config(cert, key),
+    );
+    assert!(matches!(
+        handshake(&mut client, &mut server),
+        Err(rustls::Error::InvalidCertificate(_))
+    ));
+}
+
+#[test]
+fn test_provider() {
+    let provider = default_provider();
+    assert_eq!(3, provider.cipher_suites.len());
+    assert_eq!(3, provider.kx_groups.len());
+
+    let mut random = [0u8; 32];
+    provider.secure_random.fill(&mut random).unwrap();
+    assert_ne!([0u8; 32], random);
+
+    for (_, key) in SERVER_KEYS {
+        let key = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(key.to_vec()));
+        let signing_key = provider.key_provider.load_private_key(key).unwrap();
+        assert!(signing_key.public_key().is_some());
+    }
+    let garbage = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(vec![0x30, 0x00]));
+    assert!(provider.key_provider.load_private_key(garbage).is_err());
+}